@@ -0,0 +1,272 @@
+//! Constant-Product AMM Adapter
+//!
+//! Lock position remaining_accounts layout:
+//! [0]  lp_token_account - Permanent lock's LP token account (writable)
+//! [1]  authority - CPMM vault and LP mint authority PDA
+//! [2]  pool_state - Pool state (writable)
+//! [3]  lp_mint - LP mint (writable)
+//! [4]  token_vault_0 - Pool token vault 0 (writable)
+//! [5]  token_vault_1 - Pool token vault 1 (writable)
+//! [6]  recipient_token_account_0 - Recipient for token 0 (writable)
+//! [7]  recipient_token_account_1 - Recipient for token 1 (writable)
+//! [8]  token_program_2022 - Token 2022 program
+//! [9]  memo_program - Memo program
+//! [10] vault_0_mint - Vault 0 mint
+//! [11] vault_1_mint - Vault 1 mint
+//!
+//! Swaps take the authority PDA as their only extra account.
+
+use anchor_lang::prelude::*;
+use crate::cpmm::{self, CpmmPoolState};
+use crate::samm::tick_math;
+use crate::errors::SovereignError;
+use crate::state::{AmmKind, PermanentLock};
+use super::{token_amount, AmmAdapter, AmmSwap, LockPoolAccounts, LockPosition};
+
+/// Adapter for the constant-product AMM
+pub struct CpmmAdapter;
+
+impl CpmmAdapter {
+    /// Pool state plus its trading reserves and LP supply, with `vaults`
+    /// checked against the pool
+    fn pool_snapshot(pool_state: &AccountInfo, vaults: &[AccountInfo]) -> Result<(CpmmPoolState, u64, u64)> {
+        require!(vaults.len() >= 2, SovereignError::InsufficientAccounts);
+        let pool = {
+            let pool_data = pool_state.try_borrow_data()?;
+            CpmmPoolState::try_deserialize(&pool_data)?
+        };
+        require!(
+            vaults[0].key() == pool.token_0_vault && vaults[1].key() == pool.token_1_vault,
+            SovereignError::InvalidPool
+        );
+        let (reserve_0, reserve_1) = pool
+            .reserves(token_amount(&vaults[0])?, token_amount(&vaults[1])?)
+            .ok_or(SovereignError::Overflow)?;
+        Ok((pool, reserve_0, reserve_1))
+    }
+
+    fn withdraw_accounts<'info>(position: &LockPosition<'_, 'info>) -> cpmm::WithdrawAccounts<'info> {
+        let accounts = position.accounts;
+        cpmm::WithdrawAccounts {
+            owner: position.owner.clone(),
+            authority: accounts[1].clone(),
+            pool_state: accounts[2].clone(),
+            owner_lp_token: accounts[0].clone(),
+            token_0_account: accounts[6].clone(),
+            token_1_account: accounts[7].clone(),
+            token_0_vault: accounts[4].clone(),
+            token_1_vault: accounts[5].clone(),
+            token_program: position.token_program.clone(),
+            token_program_2022: accounts[8].clone(),
+            vault_0_mint: accounts[10].clone(),
+            vault_1_mint: accounts[11].clone(),
+            lp_mint: accounts[3].clone(),
+            memo_program: accounts[9].clone(),
+        }
+    }
+}
+
+impl AmmAdapter for CpmmAdapter {
+    fn kind(&self) -> AmmKind {
+        AmmKind::Cpmm
+    }
+
+    fn position_accounts_len(&self) -> usize {
+        12
+    }
+
+    fn pool_accounts<'info>(&self, program: &Pubkey, accounts: &[AccountInfo<'info>]) -> Result<LockPoolAccounts<'info>> {
+        require!(accounts.len() >= 12, SovereignError::InsufficientAccounts);
+        // The pool state is deserialized without an Anchor owner check
+        require_keys_eq!(*accounts[2].owner, *program, SovereignError::InvalidPool);
+        let pool = {
+            let pool_data = accounts[2].try_borrow_data()?;
+            CpmmPoolState::try_deserialize(&pool_data)?
+        };
+        require!(
+            accounts[3].key() == pool.lp_mint
+                && accounts[4].key() == pool.token_0_vault
+                && accounts[5].key() == pool.token_1_vault
+                && accounts[10].key() == pool.token_0_mint
+                && accounts[11].key() == pool.token_1_mint,
+            SovereignError::InvalidPool
+        );
+        Ok(LockPoolAccounts {
+            pool_state: accounts[2].clone(),
+            token_vault_0: accounts[4].clone(),
+            token_vault_1: accounts[5].clone(),
+            vault_0_mint: accounts[10].clone(),
+            vault_1_mint: accounts[11].clone(),
+            recipient_0: accounts[6].clone(),
+            recipient_1: accounts[7].clone(),
+            token_program_2022: accounts[8].clone(),
+            memo_program: accounts[9].clone(),
+        })
+    }
+
+    fn position_liquidity(&self, accounts: &[AccountInfo]) -> Result<u128> {
+        Ok(token_amount(&accounts[0])? as u128)
+    }
+
+    fn collect_fees<'info>(
+        &self,
+        position: &LockPosition<'_, 'info>,
+        principal: u128,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        // Fees stay in the reserves: withdraw the LP tokens above the principal
+        let accounts = position.accounts;
+        let (pool, reserve_0, reserve_1) = Self::pool_snapshot(&accounts[2], &accounts[4..6])?;
+        let fee_lp = cpmm::fee_lp_amount(
+            token_amount(&accounts[0])?,
+            principal,
+            reserve_0,
+            reserve_1,
+            pool.lp_supply,
+        ).ok_or(SovereignError::Overflow)?;
+
+        if fee_lp == 0 {
+            msg!("No CPMM fees to collect");
+            return Ok(());
+        }
+
+        cpmm::withdraw(
+            &position.program,
+            Self::withdraw_accounts(position),
+            fee_lp,
+            0,
+            0,
+            signer_seeds,
+        )
+    }
+
+    fn remove_liquidity<'info>(
+        &self,
+        position: &LockPosition<'_, 'info>,
+        liquidity: u128,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        cpmm::withdraw(
+            &position.program,
+            Self::withdraw_accounts(position),
+            u64::try_from(liquidity).map_err(|_| SovereignError::Overflow)?,
+            0, // Min amounts 0 (unwind / emergency accept any amount)
+            0,
+            signer_seeds,
+        )
+    }
+
+    fn swap_exact_input<'info>(
+        &self,
+        program: &AccountInfo<'info>,
+        swap: AmmSwap<'info>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        require!(!swap.extra.is_empty(), SovereignError::InsufficientAccounts);
+
+        // Each side uses its mint's token program
+        let program_for = |mint: &AccountInfo<'info>| {
+            if *mint.owner == swap.token_program_2022.key() {
+                swap.token_program_2022.clone()
+            } else {
+                swap.token_program.clone()
+            }
+        };
+        let input_token_program = program_for(&swap.input_vault_mint);
+        let output_token_program = program_for(&swap.output_vault_mint);
+
+        let swap_accounts = cpmm::SwapBaseInputAccounts {
+            payer: swap.payer,
+            authority: swap.extra[0].clone(),
+            amm_config: swap.amm_config,
+            pool_state: swap.pool_state,
+            input_token_account: swap.input_token_account,
+            output_token_account: swap.output_token_account,
+            input_vault: swap.input_vault,
+            output_vault: swap.output_vault,
+            input_token_program,
+            output_token_program,
+            input_token_mint: swap.input_vault_mint,
+            output_token_mint: swap.output_vault_mint,
+            observation_state: swap.observation_state,
+        };
+
+        cpmm::swap_base_input(program, swap_accounts, amount_in, minimum_amount_out, signer_seeds)
+    }
+
+    fn set_pool_unrestricted<'info>(
+        &self,
+        _program: &AccountInfo<'info>,
+        _authority: &AccountInfo<'info>,
+        _pool_state: &AccountInfo<'info>,
+        _signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        // CPMM pools are never restricted
+        err!(SovereignError::UnsupportedByAmm)
+    }
+
+    fn fee_growth(&self, pool_state: &AccountInfo, vaults: &[AccountInfo]) -> Result<(u128, u128)> {
+        // sqrt(k) per LP token only grows with fees, and is the same for both sides
+        let (pool, reserve_0, reserve_1) = Self::pool_snapshot(pool_state, vaults)?;
+        let growth = cpmm::sqrt_k_per_lp_x64(reserve_0, reserve_1, pool.lp_supply)
+            .ok_or(SovereignError::Overflow)?;
+        Ok((growth, growth))
+    }
+
+    fn fees_since(
+        &self,
+        pool_state: &AccountInfo,
+        vaults: &[AccountInfo],
+        snapshot_a: u128,
+        liquidity: u128,
+        quote_mint: &Pubkey,
+    ) -> Result<u128> {
+        if snapshot_a == 0 {
+            return Ok(0);
+        }
+        let (pool, reserve_0, reserve_1) = Self::pool_snapshot(pool_state, vaults)?;
+        let growth = cpmm::sqrt_k_per_lp_x64(reserve_0, reserve_1, pool.lp_supply)
+            .ok_or(SovereignError::Overflow)?;
+
+        let fees_sqrt_k = cpmm::fee_sqrt_k_since(growth, snapshot_a, liquidity)
+            .ok_or(SovereignError::Overflow)?;
+
+        let (quote_reserve, token_reserve) = if pool.token_0_mint == *quote_mint {
+            (reserve_0, reserve_1)
+        } else {
+            (reserve_1, reserve_0)
+        };
+        Ok(cpmm::sqrt_k_quote_value(fees_sqrt_k, quote_reserve, token_reserve).unwrap_or(0))
+    }
+
+    fn lock_reserves(&self, accounts: &[AccountInfo], _lock: &PermanentLock) -> Result<(u128, u128)> {
+        // CPMM pools are never restricted, so the lock owns only its LP tokens' share
+        let (pool, reserve_0, reserve_1) = Self::pool_snapshot(&accounts[2], &accounts[4..6])?;
+        let (amount_0, amount_1) = cpmm::lp_reserves(token_amount(&accounts[0])?, reserve_0, reserve_1, pool.lp_supply)
+            .ok_or(SovereignError::Overflow)?;
+        Ok((amount_0 as u128, amount_1 as u128))
+    }
+
+    fn min_quote_reserve(
+        &self,
+        accounts: &[AccountInfo],
+        lock: &PermanentLock,
+        reserve_floor: u128,
+        amount_in: u128,
+        quote_is_0: bool,
+    ) -> Result<u128> {
+        // Constant product: x * y = x' * (y + amount_in), so x >= floor * (y + amount_in) / y.
+        // Over the lock's share only: the other LPs can withdraw before the sell.
+        let (reserve_0, reserve_1) = self.lock_reserves(accounts, lock)?;
+        let token_reserve = if quote_is_0 { reserve_1 } else { reserve_0 };
+        if token_reserve == 0 {
+            return Ok(u128::MAX);
+        }
+        Ok(token_reserve
+            .checked_add(amount_in)
+            .and_then(|supply| tick_math::mul_div_ceil(reserve_floor, supply, token_reserve))
+            .unwrap_or(u128::MAX))
+    }
+}
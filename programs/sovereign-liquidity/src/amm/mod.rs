@@ -0,0 +1,228 @@
+//! AMM Adapter Layer
+//!
+//! The permanent lock's pool operations (fee harvest, unwind, swaps, fee growth
+//! snapshots) are routed through the `AmmAdapter` trait so a sovereign can live
+//! on either backend recorded in `SovereignState::amm_kind`:
+//!
+//! - **samm**: Trashbin SAMM (concentrated liquidity, position NFT)
+//! - **cpmm**: Constant-product AMM (LP tokens)
+//!
+//! Pool creation and the CLMM-only features (price band, LP ladder, existing
+//! pools) stay backend-specific instructions.
+//!
+//! ## Lock Position Accounts
+//!
+//! Each backend defines the remaining_accounts layout of the permanent lock's
+//! position (`position_accounts_len`). Callers append their own accounts after it.
+
+pub mod samm;
+pub mod cpmm;
+
+pub use samm::SammAdapter;
+pub use cpmm::CpmmAdapter;
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmKind, PermanentLock};
+
+/// Pool-side accounts of the permanent lock's position, common to all backends
+pub struct LockPoolAccounts<'info> {
+    /// Pool state
+    pub pool_state: AccountInfo<'info>,
+    /// Pool vault for mint 0
+    pub token_vault_0: AccountInfo<'info>,
+    /// Pool vault for mint 1
+    pub token_vault_1: AccountInfo<'info>,
+    /// Mint 0
+    pub vault_0_mint: AccountInfo<'info>,
+    /// Mint 1
+    pub vault_1_mint: AccountInfo<'info>,
+    /// Permanent lock's token account for mint 0
+    pub recipient_0: AccountInfo<'info>,
+    /// Permanent lock's token account for mint 1
+    pub recipient_1: AccountInfo<'info>,
+    /// Token-2022 program
+    pub token_program_2022: AccountInfo<'info>,
+    /// Memo program
+    pub memo_program: AccountInfo<'info>,
+}
+
+impl<'info> LockPoolAccounts<'info> {
+    /// Whether the quote mint is the pool's mint 0
+    pub fn quote_is_0(&self, quote_mint: &Pubkey) -> bool {
+        self.vault_0_mint.key() == *quote_mint
+    }
+
+    /// (quote, token) recipients
+    pub fn recipients(&self, quote_mint: &Pubkey) -> (&AccountInfo<'info>, &AccountInfo<'info>) {
+        if self.quote_is_0(quote_mint) {
+            (&self.recipient_0, &self.recipient_1)
+        } else {
+            (&self.recipient_1, &self.recipient_0)
+        }
+    }
+
+    /// (quote, token) pool vaults
+    pub fn vaults(&self, quote_mint: &Pubkey) -> (&AccountInfo<'info>, &AccountInfo<'info>) {
+        if self.quote_is_0(quote_mint) {
+            (&self.token_vault_0, &self.token_vault_1)
+        } else {
+            (&self.token_vault_1, &self.token_vault_0)
+        }
+    }
+
+    /// (quote, token) mints
+    pub fn mints(&self, quote_mint: &Pubkey) -> (&AccountInfo<'info>, &AccountInfo<'info>) {
+        if self.quote_is_0(quote_mint) {
+            (&self.vault_0_mint, &self.vault_1_mint)
+        } else {
+            (&self.vault_1_mint, &self.vault_0_mint)
+        }
+    }
+}
+
+/// The permanent lock's position, as passed to the adapter
+pub struct LockPosition<'a, 'info> {
+    /// AMM program
+    pub program: AccountInfo<'info>,
+    /// Position owner (the permanent lock PDA, signs via seeds)
+    pub owner: AccountInfo<'info>,
+    /// SPL Token program
+    pub token_program: AccountInfo<'info>,
+    /// Position accounts (`position_accounts_len` entries)
+    pub accounts: &'a [AccountInfo<'info>],
+}
+
+/// Accounts for an exact-input swap through the sovereign's pool
+pub struct AmmSwap<'info> {
+    /// [signer] Owner of the input token account
+    pub payer: AccountInfo<'info>,
+    /// AMM config
+    pub amm_config: AccountInfo<'info>,
+    /// Pool state
+    pub pool_state: AccountInfo<'info>,
+    /// Input token account
+    pub input_token_account: AccountInfo<'info>,
+    /// Output token account
+    pub output_token_account: AccountInfo<'info>,
+    /// Pool vault of the input mint
+    pub input_vault: AccountInfo<'info>,
+    /// Pool vault of the output mint
+    pub output_vault: AccountInfo<'info>,
+    /// Input mint
+    pub input_vault_mint: AccountInfo<'info>,
+    /// Output mint
+    pub output_vault_mint: AccountInfo<'info>,
+    /// Observation state
+    pub observation_state: AccountInfo<'info>,
+    /// SPL Token program
+    pub token_program: AccountInfo<'info>,
+    /// Token-2022 program
+    pub token_program_2022: AccountInfo<'info>,
+    /// Memo program
+    pub memo_program: AccountInfo<'info>,
+    /// Backend-specific accounts: SAMM tick arrays, CPMM [authority]
+    pub extra: Vec<AccountInfo<'info>>,
+}
+
+/// Pool operations of the permanent lock, implemented per AMM backend
+pub trait AmmAdapter {
+    /// Backend this adapter drives
+    fn kind(&self) -> AmmKind;
+
+    /// Number of remaining_accounts describing the lock position
+    fn position_accounts_len(&self) -> usize;
+
+    /// Pool-side accounts from the lock position accounts. `program` is the
+    /// sovereign's AMM program, which must own any pool state read here.
+    fn pool_accounts<'info>(&self, program: &Pubkey, accounts: &[AccountInfo<'info>]) -> Result<LockPoolAccounts<'info>>;
+
+    /// Liquidity currently held by the lock position, in the units
+    /// `remove_liquidity` takes (SAMM: L, CPMM: LP tokens)
+    fn position_liquidity(&self, accounts: &[AccountInfo]) -> Result<u128>;
+
+    /// Collect the position's trading fees into the recipients. `principal` is
+    /// `PermanentLock::liquidity` - the part of the position that is not fees.
+    fn collect_fees<'info>(
+        &self,
+        position: &LockPosition<'_, 'info>,
+        principal: u128,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+
+    /// Remove `liquidity` (as returned by `position_liquidity`) into the recipients
+    fn remove_liquidity<'info>(
+        &self,
+        position: &LockPosition<'_, 'info>,
+        liquidity: u128,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+
+    /// Exact-input swap with a minimum output
+    fn swap_exact_input<'info>(
+        &self,
+        program: &AccountInfo<'info>,
+        swap: AmmSwap<'info>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+
+    /// Lift the recovery LP restriction on the pool
+    fn set_pool_unrestricted<'info>(
+        &self,
+        program: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        pool_state: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+
+    /// Fee growth accumulators (a, b) to snapshot for the unwind volume check.
+    /// `vaults` are the pool vaults (mint 0, mint 1), used by backends whose
+    /// growth depends on the reserves.
+    fn fee_growth(&self, pool_state: &AccountInfo, vaults: &[AccountInfo]) -> Result<(u128, u128)>;
+
+    /// Fees earned by `liquidity` since the growth snapshot `snapshot_a`
+    fn fees_since(
+        &self,
+        pool_state: &AccountInfo,
+        vaults: &[AccountInfo],
+        snapshot_a: u128,
+        liquidity: u128,
+        quote_mint: &Pubkey,
+    ) -> Result<u128>;
+
+    /// Reserves (mint 0, mint 1) held by the lock's position, from the lock
+    /// position accounts. Only the lock's share counts: a pool the sovereign did
+    /// not create, or one open to other LPs, also holds their liquidity.
+    fn lock_reserves(&self, accounts: &[AccountInfo], lock: &PermanentLock) -> Result<(u128, u128)>;
+
+    /// Least quote the lock's position must hold so that selling `amount_in`
+    /// more tokens into it still leaves `reserve_floor` of it
+    /// (u128::MAX when the sell cannot be bounded)
+    fn min_quote_reserve(
+        &self,
+        accounts: &[AccountInfo],
+        lock: &PermanentLock,
+        reserve_floor: u128,
+        amount_in: u128,
+        quote_is_0: bool,
+    ) -> Result<u128>;
+}
+
+/// Adapter for a sovereign's AMM backend
+pub fn adapter(kind: AmmKind) -> &'static dyn AmmAdapter {
+    match kind {
+        AmmKind::Samm => &SammAdapter,
+        AmmKind::Cpmm => &CpmmAdapter,
+    }
+}
+
+/// Token account balance from raw account data (0 if not a token account)
+pub(crate) fn token_amount(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(if data.len() >= 72 {
+        u64::from_le_bytes(data[64..72].try_into().unwrap())
+    } else {
+        0
+    })
+}
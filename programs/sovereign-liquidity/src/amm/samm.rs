@@ -0,0 +1,208 @@
+//! Trashbin SAMM Adapter
+//!
+//! Lock position remaining_accounts layout:
+//! [0]  nft_account - Position NFT token account
+//! [1]  personal_position - Personal position state (writable)
+//! [2]  pool_state - Pool state (writable)
+//! [3]  protocol_position - Protocol position state (writable)
+//! [4]  token_vault_0 - Pool token vault 0 (writable)
+//! [5]  token_vault_1 - Pool token vault 1 (writable)
+//! [6]  tick_array_lower - Lower tick array (writable)
+//! [7]  tick_array_upper - Upper tick array (writable)
+//! [8]  recipient_token_account_0 - Recipient for token 0 (writable)
+//! [9]  recipient_token_account_1 - Recipient for token 1 (writable)
+//! [10] token_program_2022 - Token 2022 program
+//! [11] memo_program - Memo program
+//! [12] vault_0_mint - Vault 0 mint
+//! [13] vault_1_mint - Vault 1 mint
+//! [14] tick_array_bitmap_extension
+
+use anchor_lang::prelude::*;
+use crate::errors::SovereignError;
+use crate::samm::{self, instructions as samm_ix, cpi as samm_cpi, tick_math, SammAccountDeserialize};
+use crate::state::{AmmKind, PermanentLock};
+use super::{AmmAdapter, AmmSwap, LockPoolAccounts, LockPosition};
+
+/// Adapter for the Trashbin SAMM (Raydium CLMM fork)
+pub struct SammAdapter;
+
+impl SammAdapter {
+    fn sqrt_price_x64(pool_state: &AccountInfo) -> Result<u128> {
+        let pool_data = pool_state.try_borrow_data()?;
+        Ok(samm::PoolState::try_deserialize(&pool_data)?.sqrt_price_x64)
+    }
+
+    fn decrease_accounts<'info>(position: &LockPosition<'_, 'info>) -> samm_ix::DecreaseLiquidityV2Accounts<'info> {
+        let accounts = position.accounts;
+        samm_ix::DecreaseLiquidityV2Accounts {
+            nft_owner: position.owner.clone(),
+            nft_account: accounts[0].clone(),
+            personal_position: accounts[1].clone(),
+            pool_state: accounts[2].clone(),
+            protocol_position: accounts[3].clone(),
+            token_vault_0: accounts[4].clone(),
+            token_vault_1: accounts[5].clone(),
+            tick_array_lower: accounts[6].clone(),
+            tick_array_upper: accounts[7].clone(),
+            recipient_token_account_0: accounts[8].clone(),
+            recipient_token_account_1: accounts[9].clone(),
+            token_program: position.token_program.clone(),
+            token_program_2022: accounts[10].clone(),
+            memo_program: accounts[11].clone(),
+            vault_0_mint: accounts[12].clone(),
+            vault_1_mint: accounts[13].clone(),
+            tick_array_bitmap_extension: accounts[14].clone(),
+        }
+    }
+}
+
+impl AmmAdapter for SammAdapter {
+    fn kind(&self) -> AmmKind {
+        AmmKind::Samm
+    }
+
+    fn position_accounts_len(&self) -> usize {
+        15
+    }
+
+    fn pool_accounts<'info>(&self, _program: &Pubkey, accounts: &[AccountInfo<'info>]) -> Result<LockPoolAccounts<'info>> {
+        require!(accounts.len() >= 15, SovereignError::MissingSAMMAccounts);
+        Ok(LockPoolAccounts {
+            pool_state: accounts[2].clone(),
+            token_vault_0: accounts[4].clone(),
+            token_vault_1: accounts[5].clone(),
+            vault_0_mint: accounts[12].clone(),
+            vault_1_mint: accounts[13].clone(),
+            recipient_0: accounts[8].clone(),
+            recipient_1: accounts[9].clone(),
+            token_program_2022: accounts[10].clone(),
+            memo_program: accounts[11].clone(),
+        })
+    }
+
+    fn position_liquidity(&self, accounts: &[AccountInfo]) -> Result<u128> {
+        let pp_data = accounts[1].try_borrow_data()?;
+        Ok(samm::PersonalPositionState::try_deserialize(&pp_data)?.liquidity)
+    }
+
+    fn collect_fees<'info>(
+        &self,
+        position: &LockPosition<'_, 'info>,
+        _principal: u128,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        // decrease_liquidity_v2 with liquidity=0 collects fees only
+        samm_cpi::collect_fees(&position.program, Self::decrease_accounts(position), signer_seeds)?;
+        Ok(())
+    }
+
+    fn remove_liquidity<'info>(
+        &self,
+        position: &LockPosition<'_, 'info>,
+        liquidity: u128,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        samm_cpi::remove_liquidity(
+            &position.program,
+            Self::decrease_accounts(position),
+            liquidity,
+            0, // Min amounts 0 (unwind / emergency accept any amount)
+            0,
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+
+    fn swap_exact_input<'info>(
+        &self,
+        program: &AccountInfo<'info>,
+        swap: AmmSwap<'info>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let swap_accounts = samm_ix::SwapV2Accounts {
+            payer: swap.payer,
+            amm_config: swap.amm_config,
+            pool_state: swap.pool_state,
+            input_token_account: swap.input_token_account,
+            output_token_account: swap.output_token_account,
+            input_vault: swap.input_vault,
+            output_vault: swap.output_vault,
+            observation_state: swap.observation_state,
+            token_program: swap.token_program,
+            token_program_2022: swap.token_program_2022,
+            memo_program: swap.memo_program,
+            input_vault_mint: swap.input_vault_mint,
+            output_vault_mint: swap.output_vault_mint,
+        };
+
+        samm_cpi::swap_exact_input(
+            program,
+            swap_accounts,
+            amount_in,
+            minimum_amount_out,
+            0, // no sqrt_price_limit
+            swap.extra,
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+
+    fn set_pool_unrestricted<'info>(
+        &self,
+        program: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        pool_state: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        samm_cpi::set_pool_status_unrestricted(program, authority, pool_state, signer_seeds)
+    }
+
+    fn fee_growth(&self, pool_state: &AccountInfo, _vaults: &[AccountInfo]) -> Result<(u128, u128)> {
+        let pool_data = pool_state.try_borrow_data()?;
+        let pool = samm::PoolState::try_deserialize(&pool_data)?;
+        Ok((pool.fee_growth_global_0_x64, pool.fee_growth_global_1_x64))
+    }
+
+    fn fees_since(
+        &self,
+        pool_state: &AccountInfo,
+        vaults: &[AccountInfo],
+        snapshot_a: u128,
+        liquidity: u128,
+        _quote_mint: &Pubkey,
+    ) -> Result<u128> {
+        let (growth_a, _) = self.fee_growth(pool_state, vaults)?;
+        // fees = fee_delta * position_liquidity >> 64
+        Ok(growth_a
+            .saturating_sub(snapshot_a)
+            .checked_mul(liquidity)
+            .unwrap_or(0) >> 64)
+    }
+
+    fn lock_reserves(&self, accounts: &[AccountInfo], lock: &PermanentLock) -> Result<(u128, u128)> {
+        // The primary and LP ladder positions at the current price; the pool
+        // vaults also hold any other LP's liquidity
+        tick_math::position_amounts(&lock.positions(), Self::sqrt_price_x64(&accounts[2])?)
+    }
+
+    fn min_quote_reserve(
+        &self,
+        accounts: &[AccountInfo],
+        lock: &PermanentLock,
+        reserve_floor: u128,
+        amount_in: u128,
+        quote_is_0: bool,
+    ) -> Result<u128> {
+        let sqrt_price_x64 = Self::sqrt_price_x64(&accounts[2])?;
+        // The sell walks the primary and LP ladder ranges one step at a time.
+        // Only the lock's liquidity is counted: any other LP can withdraw theirs
+        // before the sell.
+        let quote_out = tick_math::sell_amount_out(&lock.positions(), sqrt_price_x64, amount_in, !quote_is_0);
+        Ok(quote_out
+            .ok()
+            .and_then(|quote_out| reserve_floor.checked_add(quote_out))
+            .unwrap_or(u128::MAX))
+    }
+}
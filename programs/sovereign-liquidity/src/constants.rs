@@ -72,6 +72,41 @@ pub const MAX_UNWIND_VOLUME_THRESHOLD_BPS: u16 = 500;
 /// Timelock period after passed vote (2 days)
 pub const TIMELOCK_PERIOD: i64 = 2 * ONE_DAY;
 
+/// Depositor exit window after a bond deadline extension (2 days)
+pub const BOND_EXTENSION_EXIT_WINDOW: i64 = 2 * ONE_DAY;
+
+/// Partial-success vote window after a missed bond deadline (3 days)
+pub const PARTIAL_SUCCESS_VOTE_PERIOD: i64 = 3 * ONE_DAY;
+
+/// Time allowed for finalization once a raise succeeds, after which anyone
+/// can mark the sovereign stalled and open refunds (7 days)
+pub const FINALIZATION_TIMEOUT: i64 = SEVEN_DAYS;
+
+/// Maximum delay between pool creation and trading open (1 day)
+pub const MAX_LAUNCH_OPEN_DELAY: i64 = ONE_DAY;
+
+/// Maximum anti-sniper window after finalization (1 day)
+pub const MAX_LAUNCH_GUARD_WINDOW: i64 = ONE_DAY;
+
+/// Maximum early-depositor share multiplier (1.5x = 15000 bps)
+pub const MAX_EARLY_BIRD_MULTIPLIER_BPS: u16 = 15000;
+
+/// Narrowest concentrated LP band (launch price / 1.1 to launch price * 1.1)
+pub const MIN_LP_PRICE_BAND_BPS: u32 = 11_000;
+
+/// Widest concentrated LP band (launch price / 100 to launch price * 100)
+pub const MAX_LP_PRICE_BAND_BPS: u32 = 1_000_000;
+
+/// Maximum deviation of an existing SAMM pool's price from the price implied
+/// by the raise when a BYO sovereign adds liquidity to it (5%)
+pub const MAX_EXISTING_POOL_PRICE_DEVIATION_BPS: u16 = 500;
+
+/// Maximum extra LP positions (ladder rungs) besides the primary position
+pub const MAX_LP_LADDER_RUNGS: usize = 3;
+
+/// Minimum share of the raise left to the primary LP position (10%)
+pub const MIN_PRIMARY_LP_SHARE_BPS: u16 = 1000;
+
 // ============================================================
 // BASIS POINTS
 // ============================================================
@@ -82,18 +117,27 @@ pub const BPS_100_PERCENT: u16 = 10000;
 /// BPS denominator (alias for compatibility)
 pub const BPS_DENOMINATOR: u16 = 10000;
 
+/// Deposit-weighted supermajority to accept a partial raise (66.67%)
+pub const PARTIAL_SUCCESS_THRESHOLD_BPS: u16 = 6667;
+
 /// Maximum protocol fee (5% = 500 bps)
 pub const MAX_PROTOCOL_FEE_BPS: u16 = 500;
 
 /// Maximum sell fee (3% = 300 bps)
 pub const MAX_SELL_FEE_BPS: u16 = 300;
 
+/// Epochs until a Token-2022 set_transfer_fee takes effect
+pub const TRANSFER_FEE_EFFECTIVE_EPOCHS: u64 = 2;
+
 /// Maximum creation fee (10% = 1000 bps)
 pub const MAX_CREATION_FEE_BPS: u16 = 1000;
 
 /// Maximum unwind fee (20% = 2000 bps)
 pub const MAX_UNWIND_FEE_BPS: u16 = 2000;
 
+/// Maximum share of the creation fee paid to referrers (50% = 5000 bps)
+pub const MAX_REFERRAL_FEE_BPS: u16 = 5000;
+
 /// Default creation fee (0.5% = 50 bps)
 pub const DEFAULT_CREATION_FEE_BPS: u16 = 50;
 
@@ -140,6 +184,18 @@ pub const POOL_STATUS_DISABLE_OPEN_POSITION: u8 = 0b00001;
 /// Pool status: All operations allowed
 pub const POOL_STATUS_ALLOW_ALL: u8 = 0b00000;
 
+/// Maximum pool vaults tracked per sovereign for transfer hook classification
+pub const MAX_POOL_VAULTS: usize = 8;
+
+// ============================================================
+// CONSTANT-PRODUCT AMM (CPMM) CONSTANTS
+// ============================================================
+
+/// Lamports the cranker fronts to sol_vault for the CPMM pool accounts' rent
+/// (on top of the AMM config's create_pool_fee); the unused part is refunded
+/// in the same instruction
+pub const CPMM_POOL_RENT_RESERVE: u64 = 100_000_000;
+
 // ============================================================
 // PROTOCOL DEFAULTS
 // ============================================================
@@ -172,12 +228,19 @@ pub const DEPOSIT_RECORD_SEED: &[u8] = b"deposit_record";
 pub const CREATOR_TRACKER_SEED: &[u8] = b"creator_tracker";
 pub const CREATOR_FEE_TRACKER_SEED: &[u8] = b"creator_tracker"; // Alias
 pub const PERMANENT_LOCK_SEED: &[u8] = b"permanent_lock";
+pub const LOCKED_POSITION_SEED: &[u8] = b"locked_position";
 pub const TOKEN_MINT_SEED: &[u8] = b"token_mint";
 pub const TOKEN_VAULT_SEED: &[u8] = b"token_vault";
 pub const SOL_VAULT_SEED: &[u8] = b"sol_vault";
 pub const CREATION_FEE_ESCROW_SEED: &[u8] = b"creation_fee_escrow";
+pub const CREATOR_TOKEN_VAULT_SEED: &[u8] = b"creator_token_vault";
+pub const POOL_VAULT_REGISTRY_SEED: &[u8] = b"pool_vault_registry";
+pub const REFERRAL_RECORD_SEED: &[u8] = b"referral_record";
+pub const QUOTE_MINT_CONFIG_SEED: &[u8] = b"quote_mint_config";
+pub const QUOTE_VAULT_SEED: &[u8] = b"quote_vault";
 pub const PROPOSAL_SEED: &[u8] = b"proposal";
 pub const VOTE_RECORD_SEED: &[u8] = b"vote_record";
+pub const PROPOSAL_FEE_ESCROW_SEED: &[u8] = b"proposal_fee_escrow";
 pub const GENESIS_NFT_SEED: &[u8] = b"genesis_nft";
 pub const GENESIS_NFT_MINT_SEED: &[u8] = b"genesis_nft_mint";
 
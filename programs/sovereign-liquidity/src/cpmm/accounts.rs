@@ -0,0 +1,179 @@
+//! CPMM Account Structures
+//!
+//! These structures are used to deserialize account data from the CPMM program.
+//! Only the leading fields the protocol reads are parsed; they mirror the
+//! Raydium CP-Swap layouts.
+
+use anchor_lang::prelude::*;
+use crate::errors::SovereignError;
+
+// ============================================================
+// POOL STATE
+// ============================================================
+
+/// The pool state account of a constant-product pool
+#[derive(Clone)]
+pub struct CpmmPoolState {
+    /// AMM config (fee tier)
+    pub amm_config: Pubkey,
+    /// Pool creator
+    pub pool_creator: Pubkey,
+    /// Token 0 vault
+    pub token_0_vault: Pubkey,
+    /// Token 1 vault
+    pub token_1_vault: Pubkey,
+    /// LP token mint
+    pub lp_mint: Pubkey,
+    /// Token 0 mint (lower pubkey)
+    pub token_0_mint: Pubkey,
+    /// Token 1 mint (higher pubkey)
+    pub token_1_mint: Pubkey,
+    /// Token 0 program
+    pub token_0_program: Pubkey,
+    /// Token 1 program
+    pub token_1_program: Pubkey,
+    /// Observation state
+    pub observation_key: Pubkey,
+    /// Authority PDA bump
+    pub auth_bump: u8,
+    /// Status bits (deposit / withdraw / swap disabled)
+    pub status: u8,
+    /// LP mint decimals
+    pub lp_mint_decimals: u8,
+    /// Token 0 decimals
+    pub mint_0_decimals: u8,
+    /// Token 1 decimals
+    pub mint_1_decimals: u8,
+    /// LP tokens outstanding (including the permanently locked minimum)
+    pub lp_supply: u64,
+    /// Protocol fees held in vault 0, not part of the reserves
+    pub protocol_fees_token_0: u64,
+    /// Protocol fees held in vault 1, not part of the reserves
+    pub protocol_fees_token_1: u64,
+    /// Fund fees held in vault 0, not part of the reserves
+    pub fund_fees_token_0: u64,
+    /// Fund fees held in vault 1, not part of the reserves
+    pub fund_fees_token_1: u64,
+    /// Trading open time
+    pub open_time: u64,
+}
+
+impl CpmmPoolState {
+    /// Bytes parsed, including the discriminator
+    pub const PARSED_LEN: usize = 8 + 373;
+
+    /// Anchor discriminator for "account:PoolState"
+    pub const DISCRIMINATOR: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+
+    /// Deserialize from account data (checks the discriminator)
+    pub fn try_deserialize(data: &[u8]) -> Result<Self> {
+        if data.len() < Self::PARSED_LEN || data[0..8] != Self::DISCRIMINATOR {
+            return Err(error!(SovereignError::InvalidAccountData));
+        }
+
+        let data = &data[8..];
+        let pubkey_at = |i: usize| Pubkey::try_from(&data[i * 32..(i + 1) * 32]).unwrap();
+        let u64_at = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Ok(Self {
+            amm_config: pubkey_at(0),
+            pool_creator: pubkey_at(1),
+            token_0_vault: pubkey_at(2),
+            token_1_vault: pubkey_at(3),
+            lp_mint: pubkey_at(4),
+            token_0_mint: pubkey_at(5),
+            token_1_mint: pubkey_at(6),
+            token_0_program: pubkey_at(7),
+            token_1_program: pubkey_at(8),
+            observation_key: pubkey_at(9),
+            auth_bump: data[320],
+            status: data[321],
+            lp_mint_decimals: data[322],
+            mint_0_decimals: data[323],
+            mint_1_decimals: data[324],
+            lp_supply: u64_at(325),
+            protocol_fees_token_0: u64_at(333),
+            protocol_fees_token_1: u64_at(341),
+            fund_fees_token_0: u64_at(349),
+            fund_fees_token_1: u64_at(357),
+            open_time: u64_at(365),
+        })
+    }
+
+    /// Trading reserves given the vault balances (vaults also hold uncollected
+    /// protocol and fund fees)
+    pub fn reserves(&self, vault_0_amount: u64, vault_1_amount: u64) -> Option<(u64, u64)> {
+        let reserve_0 = vault_0_amount
+            .checked_sub(self.protocol_fees_token_0)?
+            .checked_sub(self.fund_fees_token_0)?;
+        let reserve_1 = vault_1_amount
+            .checked_sub(self.protocol_fees_token_1)?
+            .checked_sub(self.fund_fees_token_1)?;
+        Some((reserve_0, reserve_1))
+    }
+}
+
+// ============================================================
+// AMM CONFIG
+// ============================================================
+
+/// AMM configuration account (fee tier)
+#[derive(Clone)]
+pub struct CpmmAmmConfig {
+    /// Bump seed
+    pub bump: u8,
+    /// Disable create pool flag
+    pub disable_create_pool: bool,
+    /// Index of the config
+    pub index: u16,
+    /// Trade fee rate in hundredths of a bip (1e-6)
+    pub trade_fee_rate: u64,
+    /// Protocol fee rate (share of the trade fee)
+    pub protocol_fee_rate: u64,
+    /// Fund fee rate (share of the trade fee)
+    pub fund_fee_rate: u64,
+    /// Lamports charged to the pool creator
+    pub create_pool_fee: u64,
+    /// Protocol owner
+    pub protocol_owner: Pubkey,
+    /// Fund owner
+    pub fund_owner: Pubkey,
+}
+
+impl CpmmAmmConfig {
+    /// Bytes parsed, including the discriminator
+    pub const PARSED_LEN: usize = 8 + 100;
+
+    /// Anchor discriminator for "account:AmmConfig"
+    pub const DISCRIMINATOR: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+
+    /// Deserialize from account data (checks the discriminator)
+    pub fn try_deserialize(data: &[u8]) -> Result<Self> {
+        if data.len() < Self::PARSED_LEN || data[0..8] != Self::DISCRIMINATOR {
+            return Err(error!(SovereignError::InvalidAccountData));
+        }
+
+        let data = &data[8..];
+
+        Ok(Self {
+            bump: data[0],
+            disable_create_pool: data[1] != 0,
+            index: u16::from_le_bytes([data[2], data[3]]),
+            trade_fee_rate: u64::from_le_bytes(data[4..12].try_into().unwrap()),
+            protocol_fee_rate: u64::from_le_bytes(data[12..20].try_into().unwrap()),
+            fund_fee_rate: u64::from_le_bytes(data[20..28].try_into().unwrap()),
+            create_pool_fee: u64::from_le_bytes(data[28..36].try_into().unwrap()),
+            protocol_owner: Pubkey::try_from(&data[36..68]).unwrap(),
+            fund_owner: Pubkey::try_from(&data[68..100]).unwrap(),
+        })
+    }
+
+    /// Trade fee rate in whole basis points, rounded up
+    /// (1 bps = 100 hundredths of a bip)
+    pub fn trade_fee_bps(&self) -> Option<u16> {
+        if self.trade_fee_rate > 1_000_000 {
+            return None;
+        }
+        u16::try_from(self.trade_fee_rate.div_ceil(100)).ok()
+    }
+}
@@ -0,0 +1,318 @@
+//! CPMM CPI (Cross-Program Invocation) Helpers
+//!
+//! High-level helper functions for CPI calls to the CPMM program, and the LP
+//! share math the permanent lock uses to tell fees from principal.
+//!
+//! ## LP Share Math
+//!
+//! A constant-product pool's sqrt(k) = sqrt(reserve_0 * reserve_1) moves in
+//! proportion to the LP supply on deposits and withdrawals, and grows on swaps
+//! as the trading fee stays in the reserves. sqrt(k) per LP token therefore only
+//! grows with fees, which makes it the CPMM's fee growth accumulator.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::Instruction,
+    program::invoke_signed,
+};
+use super::instructions::*;
+use crate::samm::instructions::{readonly, readonly_signer, writable, writable_signer};
+use crate::samm::tick_math;
+
+// ============================================================
+// INITIALIZE CPI
+// ============================================================
+
+/// Create the pool and deposit the initial liquidity
+///
+/// The creator must carry no data (it pays rent through the system program)
+/// and receives the LP tokens in its LP ATA.
+///
+/// # Arguments
+///
+/// * `accounts` - Required accounts for the CPI
+/// * `init_amount_0` / `init_amount_1` - Initial deposit
+/// * `open_time` - Trading open time
+/// * `signer_seeds` - PDA signer seeds (for the creator)
+pub fn initialize<'info>(
+    cpmm_program: &AccountInfo<'info>,
+    accounts: InitializeAccounts<'info>,
+    init_amount_0: u64,
+    init_amount_1: u64,
+    open_time: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = InitializeArgs {
+        init_amount_0,
+        init_amount_1,
+        open_time,
+    };
+
+    let account_metas = vec![
+        writable_signer(accounts.creator.key()),
+        readonly(accounts.amm_config.key()),
+        readonly(accounts.authority.key()),
+        writable(accounts.pool_state.key()),
+        readonly(accounts.token_0_mint.key()),
+        readonly(accounts.token_1_mint.key()),
+        writable(accounts.lp_mint.key()),
+        writable(accounts.creator_token_0.key()),
+        writable(accounts.creator_token_1.key()),
+        writable(accounts.creator_lp_token.key()),
+        writable(accounts.token_0_vault.key()),
+        writable(accounts.token_1_vault.key()),
+        writable(accounts.create_pool_fee.key()),
+        writable(accounts.observation_state.key()),
+        readonly(accounts.token_program.key()),
+        readonly(accounts.token_0_program.key()),
+        readonly(accounts.token_1_program.key()),
+        readonly(accounts.associated_token_program.key()),
+        readonly(accounts.system_program.key()),
+        readonly(accounts.rent.key()),
+    ];
+
+    let ix = Instruction {
+        program_id: cpmm_program.key(),
+        accounts: account_metas,
+        data: args.to_instruction_data(),
+    };
+
+    let account_infos = vec![
+        accounts.creator,
+        accounts.amm_config,
+        accounts.authority,
+        accounts.pool_state,
+        accounts.token_0_mint,
+        accounts.token_1_mint,
+        accounts.lp_mint,
+        accounts.creator_token_0,
+        accounts.creator_token_1,
+        accounts.creator_lp_token,
+        accounts.token_0_vault,
+        accounts.token_1_vault,
+        accounts.create_pool_fee,
+        accounts.observation_state,
+        accounts.token_program,
+        accounts.token_0_program,
+        accounts.token_1_program,
+        accounts.associated_token_program,
+        accounts.system_program,
+        accounts.rent,
+        cpmm_program.clone(),
+    ];
+
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+// ============================================================
+// WITHDRAW CPI
+// ============================================================
+
+/// Burn LP tokens for the underlying reserves
+///
+/// Used both to harvest fees (the LP tokens above the principal) and to
+/// fully exit during an unwind.
+///
+/// # Arguments
+///
+/// * `accounts` - Required accounts for the CPI
+/// * `lp_token_amount` - LP tokens to burn
+/// * `minimum_token_0_amount` / `minimum_token_1_amount` - Slippage protection
+/// * `signer_seeds` - PDA signer seeds (for the permanent lock)
+pub fn withdraw<'info>(
+    cpmm_program: &AccountInfo<'info>,
+    accounts: WithdrawAccounts<'info>,
+    lp_token_amount: u64,
+    minimum_token_0_amount: u64,
+    minimum_token_1_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = WithdrawArgs {
+        lp_token_amount,
+        minimum_token_0_amount,
+        minimum_token_1_amount,
+    };
+
+    let account_metas = vec![
+        readonly_signer(accounts.owner.key()),
+        readonly(accounts.authority.key()),
+        writable(accounts.pool_state.key()),
+        writable(accounts.owner_lp_token.key()),
+        writable(accounts.token_0_account.key()),
+        writable(accounts.token_1_account.key()),
+        writable(accounts.token_0_vault.key()),
+        writable(accounts.token_1_vault.key()),
+        readonly(accounts.token_program.key()),
+        readonly(accounts.token_program_2022.key()),
+        readonly(accounts.vault_0_mint.key()),
+        readonly(accounts.vault_1_mint.key()),
+        writable(accounts.lp_mint.key()),
+        readonly(accounts.memo_program.key()),
+    ];
+
+    let ix = Instruction {
+        program_id: cpmm_program.key(),
+        accounts: account_metas,
+        data: args.to_instruction_data(),
+    };
+
+    let account_infos = vec![
+        accounts.owner,
+        accounts.authority,
+        accounts.pool_state,
+        accounts.owner_lp_token,
+        accounts.token_0_account,
+        accounts.token_1_account,
+        accounts.token_0_vault,
+        accounts.token_1_vault,
+        accounts.token_program,
+        accounts.token_program_2022,
+        accounts.vault_0_mint,
+        accounts.vault_1_mint,
+        accounts.lp_mint,
+        accounts.memo_program,
+        cpmm_program.clone(),
+    ];
+
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+// ============================================================
+// SWAP CPI
+// ============================================================
+
+/// Execute an exact-input swap
+///
+/// # Arguments
+///
+/// * `accounts` - Required accounts for the CPI
+/// * `amount_in` - Input amount
+/// * `minimum_amount_out` - Minimum output (slippage protection)
+/// * `signer_seeds` - PDA signer seeds (for the input account owner)
+pub fn swap_base_input<'info>(
+    cpmm_program: &AccountInfo<'info>,
+    accounts: SwapBaseInputAccounts<'info>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = SwapBaseInputArgs {
+        amount_in,
+        minimum_amount_out,
+    };
+
+    let account_metas = vec![
+        readonly_signer(accounts.payer.key()),
+        readonly(accounts.authority.key()),
+        readonly(accounts.amm_config.key()),
+        writable(accounts.pool_state.key()),
+        writable(accounts.input_token_account.key()),
+        writable(accounts.output_token_account.key()),
+        writable(accounts.input_vault.key()),
+        writable(accounts.output_vault.key()),
+        readonly(accounts.input_token_program.key()),
+        readonly(accounts.output_token_program.key()),
+        readonly(accounts.input_token_mint.key()),
+        readonly(accounts.output_token_mint.key()),
+        writable(accounts.observation_state.key()),
+    ];
+
+    let ix = Instruction {
+        program_id: cpmm_program.key(),
+        accounts: account_metas,
+        data: args.to_instruction_data(),
+    };
+
+    let account_infos = vec![
+        accounts.payer,
+        accounts.authority,
+        accounts.amm_config,
+        accounts.pool_state,
+        accounts.input_token_account,
+        accounts.output_token_account,
+        accounts.input_vault,
+        accounts.output_vault,
+        accounts.input_token_program,
+        accounts.output_token_program,
+        accounts.input_token_mint,
+        accounts.output_token_mint,
+        accounts.observation_state,
+        cpmm_program.clone(),
+    ];
+
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+// ============================================================
+// LP SHARE MATH
+// ============================================================
+
+/// sqrt(reserve_0 * reserve_1), rounded down
+pub fn sqrt_k(reserve_0: u64, reserve_1: u64) -> u128 {
+    (reserve_0 as u128 * reserve_1 as u128).isqrt()
+}
+
+/// sqrt(k) per LP token as Q64.64 - the pool's fee growth accumulator
+pub fn sqrt_k_per_lp_x64(reserve_0: u64, reserve_1: u64, lp_supply: u64) -> Option<u128> {
+    if lp_supply == 0 {
+        return None;
+    }
+    tick_math::mul_div_floor(sqrt_k(reserve_0, reserve_1), 1u128 << 64, lp_supply as u128)
+}
+
+/// sqrt(k) units owned by `lp_amount` LP tokens, rounded down
+pub fn lp_to_sqrt_k(lp_amount: u64, reserve_0: u64, reserve_1: u64, lp_supply: u64) -> Option<u128> {
+    tick_math::mul_div_floor(lp_amount as u128, sqrt_k(reserve_0, reserve_1), lp_supply as u128)
+}
+
+/// Reserves (0, 1) owned by `lp_amount` LP tokens, rounded down - what
+/// withdrawing them would pay out
+pub fn lp_reserves(lp_amount: u64, reserve_0: u64, reserve_1: u64, lp_supply: u64) -> Option<(u64, u64)> {
+    if lp_supply == 0 {
+        return Some((0, 0));
+    }
+    let share = |reserve: u64| {
+        tick_math::mul_div_floor(reserve as u128, lp_amount as u128, lp_supply as u128)
+            .and_then(|amount| u64::try_from(amount).ok())
+    };
+    Some((share(reserve_0)?, share(reserve_1)?))
+}
+
+/// Fees, in sqrt(k) units, earned by `sqrt_k_amount` units of liquidity since
+/// a `sqrt_k_per_lp_x64` snapshot: they were sqrt_k_amount / snapshot LP tokens
+/// then, and each LP token has since grown by growth - snapshot
+pub fn fee_sqrt_k_since(growth_x64: u128, snapshot_x64: u128, sqrt_k_amount: u128) -> Option<u128> {
+    tick_math::mul_div_floor(growth_x64.saturating_sub(snapshot_x64), sqrt_k_amount, snapshot_x64)
+}
+
+/// LP tokens above a principal of `principal_sqrt_k` sqrt(k) units - the fees
+/// `lp_held` LP tokens have earned. The principal is rounded up in LP tokens,
+/// so harvesting never dips into it.
+pub fn fee_lp_amount(
+    lp_held: u64,
+    principal_sqrt_k: u128,
+    reserve_0: u64,
+    reserve_1: u64,
+    lp_supply: u64,
+) -> Option<u64> {
+    let pool_sqrt_k = sqrt_k(reserve_0, reserve_1);
+    if pool_sqrt_k == 0 {
+        return None;
+    }
+    let principal_lp = tick_math::mul_div_ceil(principal_sqrt_k, lp_supply as u128, pool_sqrt_k)?;
+    Some((lp_held as u128).saturating_sub(principal_lp) as u64)
+}
+
+/// Quote value of `sqrt_k_amount` sqrt(k) units at the pool's current price:
+/// s units are s * sqrt(x/y) quote plus s * sqrt(y/x) tokens, worth
+/// 2 * s * x / sqrt(k) in quote (x = quote reserve)
+pub fn sqrt_k_quote_value(sqrt_k_amount: u128, quote_reserve: u64, token_reserve: u64) -> Option<u128> {
+    let pool_sqrt_k = sqrt_k(quote_reserve, token_reserve);
+    if pool_sqrt_k == 0 {
+        return None;
+    }
+    tick_math::mul_div_floor(sqrt_k_amount.checked_mul(2)?, quote_reserve as u128, pool_sqrt_k)
+}
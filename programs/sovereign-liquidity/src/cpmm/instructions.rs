@@ -0,0 +1,201 @@
+//! CPMM Instruction Data Structures
+//!
+//! Instruction data and account orderings for CPI calls to the CPMM program.
+//! Discriminators are the first 8 bytes of `sha256("global:<instruction_name>")`.
+
+use anchor_lang::prelude::*;
+
+// ============================================================
+// INSTRUCTION DISCRIMINATORS
+// ============================================================
+
+/// Instruction discriminators for CPMM CPI calls
+pub mod discriminators {
+    /// initialize
+    pub const INITIALIZE: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+
+    /// withdraw
+    pub const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+
+    /// swap_base_input
+    pub const SWAP_BASE_INPUT: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
+}
+
+// ============================================================
+// INITIALIZE
+// ============================================================
+
+/// Instruction data for initialize
+/// Creates the pool and deposits the initial liquidity; LP tokens go to the creator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeArgs {
+    /// Token 0 deposited
+    pub init_amount_0: u64,
+    /// Token 1 deposited
+    pub init_amount_1: u64,
+    /// Trading open time (unix timestamp)
+    pub open_time: u64,
+}
+
+impl InitializeArgs {
+    /// Serialize to instruction data (with discriminator)
+    pub fn to_instruction_data(&self) -> Vec<u8> {
+        let mut data = discriminators::INITIALIZE.to_vec();
+        data.extend(self.try_to_vec().unwrap());
+        data
+    }
+}
+
+/// Account ordering for initialize CPI
+pub struct InitializeAccounts<'info> {
+    /// [writable, signer] Pool creator - pays rent and the create pool fee
+    pub creator: AccountInfo<'info>,
+    /// [] AMM config
+    pub amm_config: AccountInfo<'info>,
+    /// [] Vault and LP mint authority PDA
+    pub authority: AccountInfo<'info>,
+    /// [writable] Pool state PDA
+    pub pool_state: AccountInfo<'info>,
+    /// [] Token 0 mint
+    pub token_0_mint: AccountInfo<'info>,
+    /// [] Token 1 mint
+    pub token_1_mint: AccountInfo<'info>,
+    /// [writable] LP mint PDA
+    pub lp_mint: AccountInfo<'info>,
+    /// [writable] Creator's token 0 account
+    pub creator_token_0: AccountInfo<'info>,
+    /// [writable] Creator's token 1 account
+    pub creator_token_1: AccountInfo<'info>,
+    /// [writable] Creator's LP token ATA (created by the CPMM)
+    pub creator_lp_token: AccountInfo<'info>,
+    /// [writable] Token 0 vault PDA
+    pub token_0_vault: AccountInfo<'info>,
+    /// [writable] Token 1 vault PDA
+    pub token_1_vault: AccountInfo<'info>,
+    /// [writable] Create pool fee receiver
+    pub create_pool_fee: AccountInfo<'info>,
+    /// [writable] Observation state PDA
+    pub observation_state: AccountInfo<'info>,
+    /// [] Token program (LP mint)
+    pub token_program: AccountInfo<'info>,
+    /// [] Token 0 program
+    pub token_0_program: AccountInfo<'info>,
+    /// [] Token 1 program
+    pub token_1_program: AccountInfo<'info>,
+    /// [] Associated token program
+    pub associated_token_program: AccountInfo<'info>,
+    /// [] System program
+    pub system_program: AccountInfo<'info>,
+    /// [] Rent sysvar
+    pub rent: AccountInfo<'info>,
+}
+
+// ============================================================
+// WITHDRAW
+// ============================================================
+
+/// Instruction data for withdraw
+/// Burns LP tokens for a pro-rata share of the reserves
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawArgs {
+    /// LP tokens to burn
+    pub lp_token_amount: u64,
+    /// Minimum token 0 to receive
+    pub minimum_token_0_amount: u64,
+    /// Minimum token 1 to receive
+    pub minimum_token_1_amount: u64,
+}
+
+impl WithdrawArgs {
+    /// Serialize to instruction data (with discriminator)
+    pub fn to_instruction_data(&self) -> Vec<u8> {
+        let mut data = discriminators::WITHDRAW.to_vec();
+        data.extend(self.try_to_vec().unwrap());
+        data
+    }
+}
+
+/// Account ordering for withdraw CPI
+pub struct WithdrawAccounts<'info> {
+    /// [signer] LP token owner
+    pub owner: AccountInfo<'info>,
+    /// [] Vault and LP mint authority PDA
+    pub authority: AccountInfo<'info>,
+    /// [writable] Pool state
+    pub pool_state: AccountInfo<'info>,
+    /// [writable] Owner's LP token account
+    pub owner_lp_token: AccountInfo<'info>,
+    /// [writable] Recipient token 0 account
+    pub token_0_account: AccountInfo<'info>,
+    /// [writable] Recipient token 1 account
+    pub token_1_account: AccountInfo<'info>,
+    /// [writable] Token 0 vault
+    pub token_0_vault: AccountInfo<'info>,
+    /// [writable] Token 1 vault
+    pub token_1_vault: AccountInfo<'info>,
+    /// [] Token program
+    pub token_program: AccountInfo<'info>,
+    /// [] Token program 2022
+    pub token_program_2022: AccountInfo<'info>,
+    /// [] Vault 0 mint
+    pub vault_0_mint: AccountInfo<'info>,
+    /// [] Vault 1 mint
+    pub vault_1_mint: AccountInfo<'info>,
+    /// [writable] LP mint
+    pub lp_mint: AccountInfo<'info>,
+    /// [] Memo program
+    pub memo_program: AccountInfo<'info>,
+}
+
+// ============================================================
+// SWAP BASE INPUT
+// ============================================================
+
+/// Instruction data for swap_base_input
+/// Exact-input swap with a minimum output
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SwapBaseInputArgs {
+    /// Input amount
+    pub amount_in: u64,
+    /// Minimum output amount
+    pub minimum_amount_out: u64,
+}
+
+impl SwapBaseInputArgs {
+    /// Serialize to instruction data (with discriminator)
+    pub fn to_instruction_data(&self) -> Vec<u8> {
+        let mut data = discriminators::SWAP_BASE_INPUT.to_vec();
+        data.extend(self.try_to_vec().unwrap());
+        data
+    }
+}
+
+/// Account ordering for swap_base_input CPI
+pub struct SwapBaseInputAccounts<'info> {
+    /// [signer] Owner of the input token account
+    pub payer: AccountInfo<'info>,
+    /// [] Vault and LP mint authority PDA
+    pub authority: AccountInfo<'info>,
+    /// [] AMM config
+    pub amm_config: AccountInfo<'info>,
+    /// [writable] Pool state
+    pub pool_state: AccountInfo<'info>,
+    /// [writable] Input token account
+    pub input_token_account: AccountInfo<'info>,
+    /// [writable] Output token account
+    pub output_token_account: AccountInfo<'info>,
+    /// [writable] Input vault
+    pub input_vault: AccountInfo<'info>,
+    /// [writable] Output vault
+    pub output_vault: AccountInfo<'info>,
+    /// [] Input token program
+    pub input_token_program: AccountInfo<'info>,
+    /// [] Output token program
+    pub output_token_program: AccountInfo<'info>,
+    /// [] Input token mint
+    pub input_token_mint: AccountInfo<'info>,
+    /// [] Output token mint
+    pub output_token_mint: AccountInfo<'info>,
+    /// [writable] Observation state
+    pub observation_state: AccountInfo<'info>,
+}
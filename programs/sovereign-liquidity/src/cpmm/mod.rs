@@ -0,0 +1,42 @@
+//! Constant-Product AMM (Raydium CP-Swap Layout) Integration Module
+//!
+//! This module provides CPI support for a constant-product AMM following the
+//! Raydium CP-Swap interface, offered as an alternative to the Trashbin SAMM.
+//! The program address is not fixed: the protocol authority sets it
+//! (`ProtocolState::cpmm_program`) and each CPMM sovereign records it at creation.
+//!
+//! ## Key Components
+//!
+//! - **accounts**: Account structures for deserializing CPMM program accounts
+//! - **instructions**: Instruction builders for CPMM CPI calls
+//! - **cpi**: High-level CPI helper functions and LP share math
+//!
+//! ## Supported Operations
+//!
+//! - `initialize` - Create the pool and deposit the initial liquidity
+//! - `withdraw` - Burn LP tokens for the underlying reserves
+//! - `swap_base_input` - Exact-input swap
+//!
+//! Trading fees stay in the reserves, so LP tokens have no separate fee balance.
+//! The permanent lock records its principal in sqrt(k) units and harvests fees by
+//! withdrawing the LP tokens above it (see `cpi::fee_lp_amount`).
+
+pub mod accounts;
+pub mod instructions;
+pub mod cpi;
+
+pub use accounts::*;
+pub use instructions::*;
+pub use cpi::*;
+
+/// PDA seeds used by the CPMM program
+pub mod pda_seeds {
+    /// Vault and LP mint authority: [AUTH_SEED]
+    pub const AUTH_SEED: &[u8] = b"vault_and_lp_mint_auth_seed";
+    /// Pool vault: [POOL_VAULT_SEED, pool_state, mint]
+    pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+    /// LP mint: [POOL_LP_MINT_SEED, pool_state]
+    pub const POOL_LP_MINT_SEED: &[u8] = b"pool_lp_mint";
+    /// Observation state: [OBSERVATION_SEED, pool_state]
+    pub const OBSERVATION_SEED: &[u8] = b"observation";
+}
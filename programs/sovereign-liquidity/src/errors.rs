@@ -442,4 +442,317 @@ pub enum SovereignError {
 
     #[msg("Insufficient remaining accounts provided")]
     InsufficientAccounts,
+
+    // ============================================================
+    // CREATOR MARKET BUY ERRORS (6360-6379)
+    // ============================================================
+
+    #[msg("AMM config does not match the sovereign's amm_config")]
+    InvalidAmmConfig,
+
+    #[msg("Escrow WGOR account must be a WGOR token account owned by the sovereign")]
+    InvalidEscrowWgorAccount,
+
+    // ============================================================
+    // CREATION FEE ERRORS (6380-6399)
+    // ============================================================
+
+    #[msg("Creation fee has already been released")]
+    CreationFeeAlreadyReleased,
+
+    // ============================================================
+    // GOVERNANCE FEE ERRORS (6400-6419)
+    // ============================================================
+
+    #[msg("Proposal fee must be 0 or cover the fee escrow's rent-exempt minimum")]
+    ProposalFeeBelowRentExempt,
+
+    // ============================================================
+    // TRANSFER HOOK ERRORS (6420-6439)
+    // ============================================================
+
+    #[msg("Transfer hook called outside a Token-2022 transfer")]
+    NotTransferring,
+
+    // ============================================================
+    // POOL VAULT REGISTRY ERRORS (6440-6459)
+    // ============================================================
+
+    #[msg("Pool vault is already registered")]
+    PoolVaultAlreadyRegistered,
+
+    #[msg("Pool vault registry is full")]
+    PoolVaultRegistryFull,
+
+    #[msg("Invalid pool vault - does not match the SAMM pool vault PDA")]
+    InvalidPoolVault,
+
+    // ============================================================
+    // SELL FEE DECAY ERRORS (6460-6479)
+    // ============================================================
+
+    #[msg("Invalid sell fee decay schedule")]
+    InvalidSellFeeDecay,
+
+    #[msg("No active sell fee decay schedule")]
+    SellFeeDecayNotActive,
+
+    #[msg("Sell fee already matches the decay schedule")]
+    SellFeeDecayUpToDate,
+
+    #[msg("Previous sell fee decay step is not in effect yet (Token-2022 applies it two epochs later)")]
+    SellFeeDecayPending,
+
+    // ============================================================
+    // LAUNCH GUARD ERRORS (6480-6499)
+    // ============================================================
+
+    #[msg("Invalid launch guard configuration")]
+    InvalidLaunchGuard,
+
+    #[msg("Buy exceeds the launch window per-transaction cap")]
+    LaunchMaxBuyExceeded,
+
+    #[msg("Wallet balance exceeds the launch window cap")]
+    LaunchMaxWalletExceeded,
+
+    #[msg("Creator buy-in is unavailable when pool open is delayed")]
+    CreatorBuyBlockedByOpenDelay,
+
+    // ============================================================
+    // ALLOWLIST ERRORS (6500-6519)
+    // ============================================================
+
+    #[msg("Invalid allowlist configuration")]
+    InvalidAllowlistConfig,
+
+    #[msg("Allowlist proof required during the private round")]
+    AllowlistProofRequired,
+
+    #[msg("Invalid allowlist proof")]
+    InvalidAllowlistProof,
+
+    #[msg("Deposit exceeds allowlist cap for this wallet")]
+    AllowlistCapExceeded,
+
+    // ============================================================
+    // DEPOSIT LIMIT ERRORS (6520-6539)
+    // ============================================================
+
+    #[msg("Invalid deposit limits")]
+    InvalidDepositLimits,
+
+    #[msg("Deposit exceeds per-wallet cap")]
+    DepositExceedsWalletCap,
+
+    #[msg("Remaining bond room is reserved for the depositors needed to reach min_depositor_count")]
+    BondReservedForNewDepositors,
+
+    // ============================================================
+    // SOFT CAP ERRORS (6540-6559)
+    // ============================================================
+
+    #[msg("Soft cap must be between the protocol minimum and the hard cap")]
+    InvalidSoftCap,
+
+    #[msg("Soft cap not met")]
+    SoftCapNotMet,
+
+    #[msg("Minimum depositor count not met")]
+    MinDepositorsNotMet,
+
+    // ============================================================
+    // PARTIAL SUCCESS ERRORS (6560-6579)
+    // ============================================================
+
+    #[msg("Partial success vote is not open")]
+    PartialSuccessVoteNotOpen,
+
+    #[msg("Already voted to accept the partial raise")]
+    PartialSuccessAlreadyVoted,
+
+    #[msg("Partial success vote period has not ended")]
+    PartialSuccessVoteActive,
+
+    #[msg("Raise is below the minimum bond target and cannot be accepted")]
+    PartialRaiseBelowMinimum,
+
+    // ============================================================
+    // DEADLINE EXTENSION ERRORS (6580-6599)
+    // ============================================================
+
+    #[msg("Bond deadline has already been extended")]
+    DeadlineAlreadyExtended,
+
+    #[msg("Invalid bond deadline extension")]
+    InvalidDeadlineExtension,
+
+    #[msg("Depositor exit window is still open")]
+    ExitWindowActive,
+
+    // ============================================================
+    // CANCELLATION ERRORS (6600-6619)
+    // ============================================================
+
+    #[msg("Sovereign cannot be cancelled after deposits")]
+    CannotCancelWithDeposits,
+
+    #[msg("Cancelling a sovereign with a token mint requires its token vault, mint and token program (and the creator's token account for BYO tokens)")]
+    MissingCancelTokenAccounts,
+
+    // ============================================================
+    // DEPOSIT FOR ERRORS (6620-6639)
+    // ============================================================
+
+    #[msg("Invalid beneficiary - the creator cannot be a deposit beneficiary")]
+    InvalidBeneficiary,
+
+    // ============================================================
+    // REFERRAL ERRORS (6640-6659)
+    // ============================================================
+
+    #[msg("Invalid referrer - cannot refer yourself or the creator")]
+    InvalidReferrer,
+
+    #[msg("Referrer does not match the referrer of the existing deposit")]
+    ReferrerMismatch,
+
+    #[msg("Referral record account required for a referred deposit")]
+    ReferralRecordRequired,
+
+    #[msg("Referral fee exceeds maximum (50%)")]
+    ReferralFeeExceedsMax,
+
+    #[msg("Referral rewards are not available for this sovereign")]
+    ReferralRewardsNotAvailable,
+
+    #[msg("Referral reward already claimed")]
+    ReferralRewardAlreadyClaimed,
+
+    #[msg("No referral reward to claim")]
+    NoReferralReward,
+
+    // ============================================================
+    // EARLY BIRD ERRORS (6660-6679)
+    // ============================================================
+
+    #[msg("Invalid early-bird bonus: multiplier must be 1.0x-1.5x and the curve must end by the bond deadline")]
+    InvalidEarlyBirdBonus,
+
+    // ============================================================
+    // QUOTE MINT ERRORS (6680-6699)
+    // ============================================================
+
+    #[msg("Quote mint is not on the protocol allowlist")]
+    QuoteMintNotAllowed,
+
+    #[msg("Invalid quote mint for this sovereign")]
+    InvalidQuoteMint,
+
+    #[msg("Quote token accounts are required for a sovereign with a token quote mint")]
+    QuoteAccountsRequired,
+
+    #[msg("Unsupported quote mint: transfer fee and transfer hook extensions are not allowed")]
+    UnsupportedQuoteMint,
+
+    #[msg("Creator escrow is only available for sovereigns bonding in native GOR")]
+    CreatorEscrowRequiresNativeQuote,
+
+    // ============================================================
+    // TICK MATH ERRORS (6700-6719)
+    // ============================================================
+
+    #[msg("Tick index out of range")]
+    InvalidTick,
+
+    #[msg("Sqrt price out of range")]
+    InvalidSqrtPrice,
+
+    // ============================================================
+    // AMM CONFIG ERRORS (6720-6739)
+    // ============================================================
+
+    #[msg("AMM config must be an AmmConfig account owned by the SAMM program")]
+    InvalidAmmConfigAccount,
+
+    #[msg("AMM config has pool creation disabled")]
+    AmmConfigDisabled,
+
+    #[msg("AMM config trade fee rate is out of range")]
+    InvalidTradeFeeRate,
+
+    // ============================================================
+    // LP PRICE BAND ERRORS (6740-6759)
+    // ============================================================
+
+    #[msg("Invalid LP price band: multiple must be between 1.1x and 100x")]
+    InvalidPriceBand,
+
+    // ============================================================
+    // LP LADDER ERRORS (6760-6779)
+    // ============================================================
+
+    #[msg("Invalid LP ladder: too many rungs, an empty rung, or too little left for the primary position")]
+    InvalidLpLadder,
+
+    #[msg("LP ladder positions must be opened in order before the primary position")]
+    InvalidLadderIndex,
+
+    #[msg("LP ladder positions are still open")]
+    LadderPositionsOutstanding,
+
+    #[msg("LP ladder position account does not match")]
+    InvalidLockedPosition,
+
+    #[msg("Volume threshold met during observation - execute_unwind cancels the unwind")]
+    UnwindVolumeThresholdMet,
+
+    #[msg("LP ladder proceeds must go to the permanent lock's token accounts")]
+    InvalidLadderRecipient,
+
+    // ============================================================
+    // FINALIZATION TIMEOUT ERRORS (6780-6799)
+    // ============================================================
+
+    #[msg("Finalization has not stalled: the sovereign is not finalizable or the timeout has not elapsed")]
+    FinalizationNotStalled,
+
+    // ============================================================
+    // EXISTING POOL ERRORS (6800-6819)
+    // ============================================================
+
+    #[msg("Only BYO token sovereigns can add liquidity to an existing pool")]
+    ExistingPoolRequiresByoToken,
+
+    #[msg("Existing pool does not match the sovereign's mints, AMM config or tick spacing")]
+    InvalidExistingPool,
+
+    #[msg("Existing pool does not allow opening positions")]
+    ExistingPoolClosedToLiquidity,
+
+    #[msg("Existing pool price deviates too far from the price implied by the raise")]
+    ExistingPoolPriceDeviation,
+
+    // ============================================================
+    // AMM ADAPTER ERRORS (6820-6839)
+    // ============================================================
+
+    #[msg("The CPMM backend is not enabled by the protocol")]
+    AmmBackendUnavailable,
+
+    #[msg("AMM program does not match the sovereign's AMM")]
+    InvalidAmmProgram,
+
+    #[msg("Operation or option is not supported by the sovereign's AMM")]
+    UnsupportedByAmm,
+
+    // ============================================================
+    // MIGRATION ERRORS (6840-6859)
+    // ============================================================
+
+    #[msg("Account is not of the type this migration expects")]
+    InvalidMigrationAccount,
+
+    #[msg("Account is already at its current size")]
+    AccountAlreadyMigrated,
 }
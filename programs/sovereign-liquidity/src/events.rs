@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{SovereignType, FeeMode, ProposalStatus};
+use crate::state::{AmmKind, SovereignType, FeeMode, ProposalStatus};
 
 // ============================================================
 // SOVEREIGN LIFECYCLE EVENTS
@@ -11,7 +11,9 @@ pub struct SovereignCreated {
     pub creator: Pubkey,
     pub token_mint: Pubkey,
     pub sovereign_type: SovereignType,
+    pub quote_mint: Pubkey,
     pub bond_target: u64,
+    pub soft_cap: u64,
     pub bond_deadline: i64,
     pub token_supply_deposited: u64,
     pub creation_fee_escrowed: u64,
@@ -19,6 +21,11 @@ pub struct SovereignCreated {
     pub fee_mode: FeeMode,
     pub amm_config: Pubkey,
     pub swap_fee_bps: u16,
+    pub lp_price_band_bps: u32,
+    pub lp_ladder_rungs: u8,
+    pub existing_pool: Pubkey,
+    pub amm_kind: AmmKind,
+    pub amm_program: Pubkey,
 }
 
 #[event]
@@ -42,6 +49,16 @@ pub struct SovereignFinalized {
     pub finalized_at: i64,
 }
 
+#[event]
+pub struct ExistingPoolLinked {
+    pub sovereign_id: u64,
+    pub pool_state: Pubkey,
+    pub pool_sqrt_price_x64: u128,
+    pub implied_sqrt_price_x64: u128,
+    pub deviation_bps: u16,
+    pub linked_at: i64,
+}
+
 #[event]
 pub struct SammPoolCreated {
     pub sovereign_id: u64,
@@ -52,6 +69,19 @@ pub struct SammPoolCreated {
     pub created_at: i64,
 }
 
+#[event]
+pub struct CpmmPoolCreated {
+    pub sovereign_id: u64,
+    pub pool_state: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_mint_0: Pubkey,
+    pub token_mint_1: Pubkey,
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub lp_amount: u64,
+    pub created_at: i64,
+}
+
 #[event]
 pub struct LiquidityAdded {
     pub sovereign_id: u64,
@@ -60,6 +90,52 @@ pub struct LiquidityAdded {
     pub liquidity: u128,
     pub amount_0: u64,
     pub amount_1: u64,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+#[event]
+pub struct SovereignCancelled {
+    pub sovereign_id: u64,
+    pub creator: Pubkey,
+    pub creation_fee_refunded: u64,
+    pub cancellation_fee: u64,
+    pub tokens_returned: u64,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct CancellationFeeUpdated {
+    pub old_fee_lamports: u64,
+    pub new_fee_lamports: u64,
+}
+
+#[event]
+pub struct QuoteMintConfigured {
+    pub mint: Pubkey,
+    pub min_bond_target: u64,
+    pub min_deposit: u64,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct ReferralFeeUpdated {
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+}
+
+#[event]
+pub struct CpmmProgramUpdated {
+    pub old_program: Pubkey,
+    pub new_program: Pubkey,
+}
+
+#[event]
+pub struct BondDeadlineExtended {
+    pub sovereign_id: u64,
+    pub old_deadline: i64,
+    pub new_deadline: i64,
+    pub exit_window_end: i64,
 }
 
 #[event]
@@ -70,6 +146,16 @@ pub struct BondingFailed {
     pub failed_at: i64,
 }
 
+#[event]
+pub struct FinalizationStalled {
+    pub sovereign_id: u64,
+    pub total_deposited: u64,
+    pub finalization_started_at: i64,
+    pub pool_created: bool,
+    pub marked_by: Pubkey,
+    pub failed_at: i64,
+}
+
 // ============================================================
 // DEPOSIT EVENTS
 // ============================================================
@@ -78,11 +164,23 @@ pub struct BondingFailed {
 pub struct InvestorDeposited {
     pub sovereign_id: u64,
     pub depositor: Pubkey,
+    pub payer: Pubkey,
     pub amount: u64,
     pub total_deposited: u64,
     pub depositor_count: u32,
 }
 
+#[event]
+pub struct ReferralVolumeUpdated {
+    pub sovereign_id: u64,
+    pub referrer: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub is_deposit: bool,
+    pub referred_volume: u64,
+    pub total_referred_volume: u64,
+}
+
 #[event]
 pub struct InvestorWithdrew {
     pub sovereign_id: u64,
@@ -108,6 +206,21 @@ pub struct CreatorMarketBuyExecuted {
     pub tokens_received: u64,
 }
 
+/// Market buy stubbed out (localnet/devnet): the escrow stays in sol_vault
+#[event]
+pub struct CreatorMarketBuySkipped {
+    pub sovereign_id: u64,
+    pub creator: Pubkey,
+    pub sol_amount: u64,
+}
+
+#[event]
+pub struct CreatorPurchasedTokensClaimed {
+    pub sovereign_id: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+}
+
 // ============================================================
 // FEE EVENTS
 // ============================================================
@@ -138,6 +251,25 @@ pub struct PoolRestricted {
     pub restricted: bool,
 }
 
+#[event]
+pub struct CreationFeeReleased {
+    pub sovereign_id: u64,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub referral_pool: u64,
+    pub released_at: i64,
+}
+
+#[event]
+pub struct ReferralRewardClaimed {
+    pub sovereign_id: u64,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub referred_volume: u64,
+    pub total_referred_volume: u64,
+    pub referral_pool: u64,
+}
+
 // ============================================================
 // TRANSFER HOOK EVENTS
 // ============================================================
@@ -150,10 +282,18 @@ pub struct TransferHookExecuted {
     pub destination: Pubkey,
     pub amount: u64,
     pub fee_amount: u64,
+    pub is_buy: bool,
     pub is_sell: bool,
     pub fee_mode: FeeMode,
 }
 
+#[event]
+pub struct PoolVaultRegistered {
+    pub sovereign_id: u64,
+    pub vault: Pubkey,
+    pub registered_by: Pubkey,
+}
+
 #[event]
 pub struct TransferFeesHarvested {
     pub sovereign_id: u64,
@@ -193,6 +333,7 @@ pub struct ProposalCreated {
     pub sovereign_id: u64,
     pub proposal_id: u64,
     pub proposer: Pubkey,
+    pub proposal_fee: u64,
     pub created_at: i64,
     pub voting_ends_at: i64,
 }
@@ -219,6 +360,15 @@ pub struct ProposalFinalized {
     pub passed: bool,
 }
 
+#[event]
+pub struct ProposalFeeSettled {
+    pub sovereign_id: u64,
+    pub proposal_id: u64,
+    pub amount: u64,
+    pub refunded: bool,
+    pub recipient: Pubkey,
+}
+
 #[event]
 pub struct UnwindExecuted {
     pub sovereign_id: u64,
@@ -259,6 +409,24 @@ pub struct ActivityCheckExecuted {
 // FAILED BONDING EVENTS
 // ============================================================
 
+#[event]
+pub struct PartialSuccessVoteCast {
+    pub sovereign_id: u64,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub total_votes: u64,
+    pub total_deposited: u64,
+}
+
+#[event]
+pub struct PartialSuccessAccepted {
+    pub sovereign_id: u64,
+    pub original_bond_target: u64,
+    pub total_deposited: u64,
+    pub accepted_at: i64,
+    pub creator_escrow_refunded: u64,
+}
+
 #[event]
 pub struct FailedWithdrawal {
     pub sovereign_id: u64,
@@ -293,6 +461,12 @@ pub struct ProtocolFeesUpdated {
     pub unwind_fee_bps: u16,
 }
 
+#[event]
+pub struct GovernanceUnwindFeeUpdated {
+    pub old_fee_lamports: u64,
+    pub new_fee_lamports: u64,
+}
+
 #[event]
 pub struct FeeThresholdUpdated {
     pub sovereign_id: u64,
@@ -356,3 +530,54 @@ pub struct SovereignRetired {
     pub sovereign_id: u64,
     pub retired_at: i64,
 }
+
+// ============================================================
+// LP LADDER EVENTS
+// ============================================================
+
+#[event]
+pub struct LadderPositionOpened {
+    pub sovereign_id: u64,
+    pub index: u8,
+    pub position_nft_mint: Pubkey,
+    pub liquidity: u128,
+    pub quote_amount: u64,
+    pub token_amount: u64,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+}
+
+#[event]
+pub struct LadderFeesCollected {
+    pub sovereign_id: u64,
+    pub index: u8,
+    pub quote_amount: u64,
+    pub token_amount: u64,
+}
+
+#[event]
+pub struct LadderPositionUnwound {
+    pub sovereign_id: u64,
+    pub index: u8,
+    pub liquidity: u128,
+    pub quote_amount: u64,
+    pub token_amount: u64,
+}
+
+#[event]
+pub struct LadderProceedsRefunded {
+    pub sovereign_id: u64,
+    pub quote_amount: u64,
+    pub token_amount: u64,
+}
+
+// ============================================================
+// MIGRATION EVENTS
+// ============================================================
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+}
@@ -4,15 +4,18 @@ use anchor_spl::token_2022::{
     Token2022,
     spl_token_2022::{
         self,
-        extension::transfer_fee::instruction as transfer_fee_ix,
+        extension::{
+            transfer_fee::instruction as transfer_fee_ix,
+            BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        },
         instruction::{set_authority, AuthorityType},
     },
 };
-use anchor_spl::token_interface::Mint as MintInterface;
+use anchor_spl::token_interface::{Mint as MintInterface, TokenAccount as TokenAccountInterface};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
-use crate::events::{ProtocolFeesUpdated, FeeThresholdUpdated, FeeThresholdRenounced, SellFeeUpdated, SellFeeRenounced};
+use crate::events::{ProtocolFeesUpdated, FeeThresholdUpdated, FeeThresholdRenounced, SellFeeUpdated, SellFeeRenounced, GovernanceUnwindFeeUpdated, PoolVaultRegistered, BondDeadlineExtended, CancellationFeeUpdated, ReferralFeeUpdated, CpmmProgramUpdated, QuoteMintConfigured};
 
 /// Update protocol-level fee parameters
 /// Only callable by protocol authority
@@ -246,6 +249,355 @@ pub fn set_protocol_paused_handler(ctx: Context<SetProtocolPaused>, paused: bool
     Ok(())
 }
 
+/// Set the governance unwind proposal fee
+/// The fee alone funds the proposal fee escrow (a system account), so a non-zero
+/// fee must cover its rent-exempt minimum. Only callable by protocol authority
+#[derive(Accounts)]
+pub struct UpdateGovernanceUnwindFee<'info> {
+    #[account(
+        address = protocol_state.authority @ SovereignError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn update_governance_unwind_fee_handler(
+    ctx: Context<UpdateGovernanceUnwindFee>,
+    new_fee_lamports: u64,
+) -> Result<()> {
+    require!(
+        new_fee_lamports == 0 || new_fee_lamports >= Rent::get()?.minimum_balance(0),
+        SovereignError::ProposalFeeBelowRentExempt
+    );
+    
+    let protocol = &mut ctx.accounts.protocol_state;
+    let old_fee_lamports = protocol.governance_unwind_fee_lamports;
+    protocol.governance_unwind_fee_lamports = new_fee_lamports;
+    
+    emit!(GovernanceUnwindFeeUpdated {
+        old_fee_lamports,
+        new_fee_lamports,
+    });
+    
+    Ok(())
+}
+
+/// Set the fee kept from the creation fee escrow on creator cancellation
+/// Only callable by protocol authority
+#[derive(Accounts)]
+pub struct UpdateCancellationFee<'info> {
+    #[account(
+        address = protocol_state.authority @ SovereignError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn update_cancellation_fee_handler(
+    ctx: Context<UpdateCancellationFee>,
+    new_fee_lamports: u64,
+) -> Result<()> {
+    let protocol = &mut ctx.accounts.protocol_state;
+    let old_fee_lamports = protocol.cancellation_fee_lamports;
+    protocol.cancellation_fee_lamports = new_fee_lamports;
+    
+    emit!(CancellationFeeUpdated {
+        old_fee_lamports,
+        new_fee_lamports,
+    });
+    
+    Ok(())
+}
+
+/// Set the share of the creation fee paid to referrers
+/// Applies to sovereigns created after the update. Only callable by protocol authority
+#[derive(Accounts)]
+pub struct UpdateReferralFee<'info> {
+    #[account(
+        address = protocol_state.authority @ SovereignError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn update_referral_fee_handler(
+    ctx: Context<UpdateReferralFee>,
+    new_fee_bps: u16,
+) -> Result<()> {
+    require!(
+        new_fee_bps <= MAX_REFERRAL_FEE_BPS,
+        SovereignError::ReferralFeeExceedsMax
+    );
+    
+    let protocol = &mut ctx.accounts.protocol_state;
+    let old_fee_bps = protocol.referral_fee_bps;
+    protocol.referral_fee_bps = new_fee_bps;
+    
+    emit!(ReferralFeeUpdated {
+        old_fee_bps,
+        new_fee_bps,
+    });
+    
+    Ok(())
+}
+
+/// Set the constant-product AMM program sovereigns may choose instead of the SAMM
+/// Existing CPMM sovereigns keep the program recorded at their creation.
+/// Pubkey::default() disables new CPMM sovereigns. Only callable by protocol authority
+#[derive(Accounts)]
+pub struct SetCpmmProgram<'info> {
+    #[account(
+        address = protocol_state.authority @ SovereignError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn set_cpmm_program_handler(
+    ctx: Context<SetCpmmProgram>,
+    new_program: Pubkey,
+) -> Result<()> {
+    require!(
+        new_program != SAMM_PROGRAM_ID,
+        SovereignError::InvalidAmmProgram
+    );
+    
+    let protocol = &mut ctx.accounts.protocol_state;
+    let old_program = protocol.cpmm_program;
+    protocol.cpmm_program = new_program;
+    
+    emit!(CpmmProgramUpdated {
+        old_program,
+        new_program,
+    });
+    
+    Ok(())
+}
+
+/// Add, update or disable an allowlisted quote mint
+/// Sovereigns created while the mint is enabled bond in it instead of native GOR.
+/// Only callable by protocol authority
+#[derive(Accounts)]
+pub struct SetQuoteMint<'info> {
+    #[account(
+        mut,
+        address = protocol_state.authority @ SovereignError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    /// The quote mint (SPL Token or Token-2022)
+    /// Native GOR is the default quote asset and cannot be allowlisted as a token
+    #[account(
+        constraint = quote_mint.key() != WGOR_MINT @ SovereignError::UnsupportedQuoteMint
+    )]
+    pub quote_mint: InterfaceAccount<'info, MintInterface>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = QuoteMintConfig::LEN,
+        seeds = [QUOTE_MINT_CONFIG_SEED, quote_mint.key().as_ref()],
+        bump
+    )]
+    pub quote_mint_config: Account<'info, QuoteMintConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_quote_mint_handler(
+    ctx: Context<SetQuoteMint>,
+    min_bond_target: u64,
+    min_deposit: u64,
+    enabled: bool,
+) -> Result<()> {
+    require!(
+        min_bond_target > 0 && min_deposit > 0 && min_deposit <= min_bond_target,
+        SovereignError::InvalidDepositLimits
+    );
+    
+    // Vault accounting assumes the amount sent is the amount received and that
+    // transfers out of the vault need no extra accounts
+    let mint_info = ctx.accounts.quote_mint.to_account_info();
+    if *mint_info.owner == spl_token_2022::ID {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let extensions = mint.get_extension_types()?;
+        require!(
+            !extensions.contains(&ExtensionType::TransferFeeConfig)
+                && !extensions.contains(&ExtensionType::TransferHook),
+            SovereignError::UnsupportedQuoteMint
+        );
+    }
+    
+    let config = &mut ctx.accounts.quote_mint_config;
+    config.mint = ctx.accounts.quote_mint.key();
+    config.token_program = *mint_info.owner;
+    config.decimals = ctx.accounts.quote_mint.decimals;
+    config.min_bond_target = min_bond_target;
+    config.min_deposit = min_deposit;
+    config.enabled = enabled;
+    config.bump = ctx.bumps.quote_mint_config;
+    
+    emit!(QuoteMintConfigured {
+        mint: config.mint,
+        min_bond_target,
+        min_deposit,
+        enabled,
+    });
+    
+    Ok(())
+}
+
+/// Register an additional pool vault for a sovereign's token
+/// Used by the transfer hook to classify buys and sells on other pools.
+/// Only callable by protocol authority
+#[derive(Accounts)]
+pub struct RegisterPoolVault<'info> {
+    #[account(
+        address = protocol_state.authority @ SovereignError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+    
+    #[account(
+        mut,
+        seeds = [POOL_VAULT_REGISTRY_SEED, sovereign.key().as_ref()],
+        bump = pool_vault_registry.bump
+    )]
+    pub pool_vault_registry: Account<'info, PoolVaultRegistry>,
+    
+    /// The pool's token account holding the sovereign's token
+    #[account(
+        token::mint = sovereign.token_mint
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccountInterface>,
+}
+
+pub fn register_pool_vault_handler(ctx: Context<RegisterPoolVault>) -> Result<()> {
+    let registry = &mut ctx.accounts.pool_vault_registry;
+    registry.register(ctx.accounts.pool_vault.key())?;
+    
+    emit!(PoolVaultRegistered {
+        sovereign_id: ctx.accounts.sovereign.sovereign_id,
+        vault: ctx.accounts.pool_vault.key(),
+        registered_by: ctx.accounts.authority.key(),
+    });
+    
+    Ok(())
+}
+
+// ============================================================
+// BONDING MANAGEMENT
+// ============================================================
+
+/// Extend the bond deadline once (creator only)
+/// The new deadline must fall within MAX_BOND_DURATION of creation.
+/// Existing depositors get BOND_EXTENSION_EXIT_WINDOW to withdraw, during
+/// which withdrawals stay open even if the target is hit.
+#[derive(Accounts)]
+pub struct ExtendBondDeadline<'info> {
+    #[account(
+        address = sovereign.creator @ SovereignError::NotCreator
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump,
+        constraint = !sovereign.deadline_extended @ SovereignError::DeadlineAlreadyExtended
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+}
+
+pub fn extend_bond_deadline_handler(
+    ctx: Context<ExtendBondDeadline>,
+    new_deadline: i64,
+) -> Result<()> {
+    let sovereign = &mut ctx.accounts.sovereign;
+    let clock = Clock::get()?;
+    
+    require!(
+        sovereign.state == SovereignStatus::Bonding,
+        SovereignError::InvalidState
+    );
+    require!(
+        !sovereign.is_deadline_passed(clock.unix_timestamp),
+        SovereignError::DeadlinePassed
+    );
+    
+    let exit_window_end = clock.unix_timestamp
+        .checked_add(BOND_EXTENSION_EXIT_WINDOW)
+        .ok_or(SovereignError::Overflow)?;
+    let max_deadline = sovereign.created_at
+        .checked_add(MAX_BOND_DURATION)
+        .ok_or(SovereignError::Overflow)?;
+    
+    // Must actually extend, cover the exit window, and stay within MAX_BOND_DURATION
+    require!(
+        new_deadline > sovereign.bond_deadline
+            && new_deadline >= exit_window_end
+            && new_deadline <= max_deadline,
+        SovereignError::InvalidDeadlineExtension
+    );
+    
+    let old_deadline = sovereign.bond_deadline;
+    sovereign.bond_deadline = new_deadline;
+    sovereign.bond_duration = new_deadline - sovereign.created_at;
+    sovereign.deadline_extended = true;
+    sovereign.extension_exit_window_end = exit_window_end;
+    
+    emit!(BondDeadlineExtended {
+        sovereign_id: sovereign.sovereign_id,
+        old_deadline,
+        new_deadline,
+        exit_window_end,
+    });
+    
+    Ok(())
+}
+
 // ============================================================
 // SELL FEE MANAGEMENT (TokenLaunch only)
 // ============================================================
@@ -323,6 +675,10 @@ pub fn update_sell_fee_handler(
     // Update sovereign state
     sovereign.sell_fee_bps = new_fee_bps;
     
+    // Manual control replaces any decay schedule (and its pending step)
+    sovereign.sell_fee_decay = SellFeeDecay::default();
+    sovereign.sell_fee_pending = false;
+    
     emit!(SellFeeUpdated {
         sovereign_id: sovereign.sovereign_id,
         old_fee_bps: old_fee,
@@ -426,6 +782,8 @@ pub fn renounce_sell_fee_handler(ctx: Context<RenounceSellFee>) -> Result<()> {
     
     // Update sovereign state
     sovereign.sell_fee_bps = 0;
+    sovereign.sell_fee_decay = SellFeeDecay::default();
+    sovereign.sell_fee_pending = false;
     sovereign.fee_control_renounced = true;
     
     emit!(SellFeeRenounced {
@@ -436,3 +794,112 @@ pub fn renounce_sell_fee_handler(ctx: Context<RenounceSellFee>) -> Result<()> {
     
     Ok(())
 }
+
+/// Step the sell fee along its decay schedule
+/// Permissionless crank - anyone can call once the schedule has started.
+/// Token-2022 applies the new transfer fee from the second epoch after this call,
+/// so sell_fee_bps only moves to a step once it is in effect, and the next step
+/// cannot be set until then.
+#[derive(Accounts)]
+pub struct CrankSellFeeDecay<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump,
+        constraint = sovereign.sovereign_type == SovereignType::TokenLaunch @ SovereignError::InvalidSovereignType,
+        constraint = !sovereign.fee_control_renounced @ SovereignError::FeeControlRenounced
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+    
+    /// The token mint with TransferFeeConfig
+    #[account(
+        mut,
+        address = sovereign.token_mint
+    )]
+    pub token_mint: InterfaceAccount<'info, MintInterface>,
+    
+    pub token_program_2022: Program<'info, Token2022>,
+}
+
+pub fn crank_sell_fee_decay_handler(ctx: Context<CrankSellFeeDecay>) -> Result<()> {
+    let sovereign = &mut ctx.accounts.sovereign;
+    let clock = Clock::get()?;
+    
+    let schedule = sovereign.sell_fee_decay;
+    let start = sovereign.sell_fee_decay_start();
+    require!(
+        schedule.is_enabled() && start > 0 && clock.unix_timestamp >= start,
+        SovereignError::SellFeeDecayNotActive
+    );
+    
+    // Settle the previous step once Token-2022 has applied it
+    let mut settled = false;
+    if sovereign.sell_fee_pending {
+        require!(
+            sovereign.is_pending_sell_fee_effective(clock.epoch),
+            SovereignError::SellFeeDecayPending
+        );
+        
+        let old_fee = sovereign.sell_fee_bps;
+        sovereign.sell_fee_bps = sovereign.pending_sell_fee_bps;
+        sovereign.sell_fee_pending = false;
+        settled = true;
+        
+        emit!(SellFeeUpdated {
+            sovereign_id: sovereign.sovereign_id,
+            old_fee_bps: old_fee,
+            new_fee_bps: sovereign.sell_fee_bps,
+            updated_by: ctx.accounts.cranker.key(),
+        });
+        
+        msg!("Sell fee decayed: {} -> {} bps", old_fee, sovereign.sell_fee_bps);
+    }
+    
+    let new_fee_bps = schedule
+        .fee_at(start, clock.unix_timestamp)
+        .min(MAX_SELL_FEE_BPS);
+    if new_fee_bps == sovereign.sell_fee_bps {
+        require!(settled, SovereignError::SellFeeDecayUpToDate);
+        return Ok(());
+    }
+    
+    // Derive sovereign PDA seeds for signing
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds = &[
+        SOVEREIGN_SEED,
+        &sovereign_id_bytes,
+        &[sovereign.bump],
+    ];
+    let sovereign_signer = &[&sovereign_seeds[..]];
+    
+    // Update the TransferFeeConfig on the mint
+    let set_fee_ix = transfer_fee_ix::set_transfer_fee(
+        &spl_token_2022::ID,
+        &ctx.accounts.token_mint.key(),
+        &sovereign.key(), // Transfer fee config authority
+        &[],
+        new_fee_bps,
+        u64::MAX, // No max fee cap
+    )?;
+    
+    invoke_signed(
+        &set_fee_ix,
+        &[
+            ctx.accounts.token_mint.to_account_info(),
+            sovereign.to_account_info(),
+        ],
+        sovereign_signer,
+    )?;
+    
+    // Takes effect at sell_fee_set_epoch + 2; settled by a later crank
+    sovereign.pending_sell_fee_bps = new_fee_bps;
+    sovereign.sell_fee_pending = true;
+    sovereign.sell_fee_set_epoch = clock.epoch;
+    
+    msg!("Sell fee decay step set: {} bps from epoch {}",
+        new_fee_bps, clock.epoch + TRANSFER_FEE_EFFECTIVE_EPOCHS);
+    
+    Ok(())
+}
@@ -8,15 +8,23 @@ use anchor_spl::token_2022::{
         extension::transfer_fee::instruction as transfer_fee_ix,
     },
 };
-use anchor_spl::token_interface::{Mint as MintInterface, TokenAccount as TokenAccountInterface};
+use anchor_spl::token_interface::{
+    Mint as MintInterface,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
 use anchor_lang::solana_program::program::invoke_signed;
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
-use crate::events::{FeesClaimed, RecoveryComplete, PoolRestricted, SellFeeRenounced, RecoveryTokensSwapped};
-use crate::samm::{instructions as samm_ix, cpi as samm_cpi};
+use crate::events::{FeesClaimed, RecoveryComplete, PoolRestricted, SellFeeRenounced, RecoveryTokensSwapped, CreatorPurchasedTokensClaimed};
+use crate::amm::{self, AmmSwap, LockPosition};
+use super::transfer_hook::{detach_transfer_hook, reattach_transfer_hook};
 
-/// Claim fees from the Trashbin SAMM position
+/// Claim fees from the permanent lock's AMM position
 /// Fees are distributed to depositors and track recovery progress
 #[derive(Accounts)]
 pub struct ClaimFees<'info> {
@@ -37,21 +45,22 @@ pub struct ClaimFees<'info> {
     pub sovereign: Account<'info, SovereignState>,
     
     #[account(
+        mut,
         seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
         bump = permanent_lock.bump
     )]
     pub permanent_lock: Account<'info, PermanentLock>,
     
-    /// CHECK: SAMM position account - validated via position NFT ownership by permanent_lock
+    /// CHECK: AMM position account - validated via position NFT ownership by permanent_lock
     /// The position is derived from the position_mint NFT held by permanent_lock PDA
     #[account(mut)]
     pub position: UncheckedAccount<'info>,
     
-    /// CHECK: Token vault 0 (GOR/WGOR side) - validated via SAMM CPI
+    /// CHECK: Token vault 0 (GOR/WGOR side) - validated via AMM CPI
     #[account(mut)]
     pub token_vault_a: UncheckedAccount<'info>,
     
-    /// CHECK: Token vault 1 (token side) - validated via SAMM CPI
+    /// CHECK: Token vault 1 (token side) - validated via AMM CPI
     #[account(mut)]
     pub token_vault_b: UncheckedAccount<'info>,
     
@@ -72,9 +81,9 @@ pub struct ClaimFees<'info> {
     )]
     pub creator_fee_tracker: Account<'info, CreatorFeeTracker>,
     
-    /// CHECK: Trashbin SAMM program
-    #[account(address = SAMM_PROGRAM_ID)]
-    pub samm_program: UncheckedAccount<'info>,
+    /// CHECK: The sovereign's AMM program
+    #[account(address = sovereign.amm_program @ SovereignError::InvalidAmmProgram)]
+    pub amm_program: UncheckedAccount<'info>,
     
     /// Token mint — needed for FairLaunch auto-renounce on recovery completion
     /// Optional: pass system_program if sovereign has no transfer fee
@@ -82,6 +91,20 @@ pub struct ClaimFees<'info> {
     #[account(mut)]
     pub token_mint: UncheckedAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<InterfaceAccount<'info, MintInterface>>,
+    
+    /// Quote vault - receives token quote fees (authority = fee_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<InterfaceAccount<'info, TokenAccountInterface>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub token_program: Program<'info, Token>,
     pub token_program_2022: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
@@ -92,6 +115,12 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
     let protocol = &ctx.accounts.protocol_state;
     let _creator_tracker = &ctx.accounts.creator_fee_tracker;
     let clock = Clock::get()?;
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_ref(),
+        ctx.accounts.quote_vault.as_ref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
     
     // Check protocol pause status
     require!(
@@ -106,82 +135,43 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
         SovereignError::InvalidState
     );
     
-    // ============ Trashbin SAMM Fee Collection ============
-    // CPI to SAMM decrease_liquidity_v2 with liquidity=0 (collects fees only)
+    // ============ AMM Fee Collection ============
+    // The adapter collects fees into the permanent lock's recipient accounts:
+    // SAMM decrease_liquidity_v2 with liquidity=0, CPMM withdraw of the LP
+    // tokens above the principal.
     // Required remaining_accounts order:
-    // [0]  nft_account - Position NFT token account
-    // [1]  personal_position - Personal position state (writable)
-    // [2]  pool_state - Pool state (writable)
-    // [3]  protocol_position - Protocol position state (writable)
-    // [4]  token_vault_0 - Pool token vault A (writable)
-    // [5]  token_vault_1 - Pool token vault B (writable)
-    // [6]  tick_array_lower - Lower tick array (writable)
-    // [7]  tick_array_upper - Upper tick array (writable)
-    // [8]  recipient_token_account_0 - Recipient for token A fees (writable)
-    // [9]  recipient_token_account_1 - Recipient for token B fees (writable)
-    // [10] token_program_2022 - Token 2022 program
-    // [11] memo_program - Memo program
-    // [12] vault_0_mint - Vault 0 mint
-    // [13] vault_1_mint - Vault 1 mint
-    // [14] tick_array_bitmap_extension
+    // [0..P]  lock position accounts - see amm::samm (P = 15) / amm::cpmm (P = 12)
     //
-    // --- Token fee routing (optional, index 15+) ---
-    // [15] amm_config           - SAMM AMM config (for swap path)
-    // [16] observation_state    - SAMM observation state (for swap path)
-    // [17] creator_token_ata    - Creator's Token-2022 ATA (for creator/active paths)
-    // [18+] swap_tick_arrays    - Tick arrays for token→WGOR swap
-    
-    let (sol_fees_collected, token_fees_collected) = if ctx.remaining_accounts.len() >= 15 {
+    // --- Token fee routing (optional, index P+) ---
+    // [P]    amm_config           - AMM config (for swap path)
+    // [P+1]  observation_state    - AMM observation state (for swap path)
+    // [P+2]  creator_token_ata    - Creator's Token-2022 ATA (for creator/active paths)
+    // [P+3+] swap_accounts        - SAMM tick arrays / CPMM authority for token→WGOR swap
+    
+    let adapter = amm::adapter(sovereign.amm_kind);
+    let position_len = adapter.position_accounts_len();
+    
+    let (sol_fees_collected, token_fees_collected) = if ctx.remaining_accounts.len() >= position_len {
+        let (position_accounts, route_accounts) = ctx.remaining_accounts.split_at(position_len);
+        let pool = adapter.pool_accounts(&sovereign.amm_program, position_accounts)?;
+        
         // SECURITY: Validate pool_state matches the sovereign's stored pool_state
         // This prevents attackers from passing arbitrary pool accounts
         require!(
-            ctx.remaining_accounts[2].key() == ctx.accounts.permanent_lock.pool_state,
+            pool.pool_state.key() == ctx.accounts.permanent_lock.pool_state,
             SovereignError::InvalidPool
         );
         
-        // Determine which recipient is WGOR vs project token based on vault mints
-        let vault_0_mint_key = ctx.remaining_accounts[12].key();
-        let _vault_1_mint_key = ctx.remaining_accounts[13].key();
-        let wgor_is_0 = vault_0_mint_key == WGOR_MINT;
-        
-        let wgor_recipient_idx: usize = if wgor_is_0 { 8 } else { 9 };
-        let token_recipient_idx: usize = if wgor_is_0 { 9 } else { 8 };
+        // Determine which recipient is WGOR (quote) vs project token based on vault mints
+        let quote_mint = sovereign.pool_quote_mint();
+        let wgor_is_0 = pool.quote_is_0(&quote_mint);
+        let (wgor_recipient, token_recipient) = pool.recipients(&quote_mint);
+        let (wgor_vault, token_vault) = pool.vaults(&quote_mint);
         
         // Snapshot token recipient ATA balance before CPI (for tracking token fees)
-        let token_balance_before = {
-            let data = ctx.remaining_accounts[token_recipient_idx].try_borrow_data()?;
-            if data.len() >= 72 {
-                u64::from_le_bytes(data[64..72].try_into().unwrap())
-            } else {
-                0u64
-            }
-        };
-        
-        // Full SAMM CPI for fee collection
-        msg!("Collecting fees via SAMM CPI...");
+        let token_balance_before = amm::token_amount(token_recipient)?;
         
-        // Build decrease_liquidity_v2 accounts (with liquidity=0 for fee collection only)
-        let decrease_accounts = samm_ix::DecreaseLiquidityV2Accounts {
-            nft_owner: ctx.accounts.permanent_lock.to_account_info(),
-            nft_account: ctx.remaining_accounts[0].clone(),
-            personal_position: ctx.remaining_accounts[1].clone(),
-            pool_state: ctx.remaining_accounts[2].clone(),
-            protocol_position: ctx.remaining_accounts[3].clone(),
-            token_vault_0: ctx.remaining_accounts[4].clone(),
-            token_vault_1: ctx.remaining_accounts[5].clone(),
-            tick_array_lower: ctx.remaining_accounts[6].clone(),
-            tick_array_upper: ctx.remaining_accounts[7].clone(),
-            recipient_token_account_0: ctx.remaining_accounts[8].clone(),
-            recipient_token_account_1: ctx.remaining_accounts[9].clone(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            token_program_2022: ctx.remaining_accounts[10].clone(),
-            memo_program: ctx.remaining_accounts[11].clone(),
-            vault_0_mint: ctx.remaining_accounts[12].clone(),
-            vault_1_mint: ctx.remaining_accounts[13].clone(),
-            tick_array_bitmap_extension: ctx.remaining_accounts[14].clone(),
-        };
-        
-        // Use permanent_lock as signer (it owns the position NFT)
+        // Use permanent_lock as signer (it owns the position)
         let sovereign_key = sovereign.key();
         let lock_seeds = &[
             PERMANENT_LOCK_SEED,
@@ -190,33 +180,39 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
         ];
         let lock_signer_seeds = &[&lock_seeds[..]];
         
-        // CPI: Collect fees (decrease_liquidity_v2 with liquidity=0)
-        samm_cpi::collect_fees(
-            &ctx.accounts.samm_program.to_account_info(),
-            decrease_accounts,
+        // Collecting, swapping and routing move the sovereign token, which would
+        // re-enter this program through the transfer hook
+        let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+        let sovereign_seeds = &[
+            SOVEREIGN_SEED,
+            &sovereign_id_bytes[..],
+            &[sovereign.bump],
+        ];
+        let sovereign_signer_seeds = &[&sovereign_seeds[..]];
+        let hook_detached = detach_transfer_hook(
+            &ctx.accounts.token_mint.to_account_info(),
+            &sovereign.to_account_info(),
+            sovereign_signer_seeds,
+        )?;
+        
+        msg!("Collecting fees via AMM CPI...");
+        adapter.collect_fees(
+            &LockPosition {
+                program: ctx.accounts.amm_program.to_account_info(),
+                owner: ctx.accounts.permanent_lock.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                accounts: position_accounts,
+            },
+            ctx.accounts.permanent_lock.liquidity,
             lock_signer_seeds,
         )?;
         
         // Read TOTAL WGOR ATA balance after harvest CPI.
         // This captures both newly harvested fees AND any leftover from previous calls.
-        let wgor_balance_after_harvest = {
-            let data = ctx.remaining_accounts[wgor_recipient_idx].try_borrow_data()?;
-            if data.len() >= 72 {
-                u64::from_le_bytes(data[64..72].try_into().unwrap())
-            } else {
-                0u64
-            }
-        };
+        let wgor_balance_after_harvest = amm::token_amount(wgor_recipient)?;
         
         // Read TOTAL token ATA balance after harvest (includes any leftovers)
-        let token_total_balance = {
-            let data = ctx.remaining_accounts[token_recipient_idx].try_borrow_data()?;
-            if data.len() >= 72 {
-                u64::from_le_bytes(data[64..72].try_into().unwrap())
-            } else {
-                0u64
-            }
-        };
+        let token_total_balance = amm::token_amount(token_recipient)?;
         let token_collected = token_total_balance.saturating_sub(token_balance_before);
         
         msg!("WGOR in ATA: {}, Token in ATA: {} (new: {})", 
@@ -233,7 +229,7 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
         //   FairLaunch + Active       → burn tokens (deflationary, benefits holders)
         //   CreatorRevenue (any)      → transfer tokens → creator's ATA
         
-        if token_total_balance > 0 && ctx.remaining_accounts.len() >= 18 {
+        if token_total_balance > 0 && route_accounts.len() >= 3 {
             let fee_mode = sovereign.fee_mode;
             let is_recovery = sovereign.state == SovereignStatus::Recovery;
             
@@ -244,53 +240,35 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 (!is_recovery && fee_mode == FeeMode::RecoveryBoost);
             
             if swap_to_investors {
-                // ---- SWAP PATH: token → WGOR via SAMM CPI ----
+                // ---- SWAP PATH: token → WGOR via AMM CPI ----
                 // permanent_lock signs (owns the token ATA as input)
                 msg!("Swapping {} tokens → WGOR for investor recovery...", token_total_balance);
                 
-                // Determine SAMM vault ordering for the swap
                 // Input = sovereign token, Output = WGOR
-                let (input_vault, output_vault) = if wgor_is_0 {
-                    // mint0=WGOR, mint1=token → vault_0=WGOR, vault_1=token
-                    // Input is token (vault_1), output is WGOR (vault_0)
-                    (ctx.remaining_accounts[5].clone(), ctx.remaining_accounts[4].clone())
-                } else {
-                    // mint0=token, mint1=WGOR → vault_0=token, vault_1=WGOR
-                    (ctx.remaining_accounts[4].clone(), ctx.remaining_accounts[5].clone())
-                };
-                
-                let (input_mint, output_mint) = if wgor_is_0 {
-                    (ctx.remaining_accounts[13].clone(), ctx.remaining_accounts[12].clone())
-                } else {
-                    (ctx.remaining_accounts[12].clone(), ctx.remaining_accounts[13].clone())
-                };
+                let (output_mint, input_mint) = pool.mints(&quote_mint);
                 
-                // Collect tick arrays for swap from remaining_accounts[18+]
-                let swap_tick_arrays: Vec<AccountInfo<'info>> = ctx.remaining_accounts[18..].to_vec();
-                
-                let swap_accounts = samm_ix::SwapV2Accounts {
+                let swap = AmmSwap {
                     payer: ctx.accounts.permanent_lock.to_account_info(),
-                    amm_config: ctx.remaining_accounts[15].clone(),
-                    pool_state: ctx.remaining_accounts[2].clone(),
-                    input_token_account: ctx.remaining_accounts[token_recipient_idx].clone(),
-                    output_token_account: ctx.remaining_accounts[wgor_recipient_idx].clone(),
-                    input_vault,
-                    output_vault,
-                    observation_state: ctx.remaining_accounts[16].clone(),
+                    amm_config: route_accounts[0].clone(),
+                    pool_state: pool.pool_state.clone(),
+                    input_token_account: token_recipient.clone(),
+                    output_token_account: wgor_recipient.clone(),
+                    input_vault: token_vault.clone(),
+                    output_vault: wgor_vault.clone(),
+                    input_vault_mint: input_mint.clone(),
+                    output_vault_mint: output_mint.clone(),
+                    observation_state: route_accounts[1].clone(),
                     token_program: ctx.accounts.token_program.to_account_info(),
-                    token_program_2022: ctx.remaining_accounts[10].clone(),
-                    memo_program: ctx.remaining_accounts[11].clone(),
-                    input_vault_mint: input_mint,
-                    output_vault_mint: output_mint,
+                    token_program_2022: pool.token_program_2022.clone(),
+                    memo_program: pool.memo_program.clone(),
+                    extra: route_accounts[3..].to_vec(),
                 };
                 
-                samm_cpi::swap_exact_input(
-                    &ctx.accounts.samm_program.to_account_info(),
-                    swap_accounts,
+                adapter.swap_exact_input(
+                    &ctx.accounts.amm_program.to_account_info(),
+                    swap,
                     token_total_balance,
                     0, // min_amount_out — permissionless, protocol token, no MEV risk
-                    0, // no sqrt_price_limit
-                    swap_tick_arrays,
                     lock_signer_seeds,
                 )?;
                 
@@ -302,7 +280,7 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 
                 let burn_ix = spl_token_2022::instruction::burn(
                     &spl_token_2022::ID,
-                    &ctx.remaining_accounts[token_recipient_idx].key(),
+                    &token_recipient.key(),
                     &ctx.accounts.token_mint.key(),
                     &ctx.accounts.permanent_lock.key(),
                     &[],
@@ -312,7 +290,7 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 invoke_signed(
                     &burn_ix,
                     &[
-                        ctx.remaining_accounts[token_recipient_idx].clone(),
+                        token_recipient.clone(),
                         ctx.accounts.token_mint.to_account_info(),
                         ctx.accounts.permanent_lock.to_account_info(),
                     ],
@@ -333,9 +311,9 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 
                 let transfer_ix = spl_token_2022::instruction::transfer_checked(
                     &spl_token_2022::ID,
-                    &ctx.remaining_accounts[token_recipient_idx].key(),
+                    &token_recipient.key(),
                     &ctx.accounts.token_mint.key(),
-                    &ctx.remaining_accounts[17].key(), // creator_token_ata
+                    &route_accounts[2].key(), // creator_token_ata
                     &ctx.accounts.permanent_lock.key(),
                     &[],
                     token_total_balance,
@@ -345,9 +323,9 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 invoke_signed(
                     &transfer_ix,
                     &[
-                        ctx.remaining_accounts[token_recipient_idx].clone(),
+                        token_recipient.clone(),
                         ctx.accounts.token_mint.to_account_info(),
-                        ctx.remaining_accounts[17].clone(), // creator_token_ata
+                        route_accounts[2].clone(), // creator_token_ata
                         ctx.accounts.permanent_lock.to_account_info(),
                     ],
                     lock_signer_seeds,
@@ -361,41 +339,31 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
         
         // ============ Solvency-Protected GOR Extraction ============
         // Read the FINAL WGOR balance (includes harvest GOR + any tokens swapped to WGOR).
-        let wgor_final_balance = {
-            let data = ctx.remaining_accounts[wgor_recipient_idx].try_borrow_data()?;
-            if data.len() >= 72 {
-                u64::from_le_bytes(data[64..72].try_into().unwrap())
-            } else {
-                0u64
-            }
-        };
+        let wgor_final_balance = amm::token_amount(wgor_recipient)?;
         
         // ---- Principal Protection Invariant ----
         // Compute the maximum extractable GOR while preserving the property:
-        //   "If all tokens were sold back into the pool, GOR reserve ≥ bond_target"
+        //   "If all tokens were sold back into the lock's position, the GOR it
+        //    holds plus the GOR held back ≥ recovery_target"
         //
-        // Formula: e_max = max(0, x_final - (x0 * S_total / y_final) * 1.001)
+        // Formula: e_max = max(0, x_final + h - (R + Q))
         // Where:
-        //   x_final = current GOR in pool vault (WGOR balance)
-        //   y_final = current token in pool vault
-        //   x0 = sovereign.bond_target (original investor principal)
-        //   S_total = token mint supply
-        //   1.001 = 0.1% safety buffer for CLMM rounding
-        
-        let wgor_vault_idx: usize = if wgor_is_0 { 4 } else { 5 };
-        let token_vault_idx: usize = if wgor_is_0 { 5 } else { 4 };
+        //   x_final = GOR held by the lock's position at the current price
+        //   h = GOR held back by earlier claims plus the WGOR harvested now
+        //   R = x0 * 1.001 (0.1% safety buffer for CLMM rounding)
+        //   x0 = sovereign.recovery_target (actual investor principal raised)
+        //   Q = GOR paid out for selling the S_total - y_final tokens outside the position
+        //   S_total = token mint supply, y_final = tokens held by the lock's position
+        //
+        // Only the lock's share of the pool counts: an existing pool, or one open
+        // to other LPs, also holds liquidity its owners can withdraw at any time.
+        // The adapter bounds Q from above: the SAMM walks the sell through the
+        // primary and every LP ladder range in turn; a CPMM pool is one constant
+        // product, R + Q = R * S_total / y_final.
         
-        let x_final: u128 = {
-            let data = ctx.remaining_accounts[wgor_vault_idx].try_borrow_data()?;
-            if data.len() >= 72 {
-                u64::from_le_bytes(data[64..72].try_into().unwrap()) as u128
-            } else { 0u128 }
-        };
-        let y_final: u128 = {
-            let data = ctx.remaining_accounts[token_vault_idx].try_borrow_data()?;
-            if data.len() >= 72 {
-                u64::from_le_bytes(data[64..72].try_into().unwrap()) as u128
-            } else { 0u128 }
+        let (x_final, y_final): (u128, u128) = {
+            let (reserve_0, reserve_1) = adapter.lock_reserves(position_accounts, &ctx.accounts.permanent_lock)?;
+            if wgor_is_0 { (reserve_0, reserve_1) } else { (reserve_1, reserve_0) }
         };
         let s_total: u128 = {
             let mint_data = ctx.accounts.token_mint.try_borrow_data()?;
@@ -403,77 +371,78 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 u64::from_le_bytes(mint_data[36..44].try_into().unwrap()) as u128
             } else { 0u128 }
         };
-        let x0: u128 = sovereign.bond_target as u128;
+        let x0: u128 = sovereign.recovery_target as u128;
         
-        // Minimum GOR that must remain in pool: (x0 * S_total / y_final) * 1.001
-        let min_reserve: u128 = if y_final > 0 {
-            x0.checked_mul(s_total).unwrap_or(u128::MAX)
-                .checked_div(y_final).unwrap_or(0)
-                .checked_mul(1001).unwrap_or(u128::MAX)
-                .checked_div(1000).unwrap_or(u128::MAX)
-        } else {
-            u128::MAX // No tokens in pool → don't extract anything
-        };
+        // Minimum GOR the lock must keep: R + Q
+        // (u128::MAX from the adapter when the sell cannot be bounded)
+        let min_reserve: u128 = adapter.min_quote_reserve(
+            position_accounts,
+            &ctx.accounts.permanent_lock,
+            x0.saturating_mul(1001) / 1000,
+            s_total.saturating_sub(y_final),
+            wgor_is_0,
+        )?;
         
-        let e_max: u64 = if x_final > min_reserve {
-            (x_final - min_reserve) as u64
-        } else {
-            0u64
-        };
+        let held: u128 = (wgor_final_balance as u128)
+            .checked_add(ctx.accounts.permanent_lock.retained_quote as u128)
+            .ok_or(SovereignError::Overflow)?;
+        let e_max: u128 = x_final
+            .saturating_add(held)
+            .saturating_sub(min_reserve);
+        
+        let extractable = std::cmp::min(held, e_max) as u64;
+        let retained = (held - extractable as u128) as u64;
         
-        let extractable = std::cmp::min(wgor_final_balance, e_max);
-        let return_to_pool = wgor_final_balance - extractable;
+        msg!("Solvency check: x_final={}, y_final={}, s_total={}, x0={}, min_reserve={}, held={}, extractable={}, retained={}",
+            x_final, y_final, s_total, x0, min_reserve, held, extractable, retained);
         
-        msg!("Solvency check: x_final={}, y_final={}, s_total={}, x0={}, min_reserve={}, e_max={}, extractable={}, return_to_pool={}",
-            x_final, y_final, s_total, x0, min_reserve, e_max, extractable, return_to_pool);
+        // Everything harvested moves into the sovereign's vault. What is not
+        // extractable stays there as principal, recorded on the lock, rather than
+        // going into the pool vaults where every LP of the pool would share it.
+        ctx.accounts.permanent_lock.retained_quote = retained;
         
         if wgor_final_balance > 0 {
-            // Return excess WGOR to pool vault to preserve principal
-            if return_to_pool > 0 {
-                let return_ix = spl_token::instruction::transfer(
+            if let Some(quote) = quote.as_ref() {
+                // Token quote: move the harvested quote into the quote vault
+                quote.sweep_into_vault(
+                    wgor_recipient.clone(),
+                    ctx.accounts.permanent_lock.to_account_info(),
+                    lock_signer_seeds,
+                    wgor_final_balance,
+                )?;
+                
+                msg!("{} quote tokens to quote vault ({} extractable, solvency-protected)", wgor_final_balance, extractable);
+            } else {
+                // Close WGOR ATA → the harvested GOR goes to fee_vault as native GOR
+                let close_ix = spl_token::instruction::close_account(
                     &spl_token::ID,
-                    &ctx.remaining_accounts[wgor_recipient_idx].key(),
-                    &ctx.remaining_accounts[wgor_vault_idx].key(),
+                    &wgor_recipient.key(),
+                    &ctx.accounts.fee_vault.key(),
                     &ctx.accounts.permanent_lock.key(),
                     &[],
-                    return_to_pool,
                 )?;
                 
                 invoke_signed(
-                    &return_ix,
+                    &close_ix,
                     &[
-                        ctx.remaining_accounts[wgor_recipient_idx].clone(),
-                        ctx.remaining_accounts[wgor_vault_idx].clone(),
+                        wgor_recipient.clone(),
+                        ctx.accounts.fee_vault.to_account_info(),
                         ctx.accounts.permanent_lock.to_account_info(),
                     ],
                     lock_signer_seeds,
                 )?;
                 
-                msg!("Returned {} WGOR to pool vault (principal protection)", return_to_pool);
+                msg!("WGOR ATA closed → {} lamports to fee_vault ({} extractable, solvency-protected)", wgor_final_balance, extractable);
             }
-            
-            // Close WGOR ATA → remaining extractable goes to fee_vault as native GOR
-            let close_ix = spl_token::instruction::close_account(
-                &spl_token::ID,
-                &ctx.remaining_accounts[wgor_recipient_idx].key(),
-                &ctx.accounts.fee_vault.key(),
-                &ctx.accounts.permanent_lock.key(),
-                &[],
-            )?;
-            
-            invoke_signed(
-                &close_ix,
-                &[
-                    ctx.remaining_accounts[wgor_recipient_idx].clone(),
-                    ctx.accounts.fee_vault.to_account_info(),
-                    ctx.accounts.permanent_lock.to_account_info(),
-                ],
-                lock_signer_seeds,
-            )?;
-            
-            msg!("WGOR ATA closed → {} lamports to fee_vault (solvency-protected)", extractable);
         }
         
+        reattach_transfer_hook(
+            &ctx.accounts.token_mint.to_account_info(),
+            &sovereign.to_account_info(),
+            sovereign_signer_seeds,
+            hook_detached,
+        )?;
+        
         (extractable, token_collected)
     } else {
         // Simplified flow without SAMM CPI (test mode)
@@ -501,8 +470,9 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
             
             // Unlock the pool via SAMM CPI (remove LP restrictions)
             // This allows external LPs to enter the pool
-            if ctx.remaining_accounts.len() >= 15 {
-                let pool_state_info = &ctx.remaining_accounts[2];
+            // (an existing pool linked by a BYO sovereign was never restricted)
+            if sovereign.pool_restricted && ctx.remaining_accounts.len() >= position_len {
+                let pool_state_info = &adapter.pool_accounts(&sovereign.amm_program, ctx.remaining_accounts)?.pool_state;
                 let sovereign_key = sovereign.key();
                 let lock_seeds = &[
                     PERMANENT_LOCK_SEED,
@@ -511,13 +481,14 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 ];
                 let lock_signer_seeds = &[&lock_seeds[..]];
                 
-                samm_cpi::set_pool_status_unrestricted(
-                    &ctx.accounts.samm_program.to_account_info(),
+                adapter.set_pool_unrestricted(
+                    &ctx.accounts.amm_program.to_account_info(),
                     &ctx.accounts.permanent_lock.to_account_info(),
                     pool_state_info,
                     lock_signer_seeds,
                 )?;
                 
+                sovereign.pool_restricted = false;
                 msg!("Pool restrictions removed - external LPs can now enter");
             }
             
@@ -553,6 +524,8 @@ pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFees<'info>>) -> R
                 )?;
                 
                 sovereign.sell_fee_bps = 0;
+                sovereign.sell_fee_decay = SellFeeDecay::default();
+                sovereign.sell_fee_pending = false;
                 sovereign.fee_control_renounced = true;
                 
                 emit!(SellFeeRenounced {
@@ -640,6 +613,24 @@ pub struct ClaimDepositorFees<'info> {
     )]
     pub fee_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = fee_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Holder's quote token account (receives token quote fees)
+    #[account(mut)]
+    pub holder_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -663,12 +654,10 @@ pub fn claim_depositor_fees_handler(ctx: Context<ClaimDepositorFees>) -> Result<
         SovereignError::NoDeposits
     );
     
-    // Calculate depositor's share based on their proportion of total deposits
+    // Calculate depositor's share based on their proportion of weighted share units
     // Using safe arithmetic to prevent overflow
-    let depositor_share_bps = deposit_record.amount
-        .checked_mul(BPS_DENOMINATOR as u64)
-        .ok_or(SovereignError::Overflow)?
-        .checked_div(sovereign.total_deposited)
+    let depositor_share_bps = sovereign
+        .share_bps(deposit_record.share_units)
         .ok_or(SovereignError::DivisionByZero)? as u16;
     
     // Fee Index Pattern: Calculate claimable based on global index
@@ -682,12 +671,18 @@ pub fn claim_depositor_fees_handler(ctx: Context<ClaimDepositorFees>) -> Result<
         .ok_or(SovereignError::DivisionByZero)?;
     
     let claimable = total_share
-        .checked_sub(deposit_record.fees_claimed)
-        .unwrap_or(0);
+        .saturating_sub(deposit_record.fees_claimed);
     
     if claimable > 0 {
+        let quote = QuoteToken::resolve(
+            sovereign,
+            ctx.accounts.quote_mint.as_deref(),
+            ctx.accounts.quote_vault.as_deref(),
+            ctx.accounts.quote_token_program.as_ref(),
+        )?;
+        
         // Verify vault has sufficient balance
-        let vault_balance = ctx.accounts.fee_vault.lamports();
+        let vault_balance = quote::vault_balance(quote.as_ref(), &ctx.accounts.fee_vault)?;
         
         require!(
             vault_balance >= claimable,
@@ -700,7 +695,7 @@ pub fn claim_depositor_fees_handler(ctx: Context<ClaimDepositorFees>) -> Result<
             .checked_add(claimable)
             .ok_or(SovereignError::Overflow)?;
         
-        // Transfer from fee vault to holder using System Program CPI
+        // Transfer from fee vault (or quote vault) to holder
         let sovereign_key = sovereign.key();
         let vault_seeds: &[&[u8]] = &[
             SOL_VAULT_SEED,
@@ -708,15 +703,13 @@ pub fn claim_depositor_fees_handler(ctx: Context<ClaimDepositorFees>) -> Result<
             &[ctx.bumps.fee_vault],
         ];
         
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.fee_vault.to_account_info(),
-                    to: ctx.accounts.holder.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.fee_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.holder.to_account_info(),
+            ctx.accounts.holder_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             claimable,
         )?;
     }
@@ -754,6 +747,24 @@ pub struct WithdrawCreatorFees<'info> {
     )]
     pub fee_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = fee_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Creator's quote token account (receives token quote fees)
+    #[account(mut)]
+    pub creator_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -773,7 +784,13 @@ pub fn withdraw_creator_fees_handler(ctx: Context<WithdrawCreatorFees>) -> Resul
     tracker.pending_withdrawal = 0;
     tracker.total_claimed = tracker.total_claimed.checked_add(amount).unwrap();
     
-    // Transfer from fee vault to creator using System Program CPI
+    // Transfer from fee vault (or quote vault) to creator
+    let quote = QuoteToken::resolve(
+        _sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
     let sovereign_key = _sovereign.key();
     let vault_seeds: &[&[u8]] = &[
         SOL_VAULT_SEED,
@@ -781,18 +798,139 @@ pub fn withdraw_creator_fees_handler(ctx: Context<WithdrawCreatorFees>) -> Resul
         &[ctx.bumps.fee_vault],
     ];
     
-    anchor_lang::system_program::transfer(
+    quote::pay_out(
+        quote.as_ref(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        vault_seeds,
+        &ctx.accounts.creator.to_account_info(),
+        ctx.accounts.creator_quote_account.as_ref().map(|a| a.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+    
+    Ok(())
+}
+
+// ============================================================
+// CLAIM CREATOR PURCHASED TOKENS
+// ============================================================
+
+/// Creator claims the tokens bought with their escrow at finalization.
+/// Locked until recovery is complete or the sovereign has been unwound.
+#[derive(Accounts)]
+pub struct ClaimCreatorPurchasedTokens<'info> {
+    #[account(
+        mut,
+        address = sovereign.creator @ SovereignError::NotCreator
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+    
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_TRACKER_SEED, sovereign.key().as_ref()],
+        bump = creator_fee_tracker.bump
+    )]
+    pub creator_fee_tracker: Account<'info, CreatorFeeTracker>,
+    
+    /// Token mint (sovereign's Token-2022 mint)
+    /// Mutable so the transfer hook can be detached for the transfer
+    #[account(
+        mut,
+        address = sovereign.token_mint
+    )]
+    pub token_mint: InterfaceAccount<'info, MintInterface>,
+    
+    /// Vault holding the creator's market-bought tokens (owned by sovereign PDA)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = sovereign,
+        seeds = [CREATOR_TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub creator_token_vault: InterfaceAccount<'info, TokenAccountInterface>,
+    
+    /// Creator's token account - receives the purchased tokens
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = creator,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccountInterface>,
+    
+    pub token_program_2022: Program<'info, Token2022>,
+}
+
+pub fn claim_creator_purchased_tokens_handler(ctx: Context<ClaimCreatorPurchasedTokens>) -> Result<()> {
+    let sovereign = &ctx.accounts.sovereign;
+    let tracker = &mut ctx.accounts.creator_fee_tracker;
+    
+    // Purchased tokens unlock after recovery OR on unwind
+    require!(
+        sovereign.recovery_complete || sovereign.state == SovereignStatus::Unwound,
+        SovereignError::CreatorTokensLocked
+    );
+    require!(
+        !tracker.purchased_tokens_claimed,
+        SovereignError::AlreadyClaimed
+    );
+    require!(
+        tracker.purchased_tokens > 0,
+        SovereignError::NothingToClaim
+    );
+    
+    let amount = tracker.purchased_tokens;
+    
+    // Effects before interaction
+    tracker.purchased_tokens_claimed = true;
+    tracker.tokens_locked = false;
+    
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds = &[
+        SOVEREIGN_SEED,
+        &sovereign_id_bytes[..],
+        &[sovereign.bump],
+    ];
+    let sovereign_signer = &[&sovereign_seeds[..]];
+    
+    let hook_detached = detach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer,
+    )?;
+    transfer_checked(
         CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.fee_vault.to_account_info(),
-                to: ctx.accounts.creator.to_account_info(),
+            ctx.accounts.token_program_2022.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.creator_token_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: sovereign.to_account_info(),
             },
-            &[vault_seeds],
+            sovereign_signer,
         ),
         amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+    reattach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer,
+        hook_detached,
     )?;
     
+    emit!(CreatorPurchasedTokensClaimed {
+        sovereign_id: sovereign.sovereign_id,
+        creator: ctx.accounts.creator.key(),
+        amount,
+    });
+    
     Ok(())
 }
 
@@ -991,27 +1129,27 @@ pub fn harvest_transfer_fees_handler<'info>(
 }
 
 // ============================================================
-// SWAP RECOVERY TOKENS → GOR (via SAMM CPI)
+// SWAP RECOVERY TOKENS → GOR (via AMM CPI)
 // ============================================================
 
-/// Swap sovereign tokens from the recovery token vault into GOR (via SAMM).
+/// Swap sovereign tokens from the recovery token vault into GOR (via the sovereign's AMM).
 /// This is step 2 of the recovery fee flow:
 ///   1. harvestTransferFees — collects Token-2022 withheld fees into recovery_token_vault
-///   2. swapRecoveryTokens — swaps those tokens to GOR via SAMM and adds to fee_vault
+///   2. swapRecoveryTokens — swaps those tokens to GOR via the AMM and adds to fee_vault
 ///
 /// Only callable during Recovery when fee_mode is RecoveryBoost or FairLaunch.
 /// Anyone can call this (permissionless — benefits all depositors).
 ///
 /// remaining_accounts order:
-///   [0]  amm_config           — SAMM AMM config (readonly)
-///   [1]  pool_state           — SAMM pool state (writable)
-///   [2]  samm_input_vault     — SAMM pool vault for sovereign token (writable)
-///   [3]  samm_output_vault    — SAMM pool vault for WGOR (writable)
-///   [4]  observation_state    — SAMM observation state (writable)
+///   [0]  amm_config           — AMM config (readonly)
+///   [1]  pool_state           — AMM pool state (writable)
+///   [2]  amm_input_vault      — AMM pool vault for sovereign token (writable)
+///   [3]  amm_output_vault     — AMM pool vault for WGOR / quote mint (writable)
+///   [4]  observation_state    — AMM observation state (writable)
 ///   [5]  token_program_2022   — Token-2022 program (readonly)
 ///   [6]  memo_program         — Memo program (readonly)
-///   [7]  wgor_mint            — WGOR mint address (readonly)
-///   [8..N] tick_arrays        — SAMM tick arrays for swap path (writable)
+///   [7]  wgor_mint            — WGOR (or token quote) mint address (readonly)
+///   [8..N] swap_accounts      — SAMM tick arrays for swap path (writable) / CPMM authority
 #[derive(Accounts)]
 pub struct SwapRecoveryTokens<'info> {
     #[account(mut)]
@@ -1037,7 +1175,9 @@ pub struct SwapRecoveryTokens<'info> {
     pub permanent_lock: Account<'info, PermanentLock>,
     
     /// Token mint (sovereign's Token-2022 mint)
+    /// Mutable so the transfer hook can be detached for the swap
     #[account(
+        mut,
         address = sovereign.token_mint
     )]
     pub token_mint: InterfaceAccount<'info, MintInterface>,
@@ -1052,14 +1192,15 @@ pub struct SwapRecoveryTokens<'info> {
     )]
     pub recovery_token_vault: InterfaceAccount<'info, TokenAccountInterface>,
     
-    /// WGOR ATA for the sovereign PDA — intermediate account for swap output
+    /// WGOR (or token quote) ATA for the sovereign PDA — intermediate account for swap output
     /// Created by the caller before this instruction (use createAssociatedTokenAccountIdempotent)
-    /// CHECK: Validated as WGOR ATA owned by sovereign PDA
+    /// CHECK: Validated as the pool quote mint ATA owned by sovereign PDA
     #[account(
         mut,
         constraint = sovereign_wgor_ata.owner == sovereign.key() @ SovereignError::Unauthorized,
+        constraint = sovereign_wgor_ata.mint == sovereign.pool_quote_mint() @ SovereignError::InvalidQuoteMint,
     )]
-    pub sovereign_wgor_ata: Account<'info, TokenAccount>,
+    pub sovereign_wgor_ata: InterfaceAccount<'info, TokenAccountInterface>,
     
     /// Fee vault (sol_vault PDA) — destination for unwrapped GOR
     /// CHECK: PDA that collects fees for investors
@@ -1070,9 +1211,23 @@ pub struct SwapRecoveryTokens<'info> {
     )]
     pub fee_vault: SystemAccount<'info>,
     
-    /// CHECK: Trashbin SAMM program
-    #[account(address = SAMM_PROGRAM_ID)]
-    pub samm_program: UncheckedAccount<'info>,
+    /// CHECK: The sovereign's AMM program
+    #[account(address = sovereign.amm_program @ SovereignError::InvalidAmmProgram)]
+    pub amm_program: UncheckedAccount<'info>,
+    
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<InterfaceAccount<'info, MintInterface>>,
+    
+    /// Quote vault - receives token quote swap output (authority = fee_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<InterfaceAccount<'info, TokenAccountInterface>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
     
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -1128,44 +1283,70 @@ pub fn swap_recovery_tokens_handler<'info>(
     ];
     let sovereign_signer = &[&sovereign_seeds[..]];
     
-    // Collect tick arrays from remaining_accounts[8..]
-    let tick_arrays: Vec<AccountInfo<'info>> = ctx.remaining_accounts[8..].to_vec();
-    
-    // Build SAMM swap accounts
-    let swap_accounts = samm_ix::SwapV2Accounts {
+    // Build AMM swap accounts (backend-specific accounts from remaining_accounts[8..])
+    let swap = AmmSwap {
         payer: sovereign.to_account_info(),                        // Sovereign PDA signs (owns token vault)
         amm_config: ctx.remaining_accounts[0].clone(),             // AMM config
         pool_state: ctx.remaining_accounts[1].clone(),             // Pool state
         input_token_account: ctx.accounts.recovery_token_vault.to_account_info(), // Token vault (input)
         output_token_account: ctx.accounts.sovereign_wgor_ata.to_account_info(), // WGOR ATA (output)
-        input_vault: ctx.remaining_accounts[2].clone(),            // SAMM vault for token
-        output_vault: ctx.remaining_accounts[3].clone(),           // SAMM vault for WGOR
+        input_vault: ctx.remaining_accounts[2].clone(),            // AMM vault for token
+        output_vault: ctx.remaining_accounts[3].clone(),           // AMM vault for WGOR
         observation_state: ctx.remaining_accounts[4].clone(),      // Observation state
         token_program: ctx.accounts.token_program.to_account_info(),
         token_program_2022: ctx.remaining_accounts[5].clone(),     // Token-2022 program
         memo_program: ctx.remaining_accounts[6].clone(),           // Memo program
         input_vault_mint: ctx.accounts.token_mint.to_account_info(), // Sovereign token mint
         output_vault_mint: ctx.remaining_accounts[7].clone(),      // WGOR mint
+        extra: ctx.remaining_accounts[8..].to_vec(),
     };
     
     // Execute swap: tokens → WGOR (min_amount_out = 0 for now, slippage handled by caller)
-    samm_cpi::swap_exact_input(
-        &ctx.accounts.samm_program.to_account_info(),
-        swap_accounts,
+    // The swap moves the sovereign token, which would re-enter this program through the hook
+    let hook_detached = detach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer,
+    )?;
+    amm::adapter(sovereign.amm_kind).swap_exact_input(
+        &ctx.accounts.amm_program.to_account_info(),
+        swap,
         swap_amount,
         0, // min_amount_out — accept any output (permissionless call, no MEV risk for protocol tokens)
-        0, // sqrt_price_limit — 0 means no limit
-        tick_arrays,
         sovereign_signer,
     )?;
+    reattach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer,
+        hook_detached,
+    )?;
     
-    msg!("Swapped {} tokens for WGOR via SAMM", swap_amount);
+    msg!("Swapped {} tokens for WGOR via AMM", swap_amount);
     
     // Read WGOR balance received
     ctx.accounts.sovereign_wgor_ata.reload()?;
     let wgor_amount = ctx.accounts.sovereign_wgor_ata.amount;
     
-    if wgor_amount > 0 {
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_ref(),
+        ctx.accounts.quote_vault.as_ref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    
+    if let Some(quote) = quote.as_ref() {
+        // Token quote: move the swap output into the quote vault
+        if wgor_amount > 0 {
+            quote.sweep_into_vault(
+                ctx.accounts.sovereign_wgor_ata.to_account_info(),
+                sovereign.to_account_info(),
+                sovereign_signer,
+                wgor_amount,
+            )?;
+            msg!("{} quote tokens moved to quote vault", wgor_amount);
+        }
+    } else if wgor_amount > 0 {
         // Close WGOR ATA → unwraps WGOR to native GOR → lamports go to fee_vault
         let close_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
             program_id: anchor_spl::token::ID,
@@ -1194,7 +1375,9 @@ pub fn swap_recovery_tokens_handler<'info>(
         )?;
         
         msg!("WGOR ATA closed → {} WGOR unwrapped to fee_vault", wgor_amount);
-        
+    }
+    
+    if wgor_amount > 0 {
         // Update recovery tracking
         sovereign.total_recovered = sovereign.total_recovered
             .checked_add(wgor_amount)
@@ -1,9 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount, Mint, Transfer, transfer};
+use anchor_spl::token_interface::{
+    Mint as MintInterface,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
 use crate::events::SovereignCreated;
+use crate::samm::{AmmConfig, SammAccountDeserialize};
+use crate::cpmm::CpmmAmmConfig;
+use super::finalize::load_existing_pool;
 
 /// Parameters for creating a new sovereign
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -11,12 +19,28 @@ pub struct CreateSovereignParams {
     /// Type of launch (TokenLaunch or BYOToken)
     pub sovereign_type: SovereignType,
     
-    /// SOL to raise (in lamports)
+    /// Quote to raise (lamports, or quote base units with a token quote mint)
     pub bond_target: u64,
     
+    /// Optional soft cap (defaults to bond_target, i.e. a single target)
+    pub soft_cap: Option<u64>,
+    
     /// Duration in seconds (7-30 days)
     pub bond_duration: i64,
     
+    /// Optional cap on each investor's total deposit
+    pub max_deposit_per_wallet: Option<u64>,
+    /// Optional minimum number of investors for bonding to succeed
+    pub min_depositor_count: Option<u32>,
+    
+    /// Optional private round: merkle root of (wallet, max_amount) leaves
+    pub allowlist_merkle_root: Option<[u8; 32]>,
+    /// End of the private round (required with allowlist_merkle_root)
+    pub allowlist_end_time: Option<i64>,
+    
+    /// Optional early-depositor share bonus (e.g. 1.2x for 24h decaying to 1.0x)
+    pub early_bird_bonus: Option<EarlyBirdBonus>,
+    
     /// Sovereign name (for metadata)
     pub name: String,
     
@@ -25,17 +49,27 @@ pub struct CreateSovereignParams {
     pub token_symbol: Option<String>,
     pub token_supply: Option<u64>,
     pub sell_fee_bps: Option<u16>,
+    /// Optional sell fee decay schedule (overrides sell_fee_bps as the initial rate)
+    pub sell_fee_decay: Option<SellFeeDecay>,
+    /// Optional anti-sniper launch guard
+    pub launch_guard: Option<LaunchGuard>,
     pub fee_mode: Option<FeeMode>,
     pub metadata_uri: Option<String>,
     
     // BYO Token only
     pub deposit_amount: Option<u64>,
     
-    // SAMM pool configuration
-    /// AMM config account address (determines swap fee tier)
+    // AMM pool configuration
+    /// AMM backend (defaults to the SAMM; the CPMM must be enabled by the protocol)
+    pub amm_kind: Option<AmmKind>,
+    /// AMM config account address (determines swap fee tier; swap_fee_bps is derived from it)
     pub amm_config: Pubkey,
-    /// Swap fee in basis points (for display/reference, must match amm_config tier)
-    pub swap_fee_bps: u16,
+    /// Optional concentrated LP band as a multiple of the launch price in bps
+    /// (e.g. 50000 = launch price / 5 to launch price * 5). Defaults to full range. SAMM only.
+    pub lp_price_band_bps: Option<u32>,
+    /// Optional LP ladder: extra positions, each taking share_bps of the raise over
+    /// its own band (band_bps 0 = full range). The primary position keeps the rest. SAMM only.
+    pub lp_ladder: Option<Vec<LpRung>>,
 }
 
 #[derive(Accounts)]
@@ -101,6 +135,24 @@ pub struct CreateSovereign<'info> {
     )]
     pub token_vault: Option<Box<Account<'info, TokenAccount>>>,
     
+    /// Optional quote mint allowlist entry - bond in this mint instead of native GOR
+    #[account(
+        seeds = [QUOTE_MINT_CONFIG_SEED, quote_mint_config.mint.as_ref()],
+        bump = quote_mint_config.bump,
+        constraint = quote_mint_config.enabled @ SovereignError::QuoteMintNotAllowed
+    )]
+    pub quote_mint_config: Option<Box<Account<'info, QuoteMintConfig>>>,
+    
+    /// CHECK: AMM config (fee tier) of the chosen backend - owner checked,
+    /// deserialized and validated in handler
+    #[account(address = params.amm_config @ SovereignError::InvalidAmmConfig)]
+    pub amm_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Optional existing SAMM pool for a BYO token - deserialized and validated in handler.
+    /// Finalization then adds liquidity to this pool instead of creating one.
+    #[account(owner = SAMM_PROGRAM_ID @ SovereignError::InvalidExistingPool)]
+    pub existing_pool_state: Option<UncheckedAccount<'info>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -118,18 +170,84 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
         SovereignError::ProtocolPaused
     );
     
+    // AMM backend - the CPMM is only available once the protocol sets its program
+    let amm_kind = params.amm_kind.unwrap_or_default();
+    let amm_program = match amm_kind {
+        AmmKind::Samm => SAMM_PROGRAM_ID,
+        AmmKind::Cpmm => {
+            require!(
+                protocol.cpmm_program != Pubkey::default(),
+                SovereignError::AmmBackendUnavailable
+            );
+            protocol.cpmm_program
+        }
+    };
+    
+    // Fee tier - must allow pool creation; the swap fee comes from its trade fee rate
+    require!(
+        *ctx.accounts.amm_config.owner == amm_program,
+        SovereignError::InvalidAmmConfigAccount
+    );
+    let (disable_create_pool, trade_fee_bps) = {
+        let data = ctx.accounts.amm_config.try_borrow_data()?;
+        match amm_kind {
+            AmmKind::Samm => {
+                let config = AmmConfig::try_deserialize(&data)
+                    .map_err(|_| SovereignError::InvalidAmmConfigAccount)?;
+                (config.disable_create_pool, config.trade_fee_bps())
+            }
+            AmmKind::Cpmm => {
+                let config = CpmmAmmConfig::try_deserialize(&data)
+                    .map_err(|_| SovereignError::InvalidAmmConfigAccount)?;
+                (config.disable_create_pool, config.trade_fee_bps())
+            }
+        }
+    };
+    require!(
+        !disable_create_pool,
+        SovereignError::AmmConfigDisabled
+    );
+    let swap_fee_bps = trade_fee_bps.ok_or(SovereignError::InvalidTradeFeeRate)?;
+    
+    // The price band, LP ladder and existing pools are concentrated liquidity features
+    if amm_kind != AmmKind::Samm {
+        require!(
+            params.lp_price_band_bps.is_none()
+                && params.lp_ladder.is_none()
+                && ctx.accounts.existing_pool_state.is_none(),
+            SovereignError::UnsupportedByAmm
+        );
+    }
+    
+    // Quote asset - native GOR unless an allowlisted quote mint is passed
+    // Token quote minimums are denominated in the quote mint's base units
+    let (quote_mint, min_bond_target, min_deposit) = match ctx.accounts.quote_mint_config.as_ref() {
+        Some(config) => (config.mint, config.min_bond_target, config.min_deposit),
+        None => (Pubkey::default(), protocol.min_bond_target, protocol.min_deposit),
+    };
+    
     // Validate common parameters
     require!(
-        params.bond_target >= protocol.min_bond_target,
+        params.bond_target >= min_bond_target,
         SovereignError::InvalidBondTarget
     );
+    let soft_cap = params.soft_cap.unwrap_or(params.bond_target);
+    require!(
+        soft_cap >= min_bond_target && soft_cap <= params.bond_target,
+        SovereignError::InvalidSoftCap
+    );
     require!(
         params.bond_duration >= MIN_BOND_DURATION && params.bond_duration <= MAX_BOND_DURATION,
         SovereignError::InvalidBondDuration
     );
     
-    // Calculate and escrow creation fee
-    let creation_fee = (params.bond_target as u128 * protocol.creation_fee_bps as u128 / BPS_100_PERCENT as u128) as u64;
+    // Calculate and escrow creation fee (always in GOR)
+    // A token quote bond target has no GOR value, so those sovereigns pay the flat minimum fee
+    let creation_fee = if quote_mint == Pubkey::default() {
+        (params.bond_target as u128 * protocol.creation_fee_bps as u128 / BPS_100_PERCENT as u128) as u64
+    } else {
+        protocol.min_fee_lamports
+    };
     
     // Transfer creation fee to escrow
     let escrow_transfer = anchor_lang::system_program::Transfer {
@@ -156,16 +274,99 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
     sovereign.token_name = params.token_name.clone().unwrap_or_default();
     sovereign.token_symbol = params.token_symbol.clone().unwrap_or_default();
     sovereign.metadata_uri = params.metadata_uri.clone().unwrap_or_default();
+    sovereign.quote_mint = quote_mint;
+    if quote_mint != Pubkey::default() {
+        sovereign.quote_min_deposit = min_deposit;
+    }
     sovereign.bond_target = params.bond_target;
+    sovereign.soft_cap = soft_cap;
     sovereign.bond_duration = params.bond_duration;
     sovereign.bond_deadline = clock.unix_timestamp + params.bond_duration;
     sovereign.creation_fee_escrowed = creation_fee;
+    sovereign.referral_fee_bps = protocol.referral_fee_bps;
+    sovereign.amm_kind = amm_kind;
+    sovereign.amm_program = amm_program;
     sovereign.amm_config = params.amm_config;
-    sovereign.swap_fee_bps = params.swap_fee_bps;
-    sovereign.pool_restricted = true;
+    sovereign.swap_fee_bps = swap_fee_bps;
+    if let Some(band_bps) = params.lp_price_band_bps {
+        require!(
+            (MIN_LP_PRICE_BAND_BPS..=MAX_LP_PRICE_BAND_BPS).contains(&band_bps),
+            SovereignError::InvalidPriceBand
+        );
+        sovereign.lp_price_band_bps = band_bps;
+    }
+    if let Some(ladder) = params.lp_ladder {
+        require!(ladder.len() <= MAX_LP_LADDER_RUNGS, SovereignError::InvalidLpLadder);
+        let mut ladder_share: u32 = 0;
+        for (rung, slot) in ladder.iter().zip(sovereign.lp_ladder.iter_mut()) {
+            require!(rung.is_enabled(), SovereignError::InvalidLpLadder);
+            require!(
+                rung.band_bps == 0
+                    || (MIN_LP_PRICE_BAND_BPS..=MAX_LP_PRICE_BAND_BPS).contains(&rung.band_bps),
+                SovereignError::InvalidPriceBand
+            );
+            ladder_share += rung.share_bps as u32;
+            *slot = *rung;
+        }
+        require!(
+            ladder_share + MIN_PRIMARY_LP_SHARE_BPS as u32 <= BPS_DENOMINATOR as u32,
+            SovereignError::InvalidLpLadder
+        );
+    }
+    // A CPMM pool has no LP restriction to set
+    sovereign.pool_restricted = amm_kind == AmmKind::Samm;
     sovereign.created_at = clock.unix_timestamp;
     sovereign.bump = ctx.bumps.sovereign;
     
+    // Optional whale protection
+    if let Some(max_per_wallet) = params.max_deposit_per_wallet {
+        require!(
+            max_per_wallet >= min_deposit,
+            SovereignError::InvalidDepositLimits
+        );
+        sovereign.max_deposit_per_wallet = max_per_wallet;
+    }
+    sovereign.min_depositor_count = params.min_depositor_count.unwrap_or(0);
+    // Every required depositor must fit in the bond at the minimum deposit
+    require!(
+        (sovereign.min_depositor_count as u128) * (min_deposit as u128) <= params.bond_target as u128,
+        SovereignError::InvalidDepositLimits
+    );
+    
+    // Optional allowlist round - must end within the bonding period
+    if let Some(root) = params.allowlist_merkle_root {
+        let end_time = params.allowlist_end_time
+            .ok_or(SovereignError::InvalidAllowlistConfig)?;
+        require!(root != [0u8; 32], SovereignError::InvalidAllowlistConfig);
+        require!(
+            end_time > clock.unix_timestamp && end_time <= sovereign.bond_deadline,
+            SovereignError::InvalidAllowlistConfig
+        );
+        sovereign.allowlist_merkle_root = root;
+        sovereign.allowlist_end_time = end_time;
+    }
+    
+    // Optional early-bird bonus - the curve must finish within the bonding period
+    if let Some(bonus) = params.early_bird_bonus {
+        require!(
+            bonus.multiplier_bps > BPS_DENOMINATOR
+                && bonus.multiplier_bps <= MAX_EARLY_BIRD_MULTIPLIER_BPS,
+            SovereignError::InvalidEarlyBirdBonus
+        );
+        require!(
+            bonus.hold_duration >= 0 && bonus.decay_duration >= 0,
+            SovereignError::InvalidEarlyBirdBonus
+        );
+        let curve_duration = bonus.hold_duration
+            .checked_add(bonus.decay_duration)
+            .ok_or(SovereignError::InvalidEarlyBirdBonus)?;
+        require!(
+            curve_duration > 0 && curve_duration <= params.bond_duration,
+            SovereignError::InvalidEarlyBirdBonus
+        );
+        sovereign.early_bird_bonus = bonus;
+    }
+    
     // Handle type-specific initialization
     match params.sovereign_type {
         SovereignType::TokenLaunch => {
@@ -174,9 +375,42 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
             require!(params.token_symbol.is_some(), SovereignError::MissingTokenSymbol);
             require!(params.token_supply.is_some(), SovereignError::MissingTokenSupply);
             
-            let sell_fee = params.sell_fee_bps.unwrap_or(0);
+            let mut sell_fee = params.sell_fee_bps.unwrap_or(0);
+            
+            if let Some(decay) = params.sell_fee_decay {
+                require!(decay.duration > 0, SovereignError::InvalidSellFeeDecay);
+                require!(decay.start_time >= 0, SovereignError::InvalidSellFeeDecay);
+                require!(
+                    decay.start_bps >= decay.end_bps,
+                    SovereignError::InvalidSellFeeDecay
+                );
+                require!(decay.start_bps <= MAX_SELL_FEE_BPS, SovereignError::SellFeeExceedsMax);
+                
+                // The mint starts at the schedule's opening rate
+                sell_fee = decay.start_bps;
+                sovereign.sell_fee_decay = decay;
+            }
+            
             require!(sell_fee <= MAX_SELL_FEE_BPS, SovereignError::SellFeeExceedsMax);
             
+            if let Some(guard) = params.launch_guard {
+                require!(
+                    guard.open_delay >= 0 && guard.open_delay <= MAX_LAUNCH_OPEN_DELAY,
+                    SovereignError::InvalidLaunchGuard
+                );
+                require!(
+                    guard.window_duration >= 0 && guard.window_duration <= MAX_LAUNCH_GUARD_WINDOW,
+                    SovereignError::InvalidLaunchGuard
+                );
+                // Caps without a window would never apply
+                require!(
+                    guard.window_duration > 0
+                        || (guard.max_buy_per_tx == 0 && guard.max_wallet_balance == 0),
+                    SovereignError::InvalidLaunchGuard
+                );
+                sovereign.launch_guard = guard;
+            }
+            
             sovereign.sell_fee_bps = sell_fee;
             sovereign.fee_mode = params.fee_mode.unwrap_or(FeeMode::CreatorRevenue);
             sovereign.token_supply_deposited = params.token_supply.unwrap();
@@ -220,6 +454,11 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
             );
             transfer(transfer_ctx, deposit_amount)?;
             
+            require!(
+                token_mint.key() != quote_mint,
+                SovereignError::InvalidQuoteMint
+            );
+            
             sovereign.token_mint = token_mint.key();
             sovereign.token_supply_deposited = deposit_amount;
             sovereign.token_total_supply = total_supply;
@@ -227,6 +466,21 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
         }
     }
     
+    // Optional existing pool (BYO only) - must pair the token with the quote mint
+    // under the same fee tier and tick spacing the locked position uses
+    if let Some(pool_info) = ctx.accounts.existing_pool_state.as_ref() {
+        require!(
+            sovereign.sovereign_type == SovereignType::BYOToken,
+            SovereignError::ExistingPoolRequiresByoToken
+        );
+        load_existing_pool(pool_info, sovereign)?;
+        
+        sovereign.existing_pool = pool_info.key();
+        // Only the pool's authority can set its status, so a pool we did not
+        // create is never restricted to the permanent lock
+        sovereign.pool_restricted = false;
+    }
+    
     // Initialize creator tracker
     creator_tracker.sovereign = sovereign.key();
     creator_tracker.creator = ctx.accounts.creator.key();
@@ -244,7 +498,9 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
         creator: sovereign.creator,
         token_mint: sovereign.token_mint,
         sovereign_type: sovereign.sovereign_type,
+        quote_mint: sovereign.quote_mint,
         bond_target: sovereign.bond_target,
+        soft_cap: sovereign.soft_cap,
         bond_deadline: sovereign.bond_deadline,
         token_supply_deposited: sovereign.token_supply_deposited,
         creation_fee_escrowed: creation_fee,
@@ -252,7 +508,59 @@ pub fn handler(ctx: Context<CreateSovereign>, params: CreateSovereignParams) ->
         fee_mode: sovereign.fee_mode,
         amm_config: sovereign.amm_config,
         swap_fee_bps: sovereign.swap_fee_bps,
+        lp_price_band_bps: sovereign.lp_price_band_bps,
+        lp_ladder_rungs: sovereign.ladder_rung_count(),
+        existing_pool: sovereign.existing_pool,
+        amm_kind: sovereign.amm_kind,
+        amm_program: sovereign.amm_program,
     });
     
     Ok(())
 }
+
+/// Create the quote vault for a sovereign bonding in a token quote mint
+/// Permissionless - must be called before the first deposit.
+/// The vault's authority is the sol_vault PDA, which signs every payout.
+#[derive(Accounts)]
+pub struct CreateQuoteVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump,
+        constraint = !sovereign.is_native_quote() @ SovereignError::InvalidQuoteMint
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+    
+    #[account(
+        address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint,
+        mint::token_program = quote_token_program
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, MintInterface>>,
+    
+    /// CHECK: PDA that owns the quote vault
+    #[account(
+        seeds = [SOL_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        token::mint = quote_mint,
+        token::authority = sol_vault,
+        token::token_program = quote_token_program,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+    
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_quote_vault_handler(_ctx: Context<CreateQuoteVault>) -> Result<()> {
+    Ok(())
+}
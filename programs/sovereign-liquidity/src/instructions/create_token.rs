@@ -7,6 +7,7 @@ use anchor_spl::token_2022::{
         instruction as token_instruction,
         extension::{
             ExtensionType, transfer_fee,
+            transfer_hook,
             metadata_pointer,
         },
     },
@@ -120,17 +121,18 @@ pub fn handler(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<(
     
     // Calculate space needed for Token-2022 mint with extensions
     // MetadataPointer is always added (points metadata to the mint itself)
-    // TransferFeeConfig and TransferHook are added when sell fee > 0
-    let extensions = if sovereign.sell_fee_bps > 0 {
-        vec![
-            ExtensionType::TransferFeeConfig,  // Automatic fee withholding
-            ExtensionType::MetadataPointer,    // Points to self for token metadata
-        ]
-    } else {
-        vec![
-            ExtensionType::MetadataPointer,    // Points to self for token metadata
-        ]
-    };
+    // TransferFeeConfig is added when sell fee > 0
+    // TransferHook is added when there is a fee or a launch guard to enforce
+    let has_fee = sovereign.sell_fee_bps > 0;
+    let has_hook = sovereign.has_transfer_hook();
+    let mut extensions = Vec::new();
+    if has_fee {
+        extensions.push(ExtensionType::TransferFeeConfig); // Automatic fee withholding
+    }
+    if has_hook {
+        extensions.push(ExtensionType::TransferHook);      // Invokes this program on every transfer
+    }
+    extensions.push(ExtensionType::MetadataPointer);       // Points to self for token metadata
     
     let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?;
     
@@ -168,8 +170,8 @@ pub fn handler(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<(
         mint_signer,
     )?;
     
-    // Initialize extensions if sell fee is configured
-    if sovereign.sell_fee_bps > 0 {
+    // Initialize TransferFeeConfig if a sell fee is configured
+    if has_fee {
         // Initialize TransferFeeConfig extension
         // Fee authority = sovereign PDA (can update fees)
         // Withdraw authority = sovereign PDA (can withdraw collected fees)
@@ -191,6 +193,26 @@ pub fn handler(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<(
         )?;
     }
     
+    if has_hook {
+        // Initialize TransferHook extension - Token-2022 calls this program's
+        // transfer_hook_execute (via the fallback) on every transfer
+        // Authority = sovereign PDA (can repoint or disable the hook)
+        let init_hook_ix = transfer_hook::instruction::initialize(
+            &spl_token_2022::ID,
+            &token_mint.key(),
+            Some(sovereign.key()), // Transfer hook authority
+            Some(crate::ID),       // Transfer hook program = this program
+        )?;
+        
+        invoke_signed(
+            &init_hook_ix,
+            &[
+                token_mint.to_account_info(),
+            ],
+            mint_signer,
+        )?;
+    }
+    
     // Initialize MetadataPointer extension - points metadata to the mint itself
     let init_metadata_pointer_ix = metadata_pointer::instruction::initialize(
         &spl_token_2022::ID,
@@ -237,13 +259,13 @@ pub fn handler(ctx: Context<CreateToken>, params: CreateTokenParams) -> Result<(
     // Calculate space for Token-2022 token account
     // Must include extensions matching the mint: TransferFeeAmount (for TransferFeeConfig)
     // and TransferHookAccount (for TransferHook)
-    let vault_extensions: Vec<ExtensionType> = if sovereign.sell_fee_bps > 0 {
-        vec![
-            ExtensionType::TransferFeeAmount,
-        ]
-    } else {
-        vec![]
-    };
+    let mut vault_extensions: Vec<ExtensionType> = Vec::new();
+    if has_fee {
+        vault_extensions.push(ExtensionType::TransferFeeAmount);
+    }
+    if has_hook {
+        vault_extensions.push(ExtensionType::TransferHookAccount);
+    }
     let vault_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&vault_extensions)?;
     let vault_lamports = rent.minimum_balance(vault_len);
     
@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint as MintInterface,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+};
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
-use crate::events::{InvestorDeposited, CreatorEscrowed};
+use crate::events::{InvestorDeposited, CreatorEscrowed, ReferralVolumeUpdated};
 
 #[derive(Accounts)]
+#[instruction(amount: u64, allowlist_proof: Option<AllowlistProof>, referrer: Option<Pubkey>)]
 pub struct Deposit<'info> {
     #[account(mut)]
     pub depositor: Signer<'info>,
@@ -32,6 +39,16 @@ pub struct Deposit<'info> {
     )]
     pub deposit_record: Account<'info, DepositRecord>,
     
+    /// Referral record for the referrer - required for referred deposits
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ReferralRecord::LEN,
+        seeds = [REFERRAL_RECORD_SEED, sovereign.key().as_ref(), referrer.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub referral_record: Option<Box<Account<'info, ReferralRecord>>>,
+    
     /// SOL vault to hold deposits during bonding
     /// CHECK: PDA that holds SOL
     #[account(
@@ -41,48 +58,76 @@ pub struct Deposit<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Depositor's quote token account (source of token quote deposits)
+    #[account(mut)]
+    pub depositor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+/// Merkle proof of an allowlist entry (wallet, max_amount)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    /// Maximum total deposit for this wallet during the private round
+    pub max_amount: u64,
+    /// Sibling hashes from leaf to root
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub fn handler(
+    ctx: Context<Deposit>,
+    amount: u64,
+    allowlist_proof: Option<AllowlistProof>,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
     let sovereign = &mut ctx.accounts.sovereign;
     let deposit_record = &mut ctx.accounts.deposit_record;
     let protocol = &ctx.accounts.protocol_state;
-    let clock = Clock::get()?;
-    
-    // Check protocol pause status
-    require!(
-        !protocol.paused,
-        SovereignError::ProtocolPaused
-    );
-    
-    // Validate state - use atomic check to prevent race conditions
-    // Must be Bonding AND not already at/past target
-    require!(
-        sovereign.state == SovereignStatus::Bonding,
-        SovereignError::InvalidState
-    );
     
-    // Double-check target hasn't been reached (prevents race condition)
-    require!(
-        sovereign.total_deposited < sovereign.bond_target,
-        SovereignError::BondingComplete
-    );
-    
-    require!(
-        !sovereign.is_deadline_passed(clock.unix_timestamp),
-        SovereignError::DeadlinePassed
-    );
-    require!(amount > 0, SovereignError::ZeroDeposit);
-    require!(
-        amount >= protocol.min_deposit,
-        SovereignError::DepositTooSmall
-    );
+    validate_deposit(sovereign, protocol, amount)?;
     
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
     let is_creator = ctx.accounts.depositor.key() == sovereign.creator;
     
     if is_creator {
         // CREATOR DEPOSIT: Goes to escrow for market buy (NOT LP)
+        // The market buy spends GOR, so escrow needs a native GOR sovereign
+        require!(
+            quote.is_none(),
+            SovereignError::CreatorEscrowRequiresNativeQuote
+        );
+        
+        // Creator escrow is never referred
+        require!(
+            referrer.is_none() && ctx.accounts.referral_record.is_none(),
+            SovereignError::InvalidReferrer
+        );
+        
+        // The market buy runs at finalization, so the pool must open immediately
+        require!(
+            sovereign.launch_guard.open_delay == 0,
+            SovereignError::CreatorBuyBlockedByOpenDelay
+        );
+        
         let max_creator_buy = sovereign.max_creator_buy_in();
         require!(
             sovereign.creator_escrow.checked_add(amount).unwrap() <= max_creator_buy,
@@ -112,72 +157,429 @@ pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         });
     } else {
         // INVESTOR DEPOSIT: Counts toward bond target
+        let had_deposit = deposit_record.has_deposit();
+        let actual_amount = investor_deposit(
+            sovereign,
+            deposit_record,
+            protocol,
+            ctx.accounts.depositor.key(),
+            ctx.accounts.depositor.key(),
+            ctx.bumps.deposit_record,
+            amount,
+            allowlist_proof.as_ref(),
+        )?;
         
-        // Re-check bond target (defense against concurrent transactions)
-        let remaining_to_target = sovereign.bond_target
-            .checked_sub(sovereign.total_deposited)
-            .ok_or(SovereignError::BondingComplete)?;
+        // Transfer quote to vault (only actual_amount)
+        quote::collect(
+            quote.as_ref(),
+            &ctx.accounts.depositor.to_account_info(),
+            ctx.accounts.depositor_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.sol_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            actual_amount,
+        )?;
         
+        track_referral(
+            sovereign,
+            deposit_record,
+            ctx.accounts.referral_record.as_deref_mut(),
+            ctx.bumps.referral_record,
+            referrer,
+            had_deposit,
+            actual_amount,
+        )?;
+    }
+    
+    update_bonding_state(sovereign)?;
+    
+    Ok(())
+}
+
+// ============================================================
+// DEPOSIT FOR (on behalf of a beneficiary)
+// ============================================================
+
+/// Deposit SOL on behalf of a beneficiary wallet
+/// The payer funds the deposit; the DepositRecord is keyed to the beneficiary,
+/// so only the beneficiary can withdraw, mint the Genesis NFT or claim refunds.
+#[derive(Accounts)]
+#[instruction(
+    beneficiary: Pubkey,
+    amount: u64,
+    allowlist_proof: Option<AllowlistProof>,
+    referrer: Option<Pubkey>,
+)]
+pub struct DepositFor<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+    
+    /// Deposit record keyed to the beneficiary - initialized if new
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DepositRecord::LEN,
+        seeds = [DEPOSIT_RECORD_SEED, sovereign.key().as_ref(), beneficiary.as_ref()],
+        bump
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+    
+    /// Referral record for the referrer - required for referred deposits
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ReferralRecord::LEN,
+        seeds = [REFERRAL_RECORD_SEED, sovereign.key().as_ref(), referrer.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub referral_record: Option<Box<Account<'info, ReferralRecord>>>,
+    
+    /// SOL vault to hold deposits during bonding
+    /// CHECK: PDA that holds SOL
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Payer's quote token account (source of token quote deposits)
+    #[account(mut)]
+    pub payer_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_for_handler(
+    ctx: Context<DepositFor>,
+    beneficiary: Pubkey,
+    amount: u64,
+    allowlist_proof: Option<AllowlistProof>,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    let sovereign = &mut ctx.accounts.sovereign;
+    let deposit_record = &mut ctx.accounts.deposit_record;
+    let protocol = &ctx.accounts.protocol_state;
+    
+    validate_deposit(sovereign, protocol, amount)?;
+    
+    // Creator escrow is separate from investor deposits
+    require!(
+        beneficiary != sovereign.creator,
+        SovereignError::InvalidBeneficiary
+    );
+    
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    let had_deposit = deposit_record.has_deposit();
+    let actual_amount = investor_deposit(
+        sovereign,
+        deposit_record,
+        protocol,
+        ctx.accounts.payer.key(),
+        beneficiary,
+        ctx.bumps.deposit_record,
+        amount,
+        allowlist_proof.as_ref(),
+    )?;
+    
+    // Transfer quote to vault (only actual_amount)
+    quote::collect(
+        quote.as_ref(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.accounts.payer_quote_account.as_ref().map(|a| a.to_account_info()),
+        &ctx.accounts.sol_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        actual_amount,
+    )?;
+    
+    track_referral(
+        sovereign,
+        deposit_record,
+        ctx.accounts.referral_record.as_deref_mut(),
+        ctx.bumps.referral_record,
+        referrer,
+        had_deposit,
+        actual_amount,
+    )?;
+    
+    update_bonding_state(sovereign)?;
+    
+    Ok(())
+}
+
+// ============================================================
+// SHARED DEPOSIT LOGIC
+// ============================================================
+
+/// Common checks for any deposit into a bonding sovereign
+fn validate_deposit(
+    sovereign: &SovereignState,
+    protocol: &ProtocolState,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Check protocol pause status
+    require!(
+        !protocol.paused,
+        SovereignError::ProtocolPaused
+    );
+    
+    // Validate state - use atomic check to prevent race conditions
+    // Must be Bonding AND not already at/past target
+    require!(
+        sovereign.state == SovereignStatus::Bonding,
+        SovereignError::InvalidState
+    );
+    
+    // Double-check target hasn't been reached (prevents race condition)
+    require!(
+        sovereign.total_deposited < sovereign.bond_target,
+        SovereignError::BondingComplete
+    );
+    
+    require!(
+        !sovereign.is_deadline_passed(clock.unix_timestamp),
+        SovereignError::DeadlinePassed
+    );
+    require!(amount > 0, SovereignError::ZeroDeposit);
+    require!(
+        amount >= sovereign.min_deposit(protocol.min_deposit),
+        SovereignError::DepositTooSmall
+    );
+    
+    Ok(())
+}
+
+/// Record an investor deposit for `depositor`, funded by `payer`
+/// Returns the amount actually deposited (capped at the remaining bond target);
+/// the caller transfers exactly that amount into the vault.
+#[allow(clippy::too_many_arguments)]
+fn investor_deposit<'info>(
+    sovereign: &mut Account<'info, SovereignState>,
+    deposit_record: &mut Account<'info, DepositRecord>,
+    protocol: &ProtocolState,
+    payer: Pubkey,
+    depositor: Pubkey,
+    deposit_record_bump: u8,
+    amount: u64,
+    allowlist_proof: Option<&AllowlistProof>,
+) -> Result<u64> {
+    let clock = Clock::get()?;
+    
+    // Re-check bond target (defense against concurrent transactions)
+    let remaining_to_target = sovereign.bond_target
+        .checked_sub(sovereign.total_deposited)
+        .ok_or(SovereignError::BondingComplete)?;
+    
+    require!(
+        remaining_to_target > 0,
+        SovereignError::BondingComplete
+    );
+    
+    // Hold back room for the depositors still needed to reach min_depositor_count,
+    // otherwise a full bond short of depositors could never finalize
+    let min_deposit = sovereign.min_deposit(protocol.min_deposit);
+    let reserved = sovereign.reserved_for_missing_depositors(deposit_record.amount == 0, min_deposit);
+    let available = remaining_to_target.saturating_sub(reserved);
+    require!(
+        available > 0,
+        SovereignError::BondReservedForNewDepositors
+    );
+    
+    // Cap deposit to remaining amount available (prevents over-bonding)
+    let actual_amount = std::cmp::min(amount, available);
+    
+    // Calculate refund if user sent excess
+    let _refund_amount = amount.saturating_sub(actual_amount);
+    
+    // If refund needed, we don't transfer the excess (it stays with user)
+    // The caller only transfers actual_amount into the vault
+    
+    // Validate actual_amount meets minimum (after capping)
+    require!(
+        actual_amount >= min_deposit || available < min_deposit,
+        SovereignError::DepositTooSmall
+    );
+    
+    // Per-wallet cap (whale protection)
+    if sovereign.max_deposit_per_wallet > 0 {
+        let new_total = deposit_record.amount
+            .checked_add(actual_amount)
+            .ok_or(SovereignError::Overflow)?;
         require!(
-            remaining_to_target > 0,
-            SovereignError::BondingComplete
+            new_total <= sovereign.max_deposit_per_wallet,
+            SovereignError::DepositExceedsWalletCap
         );
-        
-        // Cap deposit to remaining amount needed (prevents over-bonding)
-        let actual_amount = std::cmp::min(amount, remaining_to_target);
-        
-        // Calculate refund if user sent excess
-        let _refund_amount = amount.checked_sub(actual_amount).unwrap_or(0);
-        
-        // If refund needed, we don't transfer the excess (it stays with user)
-        // The user only sends actual_amount via SOL transfer below
-        
-        // Validate actual_amount meets minimum (after capping)
+    }
+    
+    // Private round: only allowlisted wallets, each up to its own cap
+    if sovereign.is_allowlist_active(clock.unix_timestamp) {
+        let entry = allowlist_proof.ok_or(SovereignError::AllowlistProofRequired)?;
         require!(
-            actual_amount >= protocol.min_deposit || remaining_to_target < protocol.min_deposit,
-            SovereignError::DepositTooSmall
+            sovereign.verify_allowlist(
+                &depositor,
+                entry.max_amount,
+                &entry.proof,
+            ),
+            SovereignError::InvalidAllowlistProof
         );
-        
-        // Transfer SOL to vault (only actual_amount)
-        let transfer_ix = anchor_lang::system_program::Transfer {
-            from: ctx.accounts.depositor.to_account_info(),
-            to: ctx.accounts.sol_vault.to_account_info(),
+        let new_total = deposit_record.amount
+            .checked_add(actual_amount)
+            .ok_or(SovereignError::Overflow)?;
+        require!(
+            new_total <= entry.max_amount,
+            SovereignError::AllowlistCapExceeded
+        );
+    }
+    
+    // Initialize deposit record if new
+    if deposit_record.amount == 0 {
+        deposit_record.sovereign = sovereign.key();
+        deposit_record.depositor = depositor;
+        deposit_record.deposited_at = clock.unix_timestamp;
+        deposit_record.bump = deposit_record_bump;
+        sovereign.depositor_count = sovereign.depositor_count.checked_add(1).unwrap();
+    }
+    
+    // Early depositors earn extra share units
+    let share_units = sovereign.early_bird_bonus
+        .share_units(actual_amount, sovereign.created_at, clock.unix_timestamp)
+        .ok_or(SovereignError::Overflow)?;
+    
+    deposit_record.amount = deposit_record.amount.checked_add(actual_amount).unwrap();
+    deposit_record.share_units = deposit_record.share_units
+        .checked_add(share_units)
+        .ok_or(SovereignError::Overflow)?;
+    sovereign.total_deposited = sovereign.total_deposited.checked_add(actual_amount).unwrap();
+    sovereign.total_share_units = sovereign.total_share_units
+        .checked_add(share_units)
+        .ok_or(SovereignError::Overflow)?;
+    
+    emit!(InvestorDeposited {
+        sovereign_id: sovereign.sovereign_id,
+        depositor,
+        payer,
+        amount: actual_amount,
+        total_deposited: sovereign.total_deposited,
+        depositor_count: sovereign.depositor_count,
+    });
+    
+    Ok(actual_amount)
+}
+
+/// Attribute deposited volume to the depositor's referrer
+/// The referrer is chosen on the depositor's first deposit (or after a full
+/// withdrawal) and all of the depositor's volume counts toward it from then on.
+fn track_referral(
+    sovereign: &mut Account<SovereignState>,
+    deposit_record: &mut DepositRecord,
+    referral_record: Option<&mut Account<ReferralRecord>>,
+    referral_bump: Option<u8>,
+    referrer: Option<Pubkey>,
+    had_deposit: bool,
+    amount: u64,
+) -> Result<()> {
+    if !had_deposit {
+        deposit_record.referrer = match referrer {
+            Some(referrer) => {
+                require!(
+                    referrer != deposit_record.depositor
+                        && referrer != sovereign.creator
+                        && referrer != Pubkey::default(),
+                    SovereignError::InvalidReferrer
+                );
+                referrer
+            }
+            None => Pubkey::default(),
         };
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                transfer_ix,
-            ),
-            actual_amount,
-        )?;
-        
-        // Initialize deposit record if new
-        if deposit_record.amount == 0 {
-            deposit_record.sovereign = sovereign.key();
-            deposit_record.depositor = ctx.accounts.depositor.key();
-            deposit_record.deposited_at = clock.unix_timestamp;
-            deposit_record.bump = ctx.bumps.deposit_record;
-            sovereign.depositor_count = sovereign.depositor_count.checked_add(1).unwrap();
-        }
-        
-        deposit_record.amount = deposit_record.amount.checked_add(actual_amount).unwrap();
-        sovereign.total_deposited = sovereign.total_deposited.checked_add(actual_amount).unwrap();
-        
-        emit!(InvestorDeposited {
-            sovereign_id: sovereign.sovereign_id,
-            depositor: ctx.accounts.depositor.key(),
-            amount: actual_amount,
-            total_deposited: sovereign.total_deposited,
-            depositor_count: sovereign.depositor_count,
-        });
+    } else if referrer.is_some() || deposit_record.has_referrer() {
+        // Follow-up deposits must name the existing referrer (if any)
+        require!(
+            referrer == Some(deposit_record.referrer),
+            SovereignError::ReferrerMismatch
+        );
+    }
+    
+    if !deposit_record.has_referrer() {
+        require!(referral_record.is_none(), SovereignError::InvalidReferrer);
+        return Ok(());
+    }
+    
+    let referral_record = referral_record.ok_or(SovereignError::ReferralRecordRequired)?;
+    
+    // Initialize referral record if new
+    if referral_record.referrer == Pubkey::default() {
+        referral_record.sovereign = sovereign.key();
+        referral_record.referrer = deposit_record.referrer;
+        referral_record.bump = referral_bump.ok_or(SovereignError::ReferralRecordRequired)?;
     }
     
-    // ATOMIC state transition: Check if bond target is now met
-    // This happens immediately to prevent race conditions
-    if sovereign.total_deposited >= sovereign.bond_target {
+    if !had_deposit {
+        referral_record.referred_count = referral_record.referred_count
+            .checked_add(1)
+            .ok_or(SovereignError::Overflow)?;
+    }
+    referral_record.referred_volume = referral_record.referred_volume
+        .checked_add(amount)
+        .ok_or(SovereignError::Overflow)?;
+    sovereign.total_referred_volume = sovereign.total_referred_volume
+        .checked_add(amount)
+        .ok_or(SovereignError::Overflow)?;
+    
+    emit!(ReferralVolumeUpdated {
+        sovereign_id: sovereign.sovereign_id,
+        referrer: referral_record.referrer,
+        depositor: deposit_record.depositor,
+        amount,
+        is_deposit: true,
+        referred_volume: referral_record.referred_volume,
+        total_referred_volume: sovereign.total_referred_volume,
+    });
+    
+    Ok(())
+}
+
+/// ATOMIC state transition: Check if bond target is now met
+/// This happens immediately to prevent race conditions.
+/// With a min_depositor_count, investor_deposit reserves room for the missing
+/// depositors, so the bond can only fill once enough investors hold a share.
+fn update_bonding_state(sovereign: &mut SovereignState) -> Result<()> {
+    if sovereign.total_deposited >= sovereign.bond_target && sovereign.has_min_depositors() {
         // Transition to Finalizing immediately to block new deposits
         sovereign.state = SovereignStatus::Finalizing;
+        sovereign.finalizing_at = Clock::get()?.unix_timestamp;
     }
-    
     Ok(())
 }
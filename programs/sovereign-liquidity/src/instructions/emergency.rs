@@ -7,11 +7,13 @@ use anchor_spl::token_interface::{
     TokenInterface,
 };
 use spl_token_2022::extension::transfer_fee;
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
 use crate::events::{EmergencyUnlocked, EmergencyWithdrawal, EmergencyCreatorWithdrawal, SovereignRetired};
-use crate::samm::{self, instructions as samm_ix, cpi as samm_cpi, SammAccountDeserialize};
+use crate::amm::{self, LockPosition};
+use super::transfer_hook::{detach_transfer_hook, reattach_transfer_hook};
 
 // ============================================================
 // EMERGENCY UNLOCK
@@ -131,6 +133,24 @@ pub struct EmergencyWithdraw<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Holder's quote token account (receives token quote payouts)
+    #[account(mut)]
+    pub holder_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     /// SPL Token program — needed if burning Genesis NFT
     pub token_program: Program<'info, anchor_spl::token::Token>,
     pub system_program: Program<'info, System>,
@@ -141,6 +161,18 @@ pub fn emergency_withdraw_handler<'info>(
 ) -> Result<()> {
     let sovereign = &mut ctx.accounts.sovereign;
     let deposit_record = &ctx.accounts.deposit_record;
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    let sovereign_key = sovereign.key();
+    let vault_seeds: &[&[u8]] = &[
+        SOL_VAULT_SEED,
+        sovereign_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ];
     
     // Cannot be creator (they use emergency_withdraw_creator)
     require!(
@@ -209,10 +241,8 @@ pub fn emergency_withdraw_handler<'info>(
         msg!("Genesis NFT burned for emergency withdrawal");
         
         // ---- Calculate proportional GOR share (capped at original deposit) ----
-        let share_bps = deposit_record.amount
-            .checked_mul(BPS_DENOMINATOR as u64)
-            .ok_or(SovereignError::Overflow)?
-            .checked_div(sovereign.total_deposited)
+        let share_bps = sovereign
+            .share_bps(deposit_record.share_units)
             .ok_or(SovereignError::DivisionByZero)?;
         
         // GOR share from unwind SOL balance — capped at original deposit amount
@@ -224,30 +254,21 @@ pub fn emergency_withdraw_handler<'info>(
             .ok_or(SovereignError::DivisionByZero)?;
         let sol_share = proportional_share.min(deposit_record.amount);
         
-        // Transfer GOR from sol_vault to holder
+        // Transfer GOR (or token quote) from the vault to holder
         if sol_share > 0 {
-            let vault_balance = ctx.accounts.sol_vault.lamports();
+            let vault_balance = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?;
             require!(
                 vault_balance >= sol_share,
                 SovereignError::InsufficientVaultBalance
             );
             
-            let sovereign_key = sovereign.key();
-            let vault_seeds: &[&[u8]] = &[
-                SOL_VAULT_SEED,
-                sovereign_key.as_ref(),
-                &[ctx.bumps.sol_vault],
-            ];
-            
-            anchor_lang::system_program::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.sol_vault.to_account_info(),
-                        to: ctx.accounts.holder.to_account_info(),
-                    },
-                    &[vault_seeds],
-                ),
+            quote::pay_out(
+                quote.as_ref(),
+                &ctx.accounts.sol_vault.to_account_info(),
+                vault_seeds,
+                &ctx.accounts.holder.to_account_info(),
+                ctx.accounts.holder_quote_account.as_ref().map(|a| a.to_account_info()),
+                &ctx.accounts.system_program.to_account_info(),
                 sol_share,
             )?;
         }
@@ -267,28 +288,19 @@ pub fn emergency_withdraw_handler<'info>(
             SovereignError::Unauthorized
         );
         
-        let vault_balance = ctx.accounts.sol_vault.lamports();
+        let vault_balance = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?;
         require!(
             vault_balance >= amount,
             SovereignError::InsufficientVaultBalance
         );
         
-        let sovereign_key = sovereign.key();
-        let vault_seeds: &[&[u8]] = &[
-            SOL_VAULT_SEED,
-            sovereign_key.as_ref(),
-            &[ctx.bumps.sol_vault],
-        ];
-        
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.holder.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.holder.to_account_info(),
+            ctx.accounts.holder_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             amount,
         )?;
         
@@ -302,7 +314,7 @@ pub fn emergency_withdraw_handler<'info>(
     // Note: deposit_record is closed via `close = holder` and rent returned
     
     // Retire only when vault is empty AND creator has reclaimed everything (GOR + tokens)
-    let remaining = ctx.accounts.sol_vault.lamports();
+    let remaining = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?;
     if remaining == 0 && sovereign.creator_escrow == 0 && sovereign.creation_fee_escrowed == 0 && sovereign.token_supply_deposited == 0 {
         sovereign.state = SovereignStatus::Retired;
         emit!(SovereignRetired {
@@ -344,15 +356,23 @@ pub struct EmergencyWithdrawCreator<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
-    /// Creation fee escrow - returned to creator on emergency
+    /// Creation fee escrow - returned to creator on emergency, unless it
+    /// still holds unclaimed referral rewards (closed in the handler)
     #[account(
         mut,
-        close = creator,
         seeds = [CREATION_FEE_ESCROW_SEED, sovereign.key().as_ref()],
         bump
     )]
     pub creation_fee_escrow: Account<'info, CreationFeeEscrow>,
     
+    /// Quote vault - required when the sovereign bonds in a token quote mint
+    /// (only read to decide whether the sovereign can retire)
+    #[account(
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
     /// CHECK: Token vault PDA holding creator's tokens (Token or Token-2022)
     #[account(
         mut,
@@ -382,7 +402,12 @@ pub fn emergency_withdraw_creator_handler(ctx: Context<EmergencyWithdrawCreator>
     let sovereign = &mut ctx.accounts.sovereign;
     
     let escrow_amount = sovereign.creator_escrow;
-    let creation_fee = ctx.accounts.creation_fee_escrow.amount;
+    
+    // Once released, the escrow only holds the referrers' unclaimed pool:
+    // it stays open for claim_referral_reward instead of going to the creator
+    let fee_escrow = &ctx.accounts.creation_fee_escrow;
+    let holds_referral_pool = fee_escrow.released && fee_escrow.amount > 0;
+    let creation_fee = if fee_escrow.released { 0 } else { fee_escrow.amount };
     
     // Transfer creator escrow from vault if any
     if escrow_amount > 0 {
@@ -460,7 +485,7 @@ pub fn emergency_withdraw_creator_handler(ctx: Context<EmergencyWithdrawCreator>
                     )?;
                 } else if sovereign.sovereign_type == SovereignType::TokenLaunch {
                     // RECOVER TokenLaunch tokens: must disable hook + fee to avoid reentrancy and fee deduction
-                    let original_fee_bps = sovereign.sell_fee_bps;
+                    let original_fee_bps = sovereign.configured_sell_fee_bps();
 
                     // Step 1: Set transfer fee to 0 (so creator gets 100% of tokens)
                     if original_fee_bps > 0 {
@@ -482,6 +507,13 @@ pub fn emergency_withdraw_creator_handler(ctx: Context<EmergencyWithdrawCreator>
                         )?;
                     }
 
+                    // Step 1.5: Detach the transfer hook (the transfer would re-enter this program)
+                    let hook_detached = detach_transfer_hook(
+                        &ctx.accounts.token_mint.to_account_info(),
+                        &sovereign.to_account_info(),
+                        &[signer_seeds],
+                    )?;
+
                     // Step 2: Transfer tokens (fee may still apply if epoch-based)
                     let transfer_ix = spl_token_2022::instruction::transfer_checked(
                         &ctx.accounts.token_program.key(),
@@ -542,7 +574,13 @@ pub fn emergency_withdraw_creator_handler(ctx: Context<EmergencyWithdrawCreator>
                         )?;
                     }
 
-                    // Step 3: Restore transfer fee
+                    // Step 3: Restore transfer hook and fee
+                    reattach_transfer_hook(
+                        &ctx.accounts.token_mint.to_account_info(),
+                        &sovereign.to_account_info(),
+                        &[signer_seeds],
+                        hook_detached,
+                    )?;
                     if original_fee_bps > 0 {
                         let restore_fee_ix = transfer_fee::instruction::set_transfer_fee(
                             &spl_token_2022::ID,
@@ -605,10 +643,19 @@ pub fn emergency_withdraw_creator_handler(ctx: Context<EmergencyWithdrawCreator>
         token_amount: token_amount_handled,
     });
     
-    // Note: creation_fee_escrow is closed via `close = creator` and rent + fee returned
+    // Close creation_fee_escrow: rent + any unreleased fee return to the creator
+    if !holds_referral_pool {
+        ctx.accounts.creation_fee_escrow.close(ctx.accounts.creator.to_account_info())?;
+    }
     
     // Retire when vault is empty AND creator has reclaimed everything (GOR + tokens)
-    let remaining = ctx.accounts.sol_vault.lamports();
+    let remaining = if sovereign.is_native_quote() {
+        ctx.accounts.sol_vault.lamports()
+    } else {
+        ctx.accounts.quote_vault.as_ref()
+            .ok_or(SovereignError::QuoteAccountsRequired)?
+            .amount
+    };
     if remaining == 0 && sovereign.creator_escrow == 0 && sovereign.creation_fee_escrowed == 0 && sovereign.token_supply_deposited == 0 {
         sovereign.state = SovereignStatus::Retired;
         emit!(SovereignRetired {
@@ -624,7 +671,7 @@ pub fn emergency_withdraw_creator_handler(ctx: Context<EmergencyWithdrawCreator>
 // EMERGENCY REMOVE LIQUIDITY
 // ============================================================
 
-/// Emergency remove liquidity from SAMM pool.
+/// Emergency remove liquidity from the sovereign's AMM pool.
 /// Only callable when sovereign is in EmergencyUnlocked state.
 /// This extracts all LP back into sol_vault and token_vault so that
 /// emergency_withdraw and emergency_withdraw_creator can distribute funds.
@@ -663,16 +710,16 @@ pub struct EmergencyRemoveLiquidity<'info> {
     )]
     pub token_mint: InterfaceAccount<'info, MintInterface>,
     
-    /// CHECK: SAMM position - MUST match permanent_lock.position_mint
+    /// CHECK: AMM position (SAMM NFT mint / CPMM LP mint) - MUST match permanent_lock.position_mint
     #[account(
         mut,
         constraint = position.key() == permanent_lock.position_mint @ SovereignError::InvalidPosition
     )]
     pub position: UncheckedAccount<'info>,
     
-    /// CHECK: Trashbin SAMM program
-    #[account(address = SAMM_PROGRAM_ID)]
-    pub samm_program: UncheckedAccount<'info>,
+    /// CHECK: The sovereign's AMM program
+    #[account(address = sovereign.amm_program @ SovereignError::InvalidAmmProgram)]
+    pub amm_program: UncheckedAccount<'info>,
     
     /// CHECK: SOL vault PDA
     #[account(
@@ -698,6 +745,27 @@ pub struct EmergencyRemoveLiquidity<'info> {
         address = protocol_state.treasury
     )]
     pub treasury: SystemAccount<'info>,
+    
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote proceeds (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Treasury's quote token account (receives token quote BYO surplus)
+    #[account(
+        mut,
+        constraint = treasury_quote_account.owner == protocol_state.treasury @ SovereignError::Unauthorized
+    )]
+    pub treasury_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn emergency_remove_liquidity_handler<'info>(
@@ -705,12 +773,22 @@ pub fn emergency_remove_liquidity_handler<'info>(
 ) -> Result<()> {
     let sovereign = &mut ctx.accounts.sovereign;
     let permanent_lock = &mut ctx.accounts.permanent_lock;
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
     
     // Guard against double-calling: permanent_lock.liquidity is set to 0 at the end
     require!(permanent_lock.liquidity > 0, SovereignError::NothingToWithdraw);
+    // LP ladder positions are drained first (unwind_ladder_position) into the
+    // lock's accounts, so the sweep below includes them
+    require!(permanent_lock.is_ladder_unwound(), SovereignError::LadderPositionsOutstanding);
     
-    // ============ Trashbin SAMM Liquidity Removal ============
-    // remaining_accounts layout:
+    // ============ AMM Liquidity Removal ============
+    // remaining_accounts: the lock position accounts - see amm::samm / amm::cpmm.
+    // SAMM layout:
     // [0]  nft_account
     // [1]  personal_position (writable)
     // [2]  pool_state (writable)
@@ -719,30 +797,31 @@ pub fn emergency_remove_liquidity_handler<'info>(
     // [5]  token_vault_1 (writable)
     // [6]  tick_array_lower (writable)
     // [7]  tick_array_upper (writable)
-    // [8]  recipient_token_account_0 / WGOR ATA (writable)
-    // [9]  recipient_token_account_1 / Token ATA (writable)
+    // [8]  recipient_token_account_0 (writable)
+    // [9]  recipient_token_account_1 (writable)
     // [10] token_program_2022
     // [11] memo_program
     // [12] vault_0_mint
     // [13] vault_1_mint
     // [14] tick_array_bitmap_extension
     
-    require!(ctx.remaining_accounts.len() >= 15, SovereignError::MissingSAMMAccounts);
+    let adapter = amm::adapter(sovereign.amm_kind);
+    let position_len = adapter.position_accounts_len();
+    require!(ctx.remaining_accounts.len() >= position_len, SovereignError::MissingSAMMAccounts);
+    let position_accounts = &ctx.remaining_accounts[..position_len];
+    let pool = adapter.pool_accounts(&sovereign.amm_program, position_accounts)?;
     
     // SECURITY: Validate pool_state matches the permanent_lock's stored pool_state
     require!(
-        ctx.remaining_accounts[2].key() == permanent_lock.pool_state,
+        pool.pool_state.key() == permanent_lock.pool_state,
         SovereignError::InvalidPool
     );
     
-    // Read actual liquidity from the SAMM personal position account
+    // Read actual liquidity from the AMM position
     // (may differ from permanent_lock.liquidity if LP was already removed by old program)
-    let pp_data = ctx.remaining_accounts[1].try_borrow_data()?;
-    let personal_pos = samm::PersonalPositionState::try_deserialize(&pp_data)?;
-    let actual_liquidity = personal_pos.liquidity;
-    drop(pp_data);
+    let actual_liquidity = adapter.position_liquidity(position_accounts)?;
     
-    msg!("Permanent lock liquidity: {}, SAMM position liquidity: {}", permanent_lock.liquidity, actual_liquidity);
+    msg!("Permanent lock liquidity: {}, AMM position liquidity: {}", permanent_lock.liquidity, actual_liquidity);
     
     let sovereign_key = sovereign.key();
     let lock_seeds = &[
@@ -752,44 +831,44 @@ pub fn emergency_remove_liquidity_handler<'info>(
     ];
     let lock_signer_seeds = &[&lock_seeds[..]];
     
-    let recipient_0_info = &ctx.remaining_accounts[8];
-    let recipient_1_info = &ctx.remaining_accounts[9];
+    // (WGOR (quote) ATA, Token ATA)
+    let (recipient_0_info, recipient_1_info) = pool.recipients(&sovereign.pool_quote_mint());
     
-    // ============ Step 1: Remove LP from SAMM (if not already removed) ============
+    // ============ Step 1: Remove LP from the AMM (if not already removed) ============
     if actual_liquidity > 0 {
-        msg!("Removing liquidity from SAMM pool...");
-        
-        let decrease_accounts = samm_ix::DecreaseLiquidityV2Accounts {
-            nft_owner: permanent_lock.to_account_info(),
-            nft_account: ctx.remaining_accounts[0].clone(),
-            personal_position: ctx.remaining_accounts[1].clone(),
-            pool_state: ctx.remaining_accounts[2].clone(),
-            protocol_position: ctx.remaining_accounts[3].clone(),
-            token_vault_0: ctx.remaining_accounts[4].clone(),
-            token_vault_1: ctx.remaining_accounts[5].clone(),
-            tick_array_lower: ctx.remaining_accounts[6].clone(),
-            tick_array_upper: ctx.remaining_accounts[7].clone(),
-            recipient_token_account_0: ctx.remaining_accounts[8].clone(),
-            recipient_token_account_1: ctx.remaining_accounts[9].clone(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            token_program_2022: ctx.remaining_accounts[10].clone(),
-            memo_program: ctx.remaining_accounts[11].clone(),
-            vault_0_mint: ctx.remaining_accounts[12].clone(),
-            vault_1_mint: ctx.remaining_accounts[13].clone(),
-            tick_array_bitmap_extension: ctx.remaining_accounts[14].clone(),
-        };
+        msg!("Removing liquidity from AMM pool...");
         
-        let _result = samm_cpi::remove_liquidity(
-            &ctx.accounts.samm_program.to_account_info(),
-            decrease_accounts,
+        // The AMM pays the tokens out with a transfer that would re-enter this program
+        let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+        let sovereign_seeds: &[&[u8]] = &[
+            SOVEREIGN_SEED,
+            &sovereign_id_bytes,
+            &[sovereign.bump],
+        ];
+        let hook_detached = detach_transfer_hook(
+            &ctx.accounts.token_mint.to_account_info(),
+            &sovereign.to_account_info(),
+            &[sovereign_seeds],
+        )?;
+        adapter.remove_liquidity(
+            &LockPosition {
+                program: ctx.accounts.amm_program.to_account_info(),
+                owner: permanent_lock.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                accounts: position_accounts,
+            },
             actual_liquidity,
-            0, // Accept any amount during emergency
-            0,
             lock_signer_seeds,
         )?;
-        msg!("LP removed from SAMM pool");
+        reattach_transfer_hook(
+            &ctx.accounts.token_mint.to_account_info(),
+            &sovereign.to_account_info(),
+            &[sovereign_seeds],
+            hook_detached,
+        )?;
+        msg!("LP removed from AMM pool");
     } else {
-        msg!("SAMM position already drained — skipping CPI, sweeping ATAs");
+        msg!("AMM position already drained — skipping CPI, sweeping ATAs");
     }
     
     // ============ Step 2: Read current WGOR ATA balance ============
@@ -799,34 +878,48 @@ pub fn emergency_remove_liquidity_handler<'info>(
     };
     msg!("WGOR ATA balance: {}", wgor_amount);
     
-    // ============ Step 3: Close WGOR ATA → all lamports go to sol_vault ============
-    // This unwraps WGOR to native SOL. Uses legacy SPL Token (WGOR is Token program).
-    // sol_vault PDA receives all lamports (token amount + rent).
-    let close_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id: anchor_spl::token::ID,
-        accounts: vec![
-            anchor_lang::solana_program::instruction::AccountMeta::new(
-                recipient_0_info.key(), false,
-            ),
-            anchor_lang::solana_program::instruction::AccountMeta::new(
-                ctx.accounts.sol_vault.key(), false,
-            ),
-            anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-                permanent_lock.key(), true,
-            ),
-        ],
-        data: vec![9u8], // SPL Token CloseAccount
-    };
-    invoke_signed(
-        &close_wgor_ix,
-        &[
-            recipient_0_info.clone(),
-            ctx.accounts.sol_vault.to_account_info(),
-            permanent_lock.to_account_info(),
-        ],
-        lock_signer_seeds,
-    )?;
-    msg!("WGOR ATA closed → {} WGOR unwrapped to sol_vault", wgor_amount);
+    // ============ Step 3: Move quote proceeds into the sovereign's vault ============
+    if let Some(quote) = quote.as_ref() {
+        // Token quote: transfer the quote ATA balance into the quote vault
+        if wgor_amount > 0 {
+            quote.sweep_into_vault(
+                recipient_0_info.clone(),
+                permanent_lock.to_account_info(),
+                lock_signer_seeds,
+                wgor_amount,
+            )?;
+        }
+        msg!("{} quote tokens moved to quote vault", wgor_amount);
+    } else {
+        // Native GOR: close WGOR ATA → all lamports go to sol_vault
+        // This unwraps WGOR to native SOL. Uses legacy SPL Token (WGOR is Token program).
+        // sol_vault PDA receives all lamports (token amount + rent).
+        let close_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: anchor_spl::token::ID,
+            accounts: vec![
+                anchor_lang::solana_program::instruction::AccountMeta::new(
+                    recipient_0_info.key(), false,
+                ),
+                anchor_lang::solana_program::instruction::AccountMeta::new(
+                    ctx.accounts.sol_vault.key(), false,
+                ),
+                anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
+                    permanent_lock.key(), true,
+                ),
+            ],
+            data: vec![9u8], // SPL Token CloseAccount
+        };
+        invoke_signed(
+            &close_wgor_ix,
+            &[
+                recipient_0_info.clone(),
+                ctx.accounts.sol_vault.to_account_info(),
+                permanent_lock.to_account_info(),
+            ],
+            lock_signer_seeds,
+        )?;
+        msg!("WGOR ATA closed → {} WGOR unwrapped to sol_vault", wgor_amount);
+    }
     
     // ============ Step 4: Update state ============
     // Set unwind_sol_balance to the WGOR token amount (= SOL from LP position).
@@ -871,15 +964,13 @@ pub fn emergency_remove_liquidity_handler<'info>(
             &[ctx.bumps.sol_vault],
         ];
         
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.treasury.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.treasury_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             surplus,
         )?;
         
@@ -960,6 +1051,24 @@ pub struct EmergencyTokenRedemption<'info> {
     )]
     pub caller_token_account: InterfaceAccount<'info, TokenAccountInterface>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Caller's quote token account (receives token quote payouts)
+    #[account(mut)]
+    pub caller_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
@@ -1022,9 +1131,15 @@ pub fn emergency_token_redemption_handler(
     )?;
     msg!("Burned {} sovereign tokens", caller_tokens);
     
-    // Transfer GOR from sol_vault to caller
+    // Transfer GOR (or token quote) from the vault to caller
     if gor_payout > 0 {
-        let vault_balance = ctx.accounts.sol_vault.lamports();
+        let quote = QuoteToken::resolve(
+            sovereign,
+            ctx.accounts.quote_mint.as_deref(),
+            ctx.accounts.quote_vault.as_deref(),
+            ctx.accounts.quote_token_program.as_ref(),
+        )?;
+        let vault_balance = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?;
         require!(
             vault_balance >= gor_payout,
             SovereignError::InsufficientVaultBalance
@@ -1037,15 +1152,13 @@ pub fn emergency_token_redemption_handler(
             &[ctx.bumps.sol_vault],
         ];
         
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.caller.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.caller.to_account_info(),
+            ctx.accounts.caller_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             gor_payout,
         )?;
         msg!("Transferred {} GOR to token holder", gor_payout);
@@ -1099,6 +1212,27 @@ pub struct SweepRedemptionPool<'info> {
     )]
     pub treasury: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote proceeds (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Treasury's quote token account (receives token quote unclaimed redemptions)
+    #[account(
+        mut,
+        constraint = treasury_quote_account.owner == protocol_state.treasury @ SovereignError::Unauthorized
+    )]
+    pub treasury_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -1119,8 +1253,14 @@ pub fn sweep_redemption_pool_handler(ctx: Context<SweepRedemptionPool>) -> Resul
     );
     
     let sweep_amount = sovereign.token_redemption_pool;
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
     
-    // Transfer unclaimed GOR from sol_vault → treasury
+    // Transfer unclaimed GOR (or token quote) from the vault → treasury
     let sovereign_key = sovereign.key();
     let vault_seeds: &[&[u8]] = &[
         SOL_VAULT_SEED,
@@ -1128,15 +1268,13 @@ pub fn sweep_redemption_pool_handler(ctx: Context<SweepRedemptionPool>) -> Resul
         &[ctx.bumps.sol_vault],
     ];
     
-    anchor_lang::system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.sol_vault.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-            &[vault_seeds],
-        ),
+    quote::pay_out(
+        quote.as_ref(),
+        &ctx.accounts.sol_vault.to_account_info(),
+        vault_seeds,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.treasury_quote_account.as_ref().map(|a| a.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
         sweep_amount,
     )?;
     
@@ -1,21 +1,45 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
+    Burn, CloseAccount, TransferChecked,
+};
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
-use crate::events::{BondingFailed, FailedWithdrawal, CreatorFailedWithdrawal};
+use crate::events::{
+    BondingFailed, FinalizationStalled, FailedWithdrawal, CreatorFailedWithdrawal,
+    PartialSuccessVoteCast, PartialSuccessAccepted, SovereignCancelled,
+};
 
-/// Mark bonding as failed if deadline passed without meeting target
+/// Mark bonding as failed if deadline passed without meeting the soft cap
+/// (or with too few investors to satisfy min_depositor_count)
+/// A missed raise that vote_partial_success could still accept waits out
+/// PARTIAL_SUCCESS_VOTE_PERIOD first; any other fails at the deadline.
 #[derive(Accounts)]
 pub struct MarkBondingFailed<'info> {
     #[account(mut)]
     pub caller: Signer<'info>,
     
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
     #[account(
         mut,
         seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
         bump = sovereign.bump
     )]
     pub sovereign: Account<'info, SovereignState>,
+    
+    /// Quote mint config - required when the sovereign bonds in a token quote mint
+    #[account(
+        seeds = [QUOTE_MINT_CONFIG_SEED, sovereign.quote_mint.as_ref()],
+        bump = quote_mint_config.bump
+    )]
+    pub quote_mint_config: Option<Box<Account<'info, QuoteMintConfig>>>,
 }
 
 pub fn mark_bonding_failed_handler(ctx: Context<MarkBondingFailed>) -> Result<()> {
@@ -34,13 +58,27 @@ pub fn mark_bonding_failed_handler(ctx: Context<MarkBondingFailed>) -> Result<()
         SovereignError::DeadlineNotPassed
     );
     
-    // Bond target not met - mark as failed
-    // Use < instead of != for safety
+    // Soft cap not met, or met by too few investors - mark as failed
+    // Raises between the soft and hard caps finalize instead of refunding
     require!(
-        sovereign.total_deposited < sovereign.bond_target,
+        !sovereign.is_soft_cap_met() || !sovereign.has_min_depositors(),
         SovereignError::BondTargetMet
     );
     
+    // A missed raise first gets a window for depositors to accept it as-is,
+    // unless the vote could never accept it
+    let min_bond_target = partial_raise_min_bond_target(
+        sovereign,
+        &ctx.accounts.protocol_state,
+        ctx.accounts.quote_mint_config.as_deref().map(|config| &**config),
+    )?;
+    if !sovereign.is_soft_cap_met() && sovereign.is_partial_raise_acceptable(min_bond_target) {
+        require!(
+            clock.unix_timestamp > sovereign.bond_deadline.saturating_add(PARTIAL_SUCCESS_VOTE_PERIOD),
+            SovereignError::PartialSuccessVoteActive
+        );
+    }
+    
     // Atomic state transition
     sovereign.state = SovereignStatus::Failed;
     
@@ -54,18 +92,259 @@ pub fn mark_bonding_failed_handler(ctx: Context<MarkBondingFailed>) -> Result<()
     Ok(())
 }
 
+/// Minimum bond target an accepted partial raise must still meet, in the
+/// sovereign's quote asset
+fn partial_raise_min_bond_target(
+    sovereign: &SovereignState,
+    protocol: &ProtocolState,
+    quote_mint_config: Option<&QuoteMintConfig>,
+) -> Result<u64> {
+    if sovereign.is_native_quote() {
+        Ok(protocol.min_bond_target)
+    } else {
+        Ok(quote_mint_config
+            .ok_or(SovereignError::InvalidQuoteMint)?
+            .min_bond_target)
+    }
+}
+
+/// Mark a stalled finalization as failed (anyone)
+/// A raise that met its target but was not finalized within FINALIZATION_TIMEOUT
+/// (from Bonding, Finalizing or PoolCreated) moves to Failed, so depositors
+/// refund via withdraw_failed and the creator via withdraw_creator_failed.
+/// Funds already in LP ladder positions come back through unwind_ladder_position
+/// and refund_ladder_proceeds; once the pool exists, refunds are pro-rata.
+#[derive(Accounts)]
+pub struct MarkFinalizationStalled<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+}
+
+pub fn mark_finalization_stalled_handler(ctx: Context<MarkFinalizationStalled>) -> Result<()> {
+    let sovereign = &mut ctx.accounts.sovereign;
+    let clock = Clock::get()?;
+    
+    // Only raises that could be finalized - missed raises go through mark_bonding_failed
+    let finalizable = match sovereign.state {
+        SovereignStatus::Finalizing | SovereignStatus::PoolCreated => true,
+        SovereignStatus::Bonding => {
+            sovereign.is_deadline_passed(clock.unix_timestamp)
+                && sovereign.is_soft_cap_met()
+                && sovereign.has_min_depositors()
+        }
+        _ => false,
+    };
+    require!(
+        finalizable && sovereign.is_finalization_stalled(clock.unix_timestamp),
+        SovereignError::FinalizationNotStalled
+    );
+    
+    let pool_created = sovereign.state == SovereignStatus::PoolCreated;
+    
+    // Atomic state transition
+    sovereign.state = SovereignStatus::Failed;
+    
+    emit!(FinalizationStalled {
+        sovereign_id: sovereign.sovereign_id,
+        total_deposited: sovereign.total_deposited,
+        finalization_started_at: sovereign.finalization_started_at(),
+        pool_created,
+        marked_by: ctx.accounts.caller.key(),
+        failed_at: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Vote to accept a missed raise as-is (investor)
+/// Weighted by DepositRecord.amount. Once approvals reach a supermajority of
+/// total_deposited, the sovereign moves to Finalizing with bond_target reset
+/// to total_deposited. Otherwise mark_bonding_failed applies after the window.
+/// Only raises that still meet the minimum bond target can be accepted, and
+/// creator escrow above 1% of the accepted raise is refunded to the creator.
+#[derive(Accounts)]
+pub struct VotePartialSuccess<'info> {
+    pub depositor: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+    
+    /// Quote mint config - required when the sovereign bonds in a token quote mint
+    #[account(
+        seeds = [QUOTE_MINT_CONFIG_SEED, sovereign.quote_mint.as_ref()],
+        bump = quote_mint_config.bump
+    )]
+    pub quote_mint_config: Option<Box<Account<'info, QuoteMintConfig>>>,
+    
+    /// Creator - receives any escrow above the accepted raise's 1% cap
+    #[account(
+        mut,
+        address = sovereign.creator @ SovereignError::NotCreator
+    )]
+    pub creator: SystemAccount<'info>,
+    
+    /// CHECK: SOL vault
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        mut,
+        seeds = [DEPOSIT_RECORD_SEED, sovereign.key().as_ref(), depositor.key().as_ref()],
+        bump = deposit_record.bump,
+        constraint = deposit_record.depositor == depositor.key() @ SovereignError::Unauthorized
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+}
+
+pub fn vote_partial_success_handler(ctx: Context<VotePartialSuccess>) -> Result<()> {
+    let sovereign = &mut ctx.accounts.sovereign;
+    let deposit_record = &mut ctx.accounts.deposit_record;
+    let clock = Clock::get()?;
+    
+    // Vote is open after the deadline, while still Bonding with the raise missed
+    require!(
+        sovereign.state == SovereignStatus::Bonding,
+        SovereignError::InvalidState
+    );
+    require!(
+        sovereign.is_deadline_passed(clock.unix_timestamp)
+            && clock.unix_timestamp <= sovereign.bond_deadline.saturating_add(PARTIAL_SUCCESS_VOTE_PERIOD),
+        SovereignError::PartialSuccessVoteNotOpen
+    );
+    require!(
+        !sovereign.is_soft_cap_met(),
+        SovereignError::PartialSuccessVoteNotOpen
+    );
+    
+    // The accepted raise becomes the bond target, so it must meet the minimum
+    let min_bond_target = partial_raise_min_bond_target(
+        sovereign,
+        &ctx.accounts.protocol_state,
+        ctx.accounts.quote_mint_config.as_deref().map(|config| &**config),
+    )?;
+    require!(
+        sovereign.total_deposited >= min_bond_target,
+        SovereignError::PartialRaiseBelowMinimum
+    );
+    require!(deposit_record.amount > 0, SovereignError::NoDeposits);
+    require!(
+        !deposit_record.partial_success_voted,
+        SovereignError::PartialSuccessAlreadyVoted
+    );
+    
+    deposit_record.partial_success_voted = true;
+    sovereign.partial_success_votes = sovereign.partial_success_votes
+        .checked_add(deposit_record.amount)
+        .ok_or(SovereignError::Overflow)?;
+    
+    emit!(PartialSuccessVoteCast {
+        sovereign_id: sovereign.sovereign_id,
+        voter: ctx.accounts.depositor.key(),
+        weight: deposit_record.amount,
+        total_votes: sovereign.partial_success_votes,
+        total_deposited: sovereign.total_deposited,
+    });
+    
+    // Supermajority reached → accept the lower raise
+    let votes_bps = (sovereign.partial_success_votes as u128)
+        .checked_mul(BPS_DENOMINATOR as u128)
+        .ok_or(SovereignError::Overflow)?
+        .checked_div(sovereign.total_deposited as u128)
+        .ok_or(SovereignError::DivisionByZero)?;
+    
+    if votes_bps >= PARTIAL_SUCCESS_THRESHOLD_BPS as u128 && sovereign.has_min_depositors() {
+        let original_bond_target = sovereign.bond_target;
+        sovereign.bond_target = sovereign.total_deposited;
+        sovereign.soft_cap = sovereign.total_deposited;
+        sovereign.state = SovereignStatus::Finalizing;
+        sovereign.finalizing_at = clock.unix_timestamp;
+        
+        // Creator escrow is capped at 1% of the (now lower) bond target
+        let escrow_refunded = sovereign.creator_escrow
+            .saturating_sub(sovereign.max_creator_buy_in());
+        if escrow_refunded > 0 {
+            let sovereign_key = sovereign.key();
+            let vault_seeds: &[&[u8]] = &[
+                SOL_VAULT_SEED,
+                sovereign_key.as_ref(),
+                &[ctx.bumps.sol_vault],
+            ];
+            
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.sol_vault.to_account_info(),
+                        to: ctx.accounts.creator.to_account_info(),
+                    },
+                    &[vault_seeds],
+                ),
+                escrow_refunded,
+            )?;
+            
+            sovereign.creator_escrow = sovereign.creator_escrow
+                .checked_sub(escrow_refunded)
+                .ok_or(SovereignError::Overflow)?;
+        }
+        
+        emit!(PartialSuccessAccepted {
+            sovereign_id: sovereign.sovereign_id,
+            original_bond_target,
+            total_deposited: sovereign.total_deposited,
+            accepted_at: clock.unix_timestamp,
+            creator_escrow_refunded: escrow_refunded,
+        });
+    }
+    
+    Ok(())
+}
+
 /// Withdraw from a failed bonding (investor)
+/// Refunds the deposit in full, or a pro-rata share of the vault when
+/// finalization stalled after the pool was created (see failed_refund_amount).
 #[derive(Accounts)]
 pub struct WithdrawFailed<'info> {
     #[account(mut)]
     pub depositor: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
         bump = sovereign.bump
     )]
     pub sovereign: Account<'info, SovereignState>,
     
+    /// CHECK: PDA verified by seeds. Only exists once an LP ladder rung was
+    /// opened; its rungs must be back in the vaults before pro-rata refunds.
+    #[account(
+        seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub permanent_lock: UncheckedAccount<'info>,
+    
     #[account(
         mut,
         close = depositor,
@@ -83,11 +362,29 @@ pub struct WithdrawFailed<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Depositor's quote token account (receives token quote refunds)
+    #[account(mut)]
+    pub depositor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw_failed_handler(ctx: Context<WithdrawFailed>) -> Result<()> {
-    let sovereign = &ctx.accounts.sovereign;
+    let sovereign = &mut ctx.accounts.sovereign;
     let deposit_record = &ctx.accounts.deposit_record;
     
     // Validate state
@@ -105,14 +402,34 @@ pub fn withdraw_failed_handler(ctx: Context<WithdrawFailed>) -> Result<()> {
     let amount = deposit_record.amount;
     require!(amount > 0, SovereignError::NothingToWithdraw);
     
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    
+    // LP ladder rungs must be back in the vault before it is split
+    let lock_info = &ctx.accounts.permanent_lock;
+    if !lock_info.data_is_empty() {
+        let data = lock_info.try_borrow_data()?;
+        let permanent_lock = PermanentLock::try_deserialize(&mut &data[..])?;
+        require!(
+            permanent_lock.is_ladder_refunded(),
+            SovereignError::LadderPositionsOutstanding
+        );
+    }
+    
     // Verify vault has sufficient balance
-    let vault_balance = ctx.accounts.sol_vault.lamports();
+    let vault_balance = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?;
+    let refund = sovereign.failed_refund_amount(amount, vault_balance)
+        .ok_or(SovereignError::Overflow)?;
     require!(
-        vault_balance >= amount,
+        vault_balance >= refund,
         SovereignError::InsufficientVaultBalance
     );
     
-    // Transfer SOL from vault to depositor using System Program CPI
+    // Transfer quote from vault to depositor with the sol_vault PDA signer
     let sovereign_key = sovereign.key();
     let vault_seeds: &[&[u8]] = &[
         SOL_VAULT_SEED,
@@ -120,22 +437,25 @@ pub fn withdraw_failed_handler(ctx: Context<WithdrawFailed>) -> Result<()> {
         &[ctx.bumps.sol_vault],
     ];
     
-    anchor_lang::system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.sol_vault.to_account_info(),
-                to: ctx.accounts.depositor.to_account_info(),
-            },
-            &[vault_seeds],
-        ),
-        amount,
+    quote::pay_out(
+        quote.as_ref(),
+        &ctx.accounts.sol_vault.to_account_info(),
+        vault_seeds,
+        &ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.depositor_quote_account.as_ref().map(|a| a.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
+        refund,
     )?;
     
+    // Remaining deposits are the base for later pro-rata refunds
+    sovereign.total_deposited = sovereign.total_deposited
+        .checked_sub(amount)
+        .ok_or(SovereignError::Underflow)?;
+    
     emit!(FailedWithdrawal {
         sovereign_id: sovereign.sovereign_id,
         depositor: ctx.accounts.depositor.key(),
-        amount,
+        amount: refund,
     });
     
     // Note: deposit_record is closed and rent returned to depositor
@@ -235,3 +555,207 @@ pub fn withdraw_creator_failed_handler(ctx: Context<WithdrawCreatorFailed>) -> R
     
     Ok(())
 }
+
+// ============================================================
+// CANCEL SOVEREIGN
+// ============================================================
+
+/// Cancel a sovereign before anyone has deposited (creator only)
+/// - BYO tokens in token_vault are returned to the creator
+/// - TokenLaunch supply in token_vault (if created) is burned
+/// - Creation fee escrow is refunded minus protocol.cancellation_fee_lamports
+/// - creator_tracker and creation_fee_escrow are closed for rent
+/// - Sovereign is marked Retired
+///
+/// token_mint, token_vault, creator_token_account and token_program are only
+/// required once a token vault exists (always for BYO, after create_token for TokenLaunch).
+#[derive(Accounts)]
+pub struct CancelSovereign<'info> {
+    #[account(
+        mut,
+        address = sovereign.creator @ SovereignError::NotCreator
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+    
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [CREATOR_TRACKER_SEED, sovereign.key().as_ref()],
+        bump = creator_tracker.bump
+    )]
+    pub creator_tracker: Box<Account<'info, CreatorFeeTracker>>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [CREATION_FEE_ESCROW_SEED, sovereign.key().as_ref()],
+        bump = creation_fee_escrow.bump
+    )]
+    pub creation_fee_escrow: Box<Account<'info, CreationFeeEscrow>>,
+    
+    /// CHECK: Treasury receives the cancellation fee
+    #[account(
+        mut,
+        address = protocol_state.treasury @ SovereignError::InvalidTreasury
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// Required (with token_vault and token_program) once the sovereign has a token mint
+    #[account(
+        mut,
+        address = sovereign.token_mint
+    )]
+    pub token_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Creator's token account - receives BYO tokens back
+    /// Required when the vault still holds BYO tokens
+    #[account(
+        mut,
+        token::mint = sovereign.token_mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn cancel_sovereign_handler(ctx: Context<CancelSovereign>) -> Result<()> {
+    let clock = Clock::get()?;
+    let sovereign = &mut ctx.accounts.sovereign;
+    
+    // Only before any deposits
+    require!(
+        sovereign.state == SovereignStatus::Bonding,
+        SovereignError::InvalidState
+    );
+    require!(
+        sovereign.total_deposited == 0 && sovereign.creator_escrow == 0,
+        SovereignError::CannotCancelWithDeposits
+    );
+    
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds = &[
+        SOVEREIGN_SEED,
+        &sovereign_id_bytes[..],
+        &[sovereign.bump],
+    ];
+    let sovereign_signer = &[&sovereign_seeds[..]];
+    
+    // ---- Return or burn the token vault ----
+    // The vault exists whenever the mint is set, and must be emptied and closed
+    // here: nothing can reach its tokens once the sovereign is retired
+    let mut tokens_returned = 0u64;
+    if sovereign.token_mint != Pubkey::default() {
+        let token_vault = ctx.accounts.token_vault.as_ref()
+            .ok_or(SovereignError::MissingCancelTokenAccounts)?;
+        let token_mint = ctx.accounts.token_mint.as_ref()
+            .ok_or(SovereignError::MissingCancelTokenAccounts)?;
+        let token_program = ctx.accounts.token_program.as_ref()
+            .ok_or(SovereignError::MissingCancelTokenAccounts)?;
+        let vault_amount = token_vault.amount;
+        
+        if vault_amount > 0 {
+            if sovereign.sovereign_type == SovereignType::BYOToken {
+                let creator_token_account = ctx.accounts.creator_token_account.as_ref()
+                    .ok_or(SovereignError::MissingCancelTokenAccounts)?;
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        TransferChecked {
+                            from: token_vault.to_account_info(),
+                            mint: token_mint.to_account_info(),
+                            to: creator_token_account.to_account_info(),
+                            authority: sovereign.to_account_info(),
+                        },
+                        sovereign_signer,
+                    ),
+                    vault_amount,
+                    token_mint.decimals,
+                )?;
+                tokens_returned = vault_amount;
+            } else {
+                // TokenLaunch supply was minted for this sovereign only
+                token_interface::burn(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Burn {
+                            mint: token_mint.to_account_info(),
+                            from: token_vault.to_account_info(),
+                            authority: sovereign.to_account_info(),
+                        },
+                        sovereign_signer,
+                    ),
+                    vault_amount,
+                )?;
+            }
+        }
+        
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: token_vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: sovereign.to_account_info(),
+            },
+            sovereign_signer,
+        ))?;
+    }
+    
+    // ---- Creation fee: keep the cancellation fee, refund the rest on close ----
+    let escrow_amount = ctx.accounts.creation_fee_escrow.amount;
+    let cancellation_fee = std::cmp::min(
+        ctx.accounts.protocol_state.cancellation_fee_lamports,
+        escrow_amount,
+    );
+    if cancellation_fee > 0 {
+        ctx.accounts.creation_fee_escrow.sub_lamports(cancellation_fee)?;
+        ctx.accounts.treasury.add_lamports(cancellation_fee)?;
+        
+        let protocol = &mut ctx.accounts.protocol_state;
+        protocol.total_fees_collected = protocol.total_fees_collected
+            .checked_add(cancellation_fee)
+            .ok_or(SovereignError::Overflow)?;
+    }
+    let creation_fee_refunded = escrow_amount - cancellation_fee;
+    
+    let escrow = &mut ctx.accounts.creation_fee_escrow;
+    escrow.amount = 0;
+    escrow.released = true;
+    
+    sovereign.creation_fee_escrowed = 0;
+    sovereign.state = SovereignStatus::Retired;
+    
+    emit!(SovereignCancelled {
+        sovereign_id: sovereign.sovereign_id,
+        creator: ctx.accounts.creator.key(),
+        creation_fee_refunded,
+        cancellation_fee,
+        tokens_returned,
+        cancelled_at: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -1,19 +1,163 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, SyncNative, Approve};
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, SyncNative};
 use anchor_spl::token_interface::{
+    self,
+    Approve as ApproveInterface,
     Mint as MintInterface,
     TokenAccount as TokenAccountInterface,
     TokenInterface,
     transfer_checked,
     TransferChecked,
 };
-use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
 use crate::events::*;
-use crate::samm::{self, instructions as samm_ix, cpi as samm_cpi};
+use crate::samm::{instructions as samm_ix, cpi as samm_cpi, tick_math, PoolState, SammAccountDeserialize};
+use super::transfer_hook::{detach_transfer_hook, reattach_transfer_hook};
+
+/// Create the permanent lock's quote and sovereign-token ATAs if they don't exist
+/// yet, so any cranker can run (or retry) the liquidity steps without setup.
+/// The ATA program also rejects accounts that are not the canonical ATAs.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_lock_token_accounts<'info>(
+    payer: &AccountInfo<'info>,
+    permanent_lock: &AccountInfo<'info>,
+    quote_mint: &AccountInfo<'info>,
+    lock_quote_account: &AccountInfo<'info>,
+    quote_token_program: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    lock_token_account: &AccountInfo<'info>,
+    token_program_2022: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    for (mint, account, token_program) in [
+        (quote_mint, lock_quote_account, quote_token_program),
+        (token_mint, lock_token_account, token_program_2022),
+    ] {
+        associated_token::create_idempotent(CpiContext::new(
+            associated_token_program.clone(),
+            associated_token::Create {
+                payer: payer.clone(),
+                associated_token: account.clone(),
+                authority: permanent_lock.clone(),
+                mint: mint.clone(),
+                system_program: system_program.clone(),
+                token_program: token_program.clone(),
+            },
+        ))?;
+    }
+    Ok(())
+}
+
+/// Deviation (bps) of an existing pool's price from the price implied by the raise.
+/// Must stay within MAX_EXISTING_POOL_PRICE_DEVIATION_BPS, so liquidity never goes
+/// into a pool pushed away from the bonding price.
+pub(crate) fn check_existing_pool_price(
+    pool_sqrt_price_x64: u128,
+    implied_sqrt_price_x64: u128,
+) -> Result<u16> {
+    let deviation_bps = tick_math::price_deviation_bps(pool_sqrt_price_x64, implied_sqrt_price_x64)
+        .filter(|deviation| *deviation <= MAX_EXISTING_POOL_PRICE_DEVIATION_BPS as u128)
+        .ok_or(SovereignError::ExistingPoolPriceDeviation)?;
+    Ok(deviation_bps as u16)
+}
+
+/// Loads a SAMM pool the sovereign adds liquidity to instead of creating one.
+/// It must pair the token with the quote mint under the sovereign's AMM config
+/// and the tick spacing the locked position uses, and still accept new positions.
+pub(crate) fn load_existing_pool(pool_info: &AccountInfo, sovereign: &SovereignState) -> Result<PoolState> {
+    require!(
+        *pool_info.owner == SAMM_PROGRAM_ID,
+        SovereignError::InvalidExistingPool
+    );
+    let pool = {
+        let data = pool_info.try_borrow_data()?;
+        PoolState::try_deserialize(&data)
+            .map_err(|_| SovereignError::InvalidExistingPool)?
+    };
+    let (mint_0, mint_1, _) = samm_cpi::sort_mints(&sovereign.pool_quote_mint(), &sovereign.token_mint);
+    require!(
+        pool.token_mint_0 == mint_0
+            && pool.token_mint_1 == mint_1
+            && pool.amm_config == sovereign.amm_config
+            && pool.tick_spacing as i32 == crate::samm::tick::DEFAULT_TICK_SPACING,
+        SovereignError::InvalidExistingPool
+    );
+    require!(
+        pool.can_open_position(),
+        SovereignError::ExistingPoolClosedToLiquidity
+    );
+    Ok(pool)
+}
+
+/// Checks that the sovereign can create its pool, moving it from Bonding to
+/// Finalizing when the soft cap was met by the deadline
+pub(crate) fn begin_pool_creation(sovereign: &mut SovereignState, now: i64) -> Result<()> {
+    // Hard cap hit → already Finalizing.
+    // Soft cap met by the deadline → finalize straight from Bonding.
+    if sovereign.state == SovereignStatus::Bonding {
+        require!(
+            sovereign.is_deadline_passed(now),
+            SovereignError::DeadlineNotPassed
+        );
+        require!(sovereign.is_soft_cap_met(), SovereignError::SoftCapNotMet);
+        require!(
+            sovereign.has_min_depositors(),
+            SovereignError::MinDepositorsNotMet
+        );
+        sovereign.state = SovereignStatus::Finalizing;
+        sovereign.finalizing_at = now;
+    }
+
+    require!(
+        sovereign.state == SovereignStatus::Finalizing,
+        SovereignError::InvalidState
+    );
+    require!(sovereign.is_soft_cap_met(), SovereignError::BondTargetNotMet);
+    require!(
+        !sovereign.is_exit_window_open(now),
+        SovereignError::ExitWindowActive
+    );
+    require!(sovereign.total_deposited > 0, SovereignError::NoDeposits);
+    require!(
+        sovereign.pool_state == Pubkey::default(),
+        SovereignError::PoolAlreadyCreated
+    );
+    Ok(())
+}
+
+/// Minimum out for the creator market buy: constant-product output at the opening
+/// reserves, net of the pool swap fee and the Token-2022 transfer fee, minus
+/// MAX_SLIPPAGE_BPS
+pub(crate) fn creator_buy_min_out(
+    sovereign: &SovereignState,
+    token_reserve: u64,
+    quote_reserve: u64,
+    creator_escrow: u64,
+) -> Result<u64> {
+    let bps = BPS_DENOMINATOR as u128;
+    let expected_tokens = (token_reserve as u128)
+        .checked_mul(creator_escrow as u128)
+        .ok_or(SovereignError::Overflow)?
+        .checked_div(
+            (quote_reserve as u128)
+                .checked_add(creator_escrow as u128)
+                .ok_or(SovereignError::Overflow)?,
+        )
+        .ok_or(SovereignError::DivisionByZero)?;
+    Ok(expected_tokens
+        .checked_mul(bps.saturating_sub(sovereign.swap_fee_bps as u128)).ok_or(SovereignError::Overflow)?
+        .checked_div(bps).ok_or(SovereignError::DivisionByZero)?
+        .checked_mul(bps - sovereign.sell_fee_bps as u128).ok_or(SovereignError::Overflow)?
+        .checked_div(bps).ok_or(SovereignError::DivisionByZero)?
+        .checked_mul(bps - MAX_SLIPPAGE_BPS as u128).ok_or(SovereignError::Overflow)?
+        .checked_div(bps).ok_or(SovereignError::DivisionByZero)? as u64)
+}
 
 // ============================================================
 // STEP 1: CREATE POOL
@@ -23,10 +167,15 @@ use crate::samm::{self, instructions as samm_ix, cpi as samm_cpi};
 /// This is step 1 of the two-step finalization process.
 ///
 /// Prerequisites:
-/// - Sovereign must be in `Finalizing` state (bond target met)
+/// - Sovereign must be in `Finalizing` state (hard cap met), or in `Bonding`
+///   past the deadline with the soft cap met
 ///
 /// After success:
-/// - Pool is created on SAMM with initial price
+/// - Pool is created on SAMM with initial price, or for a BYO sovereign with an
+///   existing pool, that pool is validated (mints, open to positions, price within
+///   MAX_EXISTING_POOL_PRICE_DEVIATION_BPS of the raise) and linked instead
+/// - A pool someone already created at the derived address is validated the same
+///   way and adopted as an existing pool
 /// - Sovereign transitions to `PoolCreated` state
 /// - Pool state address stored on sovereign
 #[derive(Accounts)]
@@ -51,9 +200,8 @@ pub struct FinalizeCreatePool<'info> {
     #[account(address = sovereign.token_mint)]
     pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
-    /// WGOR native mint
-    /// CHECK: Validated against constant
-    #[account(address = WGOR_MINT)]
+    /// Quote side of the pool: WGOR native mint, or the sovereign's token quote mint
+    #[account(address = sovereign.pool_quote_mint() @ SovereignError::InvalidQuoteMint)]
     pub wgor_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
     /// SOL vault holding deposits (used to calculate initial price)
@@ -63,6 +211,13 @@ pub struct FinalizeCreatePool<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
 
+    /// Quote vault holding token quote deposits (used instead of sol_vault for the price)
+    #[account(
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
     /// Token vault holding sovereign tokens (used to calculate initial price)
     #[account(
         seeds = [TOKEN_VAULT_SEED, sovereign.key().as_ref()],
@@ -81,6 +236,7 @@ pub struct FinalizeCreatePool<'info> {
     pub amm_config: UncheckedAccount<'info>,
 
     /// CHECK: Pool state PDA - derived from ["pool", amm_config, token_mint_0, token_mint_1]
+    /// (sovereign.existing_pool when adding liquidity to an existing pool)
     #[account(mut)]
     pub pool_state: UncheckedAccount<'info>,
 
@@ -100,10 +256,21 @@ pub struct FinalizeCreatePool<'info> {
     #[account(mut)]
     pub tick_array_bitmap: UncheckedAccount<'info>,
 
+    /// Pool vault registry used by the transfer hook to classify buys/sells.
+    /// Seeded with the pool's sovereign-token vault.
+    #[account(
+        init,
+        payer = payer,
+        space = PoolVaultRegistry::LEN,
+        seeds = [POOL_VAULT_REGISTRY_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub pool_vault_registry: Box<Account<'info, PoolVaultRegistry>>,
+
     /// Token program for WGOR (legacy SPL Token)
     pub token_program: Program<'info, Token>,
 
-    /// Token program for sovereign token (Token-2022)
+    /// Token program for sovereign token (Token-2022) and Token-2022 quote mints
     pub token_program_2022: Interface<'info, TokenInterface>,
 
     pub system_program: Program<'info, System>,
@@ -117,19 +284,10 @@ pub fn finalize_create_pool_handler(ctx: Context<FinalizeCreatePool>) -> Result<
 
     // ---- Validations ----
     require!(!protocol.paused, SovereignError::ProtocolPaused);
-    require!(
-        sovereign.state == SovereignStatus::Finalizing,
-        SovereignError::InvalidState
-    );
-    require!(
-        sovereign.total_deposited >= sovereign.bond_target,
-        SovereignError::BondTargetNotMet
-    );
-    require!(sovereign.total_deposited > 0, SovereignError::NoDeposits);
-    require!(
-        sovereign.pool_state == Pubkey::default(),
-        SovereignError::PoolAlreadyCreated
-    );
+    // CPMM sovereigns finalize through finalize_cpmm_pool
+    require!(sovereign.amm_kind == AmmKind::Samm, SovereignError::UnsupportedByAmm);
+
+    begin_pool_creation(sovereign, clock.unix_timestamp)?;
 
     // ---- Determine token ordering ----
     // Canonical order: lower pubkey = token_0, higher pubkey = token_1
@@ -151,7 +309,18 @@ pub fn finalize_create_pool_handler(ctx: Context<FinalizeCreatePool>) -> Result<
     // For CLMM: price = token_1_amount / token_0_amount
     // If WGOR is token_0: price = sovereign_tokens / wgor_amount
     // If WGOR is token_1: price = wgor_amount / sovereign_tokens
-    let sol_amount = ctx.accounts.sol_vault.lamports();
+    // (the same holds for a token quote mint in place of WGOR)
+    // The creator escrow is reserved for the post-LP market buy, not LP
+    let quote_balance = if sovereign.is_native_quote() {
+        ctx.accounts.sol_vault.lamports()
+    } else {
+        ctx.accounts.quote_vault.as_ref()
+            .ok_or(SovereignError::QuoteAccountsRequired)?
+            .amount
+    };
+    let sol_amount = quote_balance
+        .checked_sub(sovereign.creator_escrow)
+        .ok_or(SovereignError::Underflow)?;
     let token_amount = ctx.accounts.token_vault.amount;
 
     // Calculate LP allocation
@@ -178,20 +347,25 @@ pub fn finalize_create_pool_handler(ctx: Context<FinalizeCreatePool>) -> Result<
         lp_tokens
     };
 
-    let price = if wgor_is_0 {
-        // price = token_1_per_token_0 = tokens / sol
-        tokens_for_price as f64 / sol_amount as f64
+    // price = token_1_per_token_0, as an exact Q64.64 sqrt price
+    let sqrt_price_x64 = if wgor_is_0 {
+        // price = tokens / sol
+        tick_math::sqrt_price_x64_from_amounts(sol_amount, tokens_for_price)?
     } else {
-        // price = token_1_per_token_0 = sol / tokens
-        sol_amount as f64 / tokens_for_price as f64
+        // price = sol / tokens
+        tick_math::sqrt_price_x64_from_amounts(tokens_for_price, sol_amount)?
     };
 
-    let sqrt_price_x64 = samm_cpi::price_to_sqrt_price_x64(price);
-
     // ---- Determine token programs for each side ----
+    // WGOR = legacy SPL; a token quote mint may be either program
+    let quote_token_program = if *ctx.accounts.wgor_mint.to_account_info().owner == ctx.accounts.token_program_2022.key() {
+        ctx.accounts.token_program_2022.to_account_info()
+    } else {
+        ctx.accounts.token_program.to_account_info()
+    };
     let (token_program_0, token_program_1, mint_0_info, mint_1_info) = if wgor_is_0 {
         (
-            ctx.accounts.token_program.to_account_info(),    // WGOR = legacy SPL
+            quote_token_program,                                // quote side
             ctx.accounts.token_program_2022.to_account_info(), // sovereign = Token-2022
             ctx.accounts.wgor_mint.to_account_info(),
             ctx.accounts.token_mint.to_account_info(),
@@ -199,64 +373,163 @@ pub fn finalize_create_pool_handler(ctx: Context<FinalizeCreatePool>) -> Result<
     } else {
         (
             ctx.accounts.token_program_2022.to_account_info(), // sovereign = Token-2022
-            ctx.accounts.token_program.to_account_info(),      // WGOR = legacy SPL
+            quote_token_program,                                // quote side
             ctx.accounts.token_mint.to_account_info(),
             ctx.accounts.wgor_mint.to_account_info(),
         )
     };
 
-    // ---- CPI: Create Pool ----
-    let create_pool_accounts = samm_ix::CreatePoolAccounts {
-        pool_creator: ctx.accounts.payer.to_account_info(),
-        amm_config: ctx.accounts.amm_config.to_account_info(),
-        pool_state: ctx.accounts.pool_state.to_account_info(),
-        token_mint_0: mint_0_info,
-        token_mint_1: mint_1_info,
-        token_vault_0: ctx.accounts.samm_token_vault_0.to_account_info(),
-        token_vault_1: ctx.accounts.samm_token_vault_1.to_account_info(),
-        observation_state: ctx.accounts.observation_state.to_account_info(),
-        tick_array_bitmap: ctx.accounts.tick_array_bitmap.to_account_info(),
-        token_program_0,
-        token_program_1,
-        system_program: ctx.accounts.system_program.to_account_info(),
-        rent: ctx.accounts.rent.to_account_info(),
+    // ---- Trading open time ----
+    // open_time in the past = pool immediately tradeable.
+    // A launch guard can delay trading to give the anti-sniper window a clean start;
+    // the window is counted from the same timestamp.
+    let trading_opens_at = clock.unix_timestamp
+        .checked_add(sovereign.launch_guard.open_delay)
+        .ok_or(SovereignError::Overflow)?;
+    let open_time = if sovereign.launch_guard.open_delay > 0 {
+        trading_opens_at as u64
+    } else {
+        1u64
     };
 
-    #[cfg(not(any(feature = "localnet", feature = "devnet")))]
-    {
-        samm_cpi::create_pool(
-            &ctx.accounts.samm_program.to_account_info(),
-            create_pool_accounts,
-            sqrt_price_x64,
-            1u64, // open_time in the past = pool immediately tradeable
-            &[], // payer signs naturally, no PDA seeds needed
-        )?;
+    // ---- Pool already at the derived address ----
+    // Anyone can create the SAMM pool for this pair and config before we do, and
+    // create_pool would then revert on every retry. Such a pool is adopted as an
+    // existing pool: it goes through the same checks and is never restricted.
+    if !sovereign.uses_existing_pool() && *ctx.accounts.pool_state.owner == SAMM_PROGRAM_ID {
+        let (expected_pool, _) = Pubkey::find_program_address(
+            &[
+                SAMM_POOL_SEED,
+                sovereign.amm_config.as_ref(),
+                mint_0.as_ref(),
+                mint_1.as_ref(),
+            ],
+            &SAMM_PROGRAM_ID,
+        );
+        require!(
+            ctx.accounts.pool_state.key() == expected_pool,
+            SovereignError::InvalidPool
+        );
+        msg!("Pool already exists, adopting: {}", expected_pool);
+        sovereign.existing_pool = expected_pool;
     }
 
-    #[cfg(any(feature = "localnet", feature = "devnet"))]
-    {
-        msg!("DEVNET: Skipping SAMM create_pool CPI (test mode)");
-        // Suppress unused variable warnings in test mode
-        let _ = create_pool_accounts;
-        let _ = sqrt_price_x64;
+    if sovereign.uses_existing_pool() {
+        // ---- Existing pool: validate and link instead of creating ----
+        require!(
+            ctx.accounts.pool_state.key() == sovereign.existing_pool,
+            SovereignError::InvalidPool
+        );
+        let pool = load_existing_pool(&ctx.accounts.pool_state, sovereign)?;
+        require!(
+            pool.token_vault_0 == ctx.accounts.samm_token_vault_0.key()
+                && pool.token_vault_1 == ctx.accounts.samm_token_vault_1.key(),
+            SovereignError::InvalidPoolVault
+        );
+        let deviation_bps = check_existing_pool_price(pool.sqrt_price_x64, sqrt_price_x64)?;
+
+        emit!(ExistingPoolLinked {
+            sovereign_id: sovereign.sovereign_id,
+            pool_state: ctx.accounts.pool_state.key(),
+            pool_sqrt_price_x64: pool.sqrt_price_x64,
+            implied_sqrt_price_x64: sqrt_price_x64,
+            deviation_bps,
+            linked_at: clock.unix_timestamp,
+        });
+    } else {
+        // ---- CPI: Create Pool ----
+        let create_pool_accounts = samm_ix::CreatePoolAccounts {
+            pool_creator: ctx.accounts.payer.to_account_info(),
+            amm_config: ctx.accounts.amm_config.to_account_info(),
+            pool_state: ctx.accounts.pool_state.to_account_info(),
+            token_mint_0: mint_0_info,
+            token_mint_1: mint_1_info,
+            token_vault_0: ctx.accounts.samm_token_vault_0.to_account_info(),
+            token_vault_1: ctx.accounts.samm_token_vault_1.to_account_info(),
+            observation_state: ctx.accounts.observation_state.to_account_info(),
+            tick_array_bitmap: ctx.accounts.tick_array_bitmap.to_account_info(),
+            token_program_0,
+            token_program_1,
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        };
+
+        #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+        {
+            samm_cpi::create_pool(
+                &ctx.accounts.samm_program.to_account_info(),
+                create_pool_accounts,
+                sqrt_price_x64,
+                open_time,
+                &[], // payer signs naturally, no PDA seeds needed
+            )?;
+        }
+
+        #[cfg(any(feature = "localnet", feature = "devnet"))]
+        {
+            msg!("DEVNET: Skipping SAMM create_pool CPI (test mode)");
+            // Suppress unused variable warnings in test mode
+            let _ = create_pool_accounts;
+            let _ = sqrt_price_x64;
+            let _ = open_time;
+        }
     }
 
+    // ---- Register the pool's sovereign-token vault ----
+    let (expected_token_vault, _) = Pubkey::find_program_address(
+        &[
+            SAMM_POOL_VAULT_SEED,
+            ctx.accounts.pool_state.key().as_ref(),
+            token_key.as_ref(),
+        ],
+        &SAMM_PROGRAM_ID,
+    );
+    let samm_token_vault = if wgor_is_0 {
+        ctx.accounts.samm_token_vault_1.key()
+    } else {
+        ctx.accounts.samm_token_vault_0.key()
+    };
+    require!(
+        samm_token_vault == expected_token_vault,
+        SovereignError::InvalidPoolVault
+    );
+
+    let registry = &mut ctx.accounts.pool_vault_registry;
+    registry.sovereign = sovereign.key();
+    registry.bump = ctx.bumps.pool_vault_registry;
+    registry.register(samm_token_vault)?;
+
+    emit!(PoolVaultRegistered {
+        sovereign_id: sovereign.sovereign_id,
+        vault: samm_token_vault,
+        registered_by: ctx.accounts.payer.key(),
+    });
+
     // ---- Update sovereign state ----
     sovereign.pool_state = ctx.accounts.pool_state.key();
-    sovereign.pool_restricted = true;
+    // An existing pool was never restricted by us, so there is nothing to lift later
+    sovereign.pool_restricted = !sovereign.uses_existing_pool();
     sovereign.total_supply = token_amount;
+    // An existing pool is already trading, so its guard window starts now
+    sovereign.trading_opens_at = if sovereign.uses_existing_pool() {
+        clock.unix_timestamp
+    } else {
+        trading_opens_at
+    };
     sovereign.state = SovereignStatus::PoolCreated;
 
-    emit!(SammPoolCreated {
-        sovereign_id: sovereign.sovereign_id,
-        pool_state: ctx.accounts.pool_state.key(),
-        token_mint_0: mint_0,
-        token_mint_1: mint_1,
-        sqrt_price_x64,
-        created_at: clock.unix_timestamp,
-    });
+    if !sovereign.uses_existing_pool() {
+        emit!(SammPoolCreated {
+            sovereign_id: sovereign.sovereign_id,
+            pool_state: ctx.accounts.pool_state.key(),
+            token_mint_0: mint_0,
+            token_mint_1: mint_1,
+            sqrt_price_x64,
+            created_at: clock.unix_timestamp,
+        });
+    }
 
-    msg!("Pool created: {}", ctx.accounts.pool_state.key());
+    msg!("Pool ready: {}", ctx.accounts.pool_state.key());
     Ok(())
 }
 
@@ -269,17 +542,23 @@ pub fn finalize_create_pool_handler(ctx: Context<FinalizeCreatePool>) -> Result<
 ///
 /// Prerequisites:
 /// - Sovereign must be in `PoolCreated` state
-/// - Frontend must create:
-///   1. WGOR ATA for permanent_lock PDA
-///   2. Sovereign token ATA for permanent_lock PDA (Token-2022)
-///   3. Fresh Keypair for position NFT mint (passed as signer)
-///   4. ATA of permanent_lock for position NFT mint
+/// - Caller (any cranker) provides:
+///   1. Fresh Keypair for position NFT mint (passed as signer)
+///   2. ATA address of permanent_lock for position NFT mint (created by SAMM)
+/// - The permanent_lock's WGOR and sovereign token ATAs are created here if missing
 ///
 /// After success:
 /// - SOL wrapped to WGOR and deposited in pool
 /// - Sovereign tokens deposited in pool
 /// - Full-range position created, owned by permanent_lock
+/// - Creator escrow swapped for tokens, held in creator_token_vault
 /// - Sovereign transitions to `Recovery` state
+///
+/// remaining_accounts (required when creator_escrow > 0):
+///   [0]    amm_config          — SAMM AMM config (readonly)
+///   [1]    escrow_wgor_account — WGOR token account owned by sovereign PDA (writable)
+///   [2]    memo_program        — Memo program (readonly)
+///   [3..N] tick_arrays         — SAMM tick arrays for the swap path (writable)
 #[derive(Accounts)]
 pub struct FinalizeAddLiquidity<'info> {
     #[account(mut)]
@@ -299,13 +578,15 @@ pub struct FinalizeAddLiquidity<'info> {
     pub sovereign: Box<Account<'info, SovereignState>>,
 
     /// Token mint for the sovereign token (Token-2022)
+    /// Mutable so the transfer hook can be detached while the program moves tokens
     #[account(
+        mut,
         address = sovereign.token_mint
     )]
     pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
-    /// WGOR native mint
-    #[account(address = WGOR_MINT)]
+    /// Quote side of the pool: WGOR native mint, or the sovereign's token quote mint
+    #[account(address = sovereign.pool_quote_mint() @ SovereignError::InvalidQuoteMint)]
     pub wgor_mint: Box<InterfaceAccount<'info, MintInterface>>,
 
     /// SOL vault holding deposits
@@ -317,6 +598,14 @@ pub struct FinalizeAddLiquidity<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
 
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
     /// Sovereign's token vault (Token-2022)
     #[account(
         mut,
@@ -335,15 +624,35 @@ pub struct FinalizeAddLiquidity<'info> {
     )]
     pub permanent_lock: Box<Account<'info, PermanentLock>>,
 
-    // ---- Token accounts for permanent_lock (created by frontend) ----
+    /// Creator fee tracker - records tokens bought with the creator escrow
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_TRACKER_SEED, sovereign.key().as_ref()],
+        bump = creator_fee_tracker.bump
+    )]
+    pub creator_fee_tracker: Box<Account<'info, CreatorFeeTracker>>,
 
-    /// WGOR token account owned by permanent_lock PDA
-    /// CHECK: Frontend creates this ATA before calling instruction
+    /// Holds the creator's market-bought tokens until recovery or unwind (Token-2022)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = sovereign,
+        token::token_program = token_program_2022,
+        seeds = [CREATOR_TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub creator_token_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    // ---- Token accounts for permanent_lock (created if missing) ----
+
+    /// WGOR (or token quote) ATA of permanent_lock PDA
+    /// CHECK: Created idempotently through the ATA program, which checks the address
     #[account(mut)]
     pub lock_wgor_account: UncheckedAccount<'info>,
 
-    /// Sovereign token account owned by permanent_lock PDA (Token-2022)
-    /// CHECK: Frontend creates this ATA before calling instruction
+    /// Sovereign token ATA of permanent_lock PDA (Token-2022)
+    /// CHECK: Created idempotently through the ATA program, which checks the address
     #[account(mut)]
     pub lock_token_account: UncheckedAccount<'info>,
 
@@ -423,6 +732,9 @@ pub struct FinalizeAddLiquidity<'info> {
     /// Token-2022 program (for sovereign token)
     pub token_program_2022: Interface<'info, TokenInterface>,
 
+    /// Token program of a token quote mint (required for token quote sovereigns)
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -446,6 +758,11 @@ pub fn finalize_add_liquidity_handler<'info>(
         sovereign.pool_state != Pubkey::default(),
         SovereignError::PoolNotCreated
     );
+    // LP ladder rungs open first; the primary position takes what they leave
+    require!(
+        ctx.accounts.permanent_lock.position_count == sovereign.ladder_rung_count(),
+        SovereignError::InvalidLadderIndex
+    );
 
     // ---- Signer seeds ----
     let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
@@ -463,8 +780,31 @@ pub fn finalize_add_liquidity_handler<'info>(
     ];
     let lock_signer_seeds = &[&lock_seeds[..]];
 
+    // Token-2022 would re-enter this program through the hook on every token move below
+    let hook_detached = detach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer_seeds,
+    )?;
+
+    let quote = QuoteToken::resolve(
+        sovereign,
+        Some(&ctx.accounts.wgor_mint),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    // Program of the quote-side token accounts (legacy SPL for WGOR)
+    let quote_program_info = match quote.as_ref() {
+        Some(quote) => quote.token_program.clone(),
+        None => ctx.accounts.token_program.to_account_info(),
+    };
+
     // ---- Calculate amounts ----
-    let sol_amount = ctx.accounts.sol_vault.lamports();
+    // Creator escrow stays in sol_vault for the market buy after the position opens
+    let creator_escrow = sovereign.creator_escrow;
+    let sol_amount = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?
+        .checked_sub(creator_escrow)
+        .ok_or(SovereignError::Underflow)?;
     let token_amount = ctx.accounts.token_vault.amount;
 
     // LP token allocation
@@ -484,9 +824,23 @@ pub fn finalize_add_liquidity_handler<'info>(
         lp_tokens
     );
 
-    // ---- Step 1: Wrap SOL to WGOR ----
-    // Transfer SOL from sol_vault PDA → lock_wgor_account
-    // Then sync_native to update token balance
+    create_lock_token_accounts(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.permanent_lock.to_account_info(),
+        &ctx.accounts.wgor_mint.to_account_info(),
+        &ctx.accounts.lock_wgor_account.to_account_info(),
+        &quote_program_info,
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.lock_token_account.to_account_info(),
+        &ctx.accounts.token_program_2022.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // ---- Step 1: Move the raise into the lock's quote account ----
+    // Native: transfer SOL from sol_vault PDA → lock_wgor_account,
+    //         then sync_native to update token balance
+    // Token:  transfer quote tokens from quote_vault → lock_wgor_account
 
     let sol_vault_bump = ctx.bumps.sol_vault;
     let sol_vault_seeds = &[
@@ -496,31 +850,29 @@ pub fn finalize_add_liquidity_handler<'info>(
     ];
     let sol_vault_signer = &[&sol_vault_seeds[..]];
 
-    // Transfer SOL from sol_vault to the WGOR token account
-    let transfer_sol_ix = anchor_lang::solana_program::system_instruction::transfer(
-        &ctx.accounts.sol_vault.key(),
-        &ctx.accounts.lock_wgor_account.key(),
+    quote::pay_out(
+        quote.as_ref(),
+        &ctx.accounts.sol_vault.to_account_info(),
+        sol_vault_seeds,
+        &ctx.accounts.lock_wgor_account.to_account_info(),
+        Some(ctx.accounts.lock_wgor_account.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
         sol_amount,
-    );
-    invoke_signed(
-        &transfer_sol_ix,
-        &[
-            ctx.accounts.sol_vault.to_account_info(),
-            ctx.accounts.lock_wgor_account.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        sol_vault_signer,
     )?;
 
-    // Sync native to update the WGOR token balance
-    token::sync_native(CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        SyncNative {
-            account: ctx.accounts.lock_wgor_account.to_account_info(),
-        },
-    ))?;
+    if quote.is_none() {
+        // Sync native to update the WGOR token balance
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.lock_wgor_account.to_account_info(),
+            },
+        ))?;
 
-    msg!("Wrapped {} lamports to WGOR", sol_amount);
+        msg!("Wrapped {} lamports to WGOR", sol_amount);
+    } else {
+        msg!("Moved {} quote tokens to permanent lock", sol_amount);
+    }
 
     // ---- Step 2: Transfer sovereign tokens to permanent_lock's account ----
     let token_decimals = ctx.accounts.token_mint.decimals;
@@ -582,39 +934,41 @@ pub fn finalize_add_liquidity_handler<'info>(
     };
 
     // ---- Step 4: Let SAMM compute liquidity from token amounts ----
-    // Instead of computing liquidity ourselves (f64 math diverges from SAMM's
-    // Q64.64 fixed-point), we pass liquidity=0 with base_flag so the SAMM
-    // calculates optimal liquidity. We compute from the sovereign token side
-    // to maximize token utilization per spec ("100% tokens to LP").
+    // Instead of passing an explicit liquidity (the SAMM would then demand
+    // rounded-up amounts for it), we pass liquidity=0 with base_flag so the SAMM
+    // calculates optimal liquidity. See samm_cpi::plan_liquidity.
     //
     // base_flag=true  → compute L from amount_0_max
     // base_flag=false → compute L from amount_1_max
     //
-    // We apply a tiny 0.01% safety margin on the base (token) side so the
-    // SAMM's computed need for the other side stays within what we have.
-    // For a correctly-priced pool, dust is ≤ 0.01% of each side.
-    let (base_flag, amount_0_for_lp, amount_1_for_lp) = if wgor_is_0 {
-        // tokens are amount_1 → compute L from amount_1 → base_flag=false
-        let token_adj = amount_1.checked_mul(9999).unwrap().checked_div(10000).unwrap();
-        (Some(false), amount_0, token_adj)
+    // The pool was created at the deposit ratio in finalize_create_pool; LP ladder
+    // positions open at the pool's live price, and so does the primary.
+    let implied_sqrt_price_x64 = if wgor_is_0 {
+        tick_math::sqrt_price_x64_from_amounts(sol_amount, tokens_in_lock)?
     } else {
-        // tokens are amount_0 → compute L from amount_0 → base_flag=true
-        let token_adj = amount_0.checked_mul(9999).unwrap().checked_div(10000).unwrap();
-        (Some(true), token_adj, amount_1)
+        tick_math::sqrt_price_x64_from_amounts(tokens_in_lock, sol_amount)?
     };
-
-    // Estimate liquidity for record-keeping (not used by SAMM)
-    let price = if wgor_is_0 {
-        tokens_in_lock as f64 / sol_amount as f64
-    } else {
-        sol_amount as f64 / tokens_in_lock as f64
+    #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+    let sqrt_price_x64 = {
+        let pool_data = ctx.accounts.pool_state.try_borrow_data()?;
+        PoolState::try_deserialize(&pool_data)?.sqrt_price_x64
     };
-    let sqrt_price_x64 = samm_cpi::price_to_sqrt_price_x64(price);
-    let liquidity_estimate = samm_cpi::calculate_full_range_liquidity(
-        amount_0,
-        amount_1,
+    #[cfg(any(feature = "localnet", feature = "devnet"))]
+    let sqrt_price_x64 = implied_sqrt_price_x64;
+    // An existing pool trades freely, so its price is re-checked before depositing
+    if sovereign.uses_existing_pool() {
+        check_existing_pool_price(sqrt_price_x64, implied_sqrt_price_x64)?;
+    }
+    let plan = samm_cpi::plan_liquidity(
         sqrt_price_x64,
-    );
+        sovereign.lp_price_band_bps,
+        wgor_is_0,
+        sol_amount,
+        tokens_in_lock,
+    )?;
+    let (tick_lower, tick_upper) = (plan.tick_lower, plan.tick_upper);
+    // With the safety margin, this is the liquidity the SAMM mints; kept for record-keeping
+    let liquidity_estimate = plan.liquidity;
 
     // ---- Step 5: Approve wallet payer as delegate on token accounts ----
     // The SAMM's OpenPositionV2 uses the `payer` account for both:
@@ -624,11 +978,11 @@ pub fn finalize_add_liquidity_handler<'info>(
     // be used as the SAMM payer. Instead, we approve the wallet payer as a
     // delegate on both token accounts so the SAMM can use the wallet for both.
 
-    // Approve payer as delegate on WGOR account (legacy SPL Token)
-    token::approve(
+    // Approve payer as delegate on WGOR (quote) account
+    token_interface::approve(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Approve {
+            quote_program_info.clone(),
+            ApproveInterface {
                 to: ctx.accounts.lock_wgor_account.to_account_info(),
                 delegate: ctx.accounts.payer.to_account_info(),
                 authority: ctx.accounts.permanent_lock.to_account_info(),
@@ -706,14 +1060,16 @@ pub fn finalize_add_liquidity_handler<'info>(
         // No PDA signer seeds needed - wallet payer is already a transaction signer
         // Pass liquidity=0 with base_flag to let the SAMM compute optimal liquidity
         // from the sovereign token side (maximizes token utilization)
-        samm_cpi::open_position_full_range(
+        samm_cpi::open_position(
             &ctx.accounts.samm_program.to_account_info(),
             open_position_accounts,
-            0,                // let SAMM compute liquidity
-            amount_0_for_lp,  // max amount_0 (full if GOR, 0.01%-reduced if tokens)
-            amount_1_for_lp,  // max amount_1 (full if GOR, 0.01%-reduced if tokens)
-            samm::tick::DEFAULT_TICK_SPACING,
-            base_flag,        // compute L from whichever side is the sovereign token
+            tick_lower,
+            tick_upper,
+            0,                  // let SAMM compute liquidity
+            plan.amount_0_max,  // max amount_0 (full if GOR, 0.01%-reduced if tokens)
+            plan.amount_1_max,  // max amount_1 (full if GOR, 0.01%-reduced if tokens)
+            crate::samm::tick::DEFAULT_TICK_SPACING,
+            plan.base_flag,     // compute L from the sovereign token side (or the binding side)
             &[], // wallet + position_nft_mint are already outer tx signers
         )?;
 
@@ -726,9 +1082,9 @@ pub fn finalize_add_liquidity_handler<'info>(
     }
 
     // ---- Step 7: Revoke delegate approvals for security ----
-    // Revoke delegate on WGOR account
+    // Revoke delegate on WGOR (quote) account
     let revoke_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id: ctx.accounts.token_program.key(),
+        program_id: quote_program_info.key(),
         accounts: vec![
             anchor_lang::solana_program::instruction::AccountMeta::new(
                 ctx.accounts.lock_wgor_account.key(), false,
@@ -744,7 +1100,7 @@ pub fn finalize_add_liquidity_handler<'info>(
         &[
             ctx.accounts.lock_wgor_account.to_account_info(),
             ctx.accounts.permanent_lock.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
+            quote_program_info,
         ],
         lock_signer_seeds,
     )?;
@@ -779,8 +1135,8 @@ pub fn finalize_add_liquidity_handler<'info>(
     permanent_lock.position_mint = ctx.accounts.position_nft_mint.key();
     permanent_lock.position_token_account = ctx.accounts.position_nft_account.key();
     permanent_lock.liquidity = liquidity_estimate;
-    permanent_lock.tick_lower_index = samm::tick::MIN_TICK;
-    permanent_lock.tick_upper_index = samm::tick::MAX_TICK;
+    permanent_lock.tick_lower_index = tick_lower;
+    permanent_lock.tick_upper_index = tick_upper;
     permanent_lock.unwound = false;
     permanent_lock.created_at = clock.unix_timestamp;
     permanent_lock.bump = ctx.bumps.permanent_lock;
@@ -818,6 +1174,8 @@ pub fn finalize_add_liquidity_handler<'info>(
         liquidity: liquidity_estimate,
         amount_0,
         amount_1,
+        tick_lower_index: tick_lower,
+        tick_upper_index: tick_upper,
     });
 
     emit!(SovereignFinalized {
@@ -829,17 +1187,173 @@ pub fn finalize_add_liquidity_handler<'info>(
         finalized_at: clock.unix_timestamp,
     });
 
-    // ---- Creator market buy (placeholder) ----
-    if sovereign.creator_escrow > 0 {
-        emit!(CreatorMarketBuyExecuted {
-            sovereign_id: sovereign.sovereign_id,
-            creator: sovereign.creator,
-            sol_amount: sovereign.creator_escrow,
-            tokens_received: 0, // TODO: Execute actual swap
-        });
-        sovereign.creator_escrow = 0;
+    // ---- Step 9: Creator market buy ----
+    // The creator's escrow is not LP - it buys tokens through the freshly
+    // opened pool in the same transaction (no window for a sandwich).
+    // Bought tokens stay in creator_token_vault until recovery or unwind.
+    if creator_escrow > 0 {
+        require!(
+            ctx.remaining_accounts.len() >= 4,
+            SovereignError::InsufficientAccounts
+        );
+        require!(
+            ctx.remaining_accounts[0].key() == sovereign.amm_config,
+            SovereignError::InvalidAmmConfig
+        );
+
+        let escrow_wgor_info = &ctx.remaining_accounts[1];
+        {
+            let escrow_wgor: Account<'info, TokenAccount> = Account::try_from(escrow_wgor_info)
+                .map_err(|_| SovereignError::InvalidEscrowWgorAccount)?;
+            require!(
+                escrow_wgor.mint == WGOR_MINT && escrow_wgor.owner == sovereign_key,
+                SovereignError::InvalidEscrowWgorAccount
+            );
+        }
+
+        let min_tokens_out = creator_buy_min_out(sovereign, tokens_in_lock, sol_amount, creator_escrow)?;
+
+        #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+        let tokens_received = {
+            // Wrap the escrow: sol_vault → escrow WGOR account, then sync_native
+            let transfer_escrow_ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.sol_vault.key(),
+                &escrow_wgor_info.key(),
+                creator_escrow,
+            );
+            invoke_signed(
+                &transfer_escrow_ix,
+                &[
+                    ctx.accounts.sol_vault.to_account_info(),
+                    escrow_wgor_info.clone(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                sol_vault_signer,
+            )?;
+
+            token::sync_native(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SyncNative {
+                    account: escrow_wgor_info.clone(),
+                },
+            ))?;
+
+            let (input_vault, output_vault) = if wgor_is_0 {
+                (
+                    ctx.accounts.samm_token_vault_0.to_account_info(),
+                    ctx.accounts.samm_token_vault_1.to_account_info(),
+                )
+            } else {
+                (
+                    ctx.accounts.samm_token_vault_1.to_account_info(),
+                    ctx.accounts.samm_token_vault_0.to_account_info(),
+                )
+            };
+
+            let swap_accounts = samm_ix::SwapV2Accounts {
+                payer: sovereign.to_account_info(),                     // Sovereign PDA owns the escrow WGOR
+                amm_config: ctx.remaining_accounts[0].clone(),
+                pool_state: ctx.accounts.pool_state.to_account_info(),
+                input_token_account: escrow_wgor_info.clone(),          // WGOR (input)
+                output_token_account: ctx.accounts.creator_token_vault.to_account_info(), // tokens (output)
+                input_vault,
+                output_vault,
+                observation_state: ctx.accounts.observation_state.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_program_2022: ctx.accounts.token_program_2022.to_account_info(),
+                memo_program: ctx.remaining_accounts[2].clone(),
+                input_vault_mint: ctx.accounts.wgor_mint.to_account_info(),
+                output_vault_mint: ctx.accounts.token_mint.to_account_info(),
+            };
+
+            let tokens_before = ctx.accounts.creator_token_vault.amount;
+
+            samm_cpi::swap_exact_input(
+                &ctx.accounts.samm_program.to_account_info(),
+                swap_accounts,
+                creator_escrow,
+                min_tokens_out,
+                0, // sqrt_price_limit — 0 means no limit
+                ctx.remaining_accounts[3..].to_vec(),
+                sovereign_signer_seeds,
+            )?;
+
+            // swap_exact_input does not parse return data - measure the vault delta
+            // (net of any Token-2022 transfer fee withheld on the way in)
+            ctx.accounts.creator_token_vault.reload()?;
+            let received = ctx.accounts.creator_token_vault.amount
+                .checked_sub(tokens_before)
+                .ok_or(SovereignError::Underflow)?;
+            require!(received >= min_tokens_out, SovereignError::SlippageExceeded);
+
+            // Close the escrow WGOR account → any unswapped dust + rent back to sol_vault
+            let close_escrow_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
+                program_id: ctx.accounts.token_program.key(),
+                accounts: vec![
+                    anchor_lang::solana_program::instruction::AccountMeta::new(
+                        escrow_wgor_info.key(), false,
+                    ),
+                    anchor_lang::solana_program::instruction::AccountMeta::new(
+                        ctx.accounts.sol_vault.key(), false,
+                    ),
+                    anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
+                        sovereign_key, true,
+                    ),
+                ],
+                data: vec![9u8], // SPL Token CloseAccount instruction discriminator
+            };
+            invoke_signed(
+                &close_escrow_wgor_ix,
+                &[
+                    escrow_wgor_info.clone(),
+                    ctx.accounts.sol_vault.to_account_info(),
+                    sovereign.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                ],
+                sovereign_signer_seeds,
+            )?;
+
+            msg!("Creator market buy: {} lamports → {} tokens", creator_escrow, received);
+            Some(received)
+        };
+
+        #[cfg(any(feature = "localnet", feature = "devnet"))]
+        let tokens_received = {
+            // No swap without the AMM: the escrow stays in sol_vault and stays
+            // recorded in creator_escrow (emergency_withdraw_creator returns it)
+            msg!("DEVNET: Skipping creator market buy swap (test mode), {} lamports left in escrow", creator_escrow);
+            let _ = (escrow_wgor_info, min_tokens_out);
+            emit!(CreatorMarketBuySkipped {
+                sovereign_id: sovereign.sovereign_id,
+                creator: sovereign.creator,
+                sol_amount: creator_escrow,
+            });
+            None
+        };
+
+        if let Some(tokens_received) = tokens_received {
+            let tracker = &mut ctx.accounts.creator_fee_tracker;
+            tracker.purchased_tokens = tokens_received;
+            tracker.tokens_locked = true;
+            tracker.purchased_at = clock.unix_timestamp;
+
+            emit!(CreatorMarketBuyExecuted {
+                sovereign_id: sovereign.sovereign_id,
+                creator: sovereign.creator,
+                sol_amount: creator_escrow,
+                tokens_received,
+            });
+            sovereign.creator_escrow = 0;
+        }
     }
 
+    reattach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer_seeds,
+        hook_detached,
+    )?;
+
     msg!("Sovereign finalized successfully. State: Recovery");
     Ok(())
 }
@@ -918,13 +1432,10 @@ pub fn mint_genesis_nft_handler(ctx: Context<MintGenesisNFT>) -> Result<()> {
     require!(!deposit_record.nft_minted, SovereignError::NFTAlreadyMinted);
     require!(deposit_record.amount > 0, SovereignError::ZeroDeposit);
 
-    // Calculate voting power based on deposit share
-    let voting_power = deposit_record
-        .amount
-        .checked_mul(BPS_DENOMINATOR as u64)
-        .unwrap()
-        .checked_div(sovereign.total_deposited)
-        .unwrap();
+    // Calculate voting power based on weighted deposit share
+    let voting_power = sovereign
+        .share_bps(deposit_record.share_units)
+        .ok_or(SovereignError::DivisionByZero)?;
 
     require!(
         voting_power <= u16::MAX as u64,
@@ -1051,3 +1562,176 @@ pub fn mint_genesis_nft_handler(ctx: Context<MintGenesisNFT>) -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================
+// RELEASE CREATION FEE
+// ============================================================
+
+/// Release the escrowed creation fee to the protocol treasury.
+/// Permissionless - callable by anyone once the sovereign has finalized.
+///
+/// If any deposits were referred, `referral_fee_bps` of the fee stays in the
+/// escrow as the referral pool, claimable via claim_referral_reward.
+///
+/// The escrow PDA stays open (rent-exempt) with `released = true`; its amount
+/// is then the unclaimed referral pool, which emergency_withdraw_creator leaves
+/// in place for the referrers.
+#[derive(Accounts)]
+pub struct ReleaseCreationFee<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+
+    #[account(
+        mut,
+        seeds = [CREATION_FEE_ESCROW_SEED, sovereign.key().as_ref()],
+        bump = creation_fee_escrow.bump,
+        constraint = !creation_fee_escrow.released @ SovereignError::CreationFeeAlreadyReleased
+    )]
+    pub creation_fee_escrow: Account<'info, CreationFeeEscrow>,
+
+    /// CHECK: Protocol treasury — receives the creation fee
+    #[account(
+        mut,
+        address = protocol_state.treasury @ SovereignError::InvalidTreasury
+    )]
+    pub treasury: SystemAccount<'info>,
+}
+
+pub fn release_creation_fee_handler(ctx: Context<ReleaseCreationFee>) -> Result<()> {
+    let sovereign = &mut ctx.accounts.sovereign;
+    let protocol = &mut ctx.accounts.protocol_state;
+    let escrow = &mut ctx.accounts.creation_fee_escrow;
+    let clock = Clock::get()?;
+
+    // Bonding must have succeeded (liquidity added). An emergency-unlocked
+    // sovereign refunds the creation fee to the creator instead.
+    require!(
+        sovereign.finalized_at > 0,
+        SovereignError::InvalidState
+    );
+    require!(
+        sovereign.state != SovereignStatus::EmergencyUnlocked,
+        SovereignError::InvalidState
+    );
+
+    // Referrers' share stays in the escrow
+    let referral_pool = if sovereign.total_referred_volume > 0 {
+        (escrow.amount as u128 * sovereign.referral_fee_bps as u128 / BPS_100_PERCENT as u128) as u64
+    } else {
+        0
+    };
+    let amount = escrow.amount
+        .checked_sub(referral_pool)
+        .ok_or(SovereignError::Overflow)?;
+
+    // Effects before interaction
+    escrow.amount = referral_pool;
+    escrow.released = true;
+    sovereign.creation_fee_escrowed = 0;
+    sovereign.referral_pool = referral_pool;
+    protocol.total_fees_collected = protocol.total_fees_collected
+        .checked_add(amount)
+        .ok_or(SovereignError::Overflow)?;
+
+    // Escrow is a program-owned data account - move lamports directly
+    if amount > 0 {
+        escrow.sub_lamports(amount)?;
+        ctx.accounts.treasury.add_lamports(amount)?;
+    }
+
+    emit!(CreationFeeReleased {
+        sovereign_id: sovereign.sovereign_id,
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+        referral_pool,
+        released_at: clock.unix_timestamp,
+    });
+
+    msg!("Creation fee released: {} lamports → treasury, {} lamports → referral pool", amount, referral_pool);
+    Ok(())
+}
+
+// ============================================================
+// CLAIM REFERRAL REWARD
+// ============================================================
+
+/// Claim a referrer's share of the referral pool.
+/// The pool is split pro-rata to the volume each referrer brought in.
+#[derive(Accounts)]
+pub struct ClaimReferralReward<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_RECORD_SEED, sovereign.key().as_ref(), referrer.key().as_ref()],
+        bump = referral_record.bump,
+        constraint = !referral_record.claimed @ SovereignError::ReferralRewardAlreadyClaimed
+    )]
+    pub referral_record: Account<'info, ReferralRecord>,
+
+    #[account(
+        mut,
+        seeds = [CREATION_FEE_ESCROW_SEED, sovereign.key().as_ref()],
+        bump = creation_fee_escrow.bump,
+        constraint = creation_fee_escrow.released @ SovereignError::ReferralRewardsNotAvailable
+    )]
+    pub creation_fee_escrow: Account<'info, CreationFeeEscrow>,
+}
+
+pub fn claim_referral_reward_handler(ctx: Context<ClaimReferralReward>) -> Result<()> {
+    let sovereign = &ctx.accounts.sovereign;
+    let referral_record = &mut ctx.accounts.referral_record;
+    let escrow = &mut ctx.accounts.creation_fee_escrow;
+
+    require!(
+        sovereign.referral_pool > 0,
+        SovereignError::ReferralRewardsNotAvailable
+    );
+
+    let reward = std::cmp::min(
+        referral_record.calculate_reward(sovereign.referral_pool, sovereign.total_referred_volume),
+        escrow.amount,
+    );
+    require!(reward > 0, SovereignError::NoReferralReward);
+
+    // Effects before interaction
+    referral_record.claimed = true;
+    referral_record.reward_claimed = reward;
+    escrow.amount = escrow.amount
+        .checked_sub(reward)
+        .ok_or(SovereignError::Overflow)?;
+
+    escrow.sub_lamports(reward)?;
+    ctx.accounts.referrer.add_lamports(reward)?;
+
+    emit!(ReferralRewardClaimed {
+        sovereign_id: sovereign.sovereign_id,
+        referrer: ctx.accounts.referrer.key(),
+        amount: reward,
+        referred_volume: referral_record.referred_volume,
+        total_referred_volume: sovereign.total_referred_volume,
+        referral_pool: sovereign.referral_pool,
+    });
+
+    Ok(())
+}
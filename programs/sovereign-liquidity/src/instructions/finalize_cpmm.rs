@@ -0,0 +1,796 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::{self, Token, SyncNative};
+use anchor_spl::token_interface::{
+    self,
+    CloseAccount,
+    Mint as MintInterface,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
+use anchor_spl::associated_token::{self, AssociatedToken};
+use crate::cpmm::{pda_seeds, CpmmAmmConfig};
+use crate::quote::{self, QuoteToken};
+use crate::samm::cpi as samm_cpi;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::SovereignError;
+use crate::events::*;
+use super::finalize::{begin_pool_creation, create_lock_token_accounts, creator_buy_min_out};
+use super::transfer_hook::{detach_transfer_hook, reattach_transfer_hook};
+
+// ============================================================
+// CPMM: CREATE POOL + LOCK LP
+// ============================================================
+
+/// Create the CPMM pool for a CPMM sovereign and lock its LP tokens.
+/// Single-step counterpart of finalize_create_pool + finalize_add_liquidity.
+///
+/// Prerequisites:
+/// - Sovereign uses the CPMM backend (`amm_kind == Cpmm`)
+/// - Sovereign must be in `Finalizing` state (hard cap met), or in `Bonding`
+///   past the deadline with the soft cap met
+///
+/// The CPMM's pool creator must be a signer without data that pays rent, so the
+/// sol_vault PDA creates the pool from its own token accounts. The cranker fronts
+/// the create pool fee plus CPMM_POOL_RENT_RESERVE to sol_vault and gets back
+/// whatever the CPMM did not spend.
+///
+/// After success:
+/// - Pool created at the deposit ratio with the raise and the LP token allocation
+/// - LP tokens held by permanent_lock, principal recorded in sqrt(k) units
+/// - Creator escrow swapped for tokens, held in creator_token_vault
+/// - Sovereign transitions to `Recovery` state (the pool is never restricted)
+#[derive(Accounts)]
+pub struct FinalizeCpmmPool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    #[account(
+        mut,
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+
+    /// The sovereign's token mint (Token-2022)
+    /// Mutable so the transfer hook can be detached while the program moves tokens
+    #[account(mut, address = sovereign.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// Quote side of the pool: WGOR native mint, or the sovereign's token quote mint
+    #[account(address = sovereign.pool_quote_mint() @ SovereignError::InvalidQuoteMint)]
+    pub wgor_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// SOL vault holding deposits - the CPMM pool creator
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
+    /// Sovereign's token vault (Token-2022)
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Permanent lock PDA (initialized in this instruction)
+    #[account(
+        init,
+        payer = payer,
+        space = PermanentLock::LEN,
+        seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub permanent_lock: Box<Account<'info, PermanentLock>>,
+
+    /// Creator fee tracker - records tokens bought with the creator escrow
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_TRACKER_SEED, sovereign.key().as_ref()],
+        bump = creator_fee_tracker.bump
+    )]
+    pub creator_fee_tracker: Box<Account<'info, CreatorFeeTracker>>,
+
+    /// Holds the creator's market-bought tokens until recovery or unwind (Token-2022)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        token::mint = token_mint,
+        token::authority = sovereign,
+        token::token_program = token_program_2022,
+        seeds = [CREATOR_TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub creator_token_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    // ---- Pool creator token accounts (owned by sol_vault) ----
+
+    /// WGOR ATA of sol_vault for native quote sovereigns (created here, closed at the end);
+    /// the quote_vault itself for token quote sovereigns
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub vault_quote_account: UncheckedAccount<'info>,
+
+    /// Sovereign token ATA of sol_vault (Token-2022)
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: LP token ATA of sol_vault (created by the CPMM, closed here)
+    #[account(mut)]
+    pub vault_lp_account: UncheckedAccount<'info>,
+
+    // ---- Token accounts for permanent_lock (created if missing) ----
+
+    /// WGOR (or token quote) ATA of permanent_lock PDA
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub lock_wgor_account: UncheckedAccount<'info>,
+
+    /// Sovereign token ATA of permanent_lock PDA (Token-2022)
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub lock_token_account: UncheckedAccount<'info>,
+
+    /// LP token ATA of permanent_lock PDA - holds the locked LP tokens
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub lock_lp_account: UncheckedAccount<'info>,
+
+    // ---- CPMM Accounts ----
+
+    /// CHECK: CPMM program recorded on the sovereign
+    #[account(address = sovereign.amm_program @ SovereignError::InvalidAmmProgram)]
+    pub amm_program: UncheckedAccount<'info>,
+
+    /// CHECK: AMM configuration account on the CPMM — validated against sovereign.amm_config
+    #[account(address = sovereign.amm_config)]
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: Vault and LP mint authority PDA - derived from ["vault_and_lp_mint_auth_seed"]
+    pub cpmm_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Pool state PDA - derived from ["pool", amm_config, token_mint_0, token_mint_1]
+    #[account(mut)]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: LP mint PDA - derived from ["pool_lp_mint", pool_state]
+    #[account(mut)]
+    pub lp_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token vault 0 PDA on the CPMM - derived from ["pool_vault", pool_state, token_mint_0]
+    #[account(mut)]
+    pub cpmm_token_vault_0: UncheckedAccount<'info>,
+
+    /// CHECK: Token vault 1 PDA on the CPMM - derived from ["pool_vault", pool_state, token_mint_1]
+    #[account(mut)]
+    pub cpmm_token_vault_1: UncheckedAccount<'info>,
+
+    /// CHECK: Create pool fee receiver (checked by the CPMM)
+    #[account(mut)]
+    pub create_pool_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Observation state PDA - derived from ["observation", pool_state]
+    #[account(mut)]
+    pub observation_state: UncheckedAccount<'info>,
+
+    /// Pool vault registry used by the transfer hook to classify buys/sells.
+    /// Seeded with the pool's sovereign-token vault.
+    #[account(
+        init,
+        payer = payer,
+        space = PoolVaultRegistry::LEN,
+        seeds = [POOL_VAULT_REGISTRY_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub pool_vault_registry: Box<Account<'info, PoolVaultRegistry>>,
+
+    // ---- Standard Programs ----
+
+    /// Legacy SPL Token program (for WGOR and the LP mint)
+    pub token_program: Program<'info, Token>,
+
+    /// Token-2022 program (for sovereign token)
+    pub token_program_2022: Interface<'info, TokenInterface>,
+
+    /// Token program of a token quote mint (required for token quote sovereigns)
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn finalize_cpmm_pool_handler(ctx: Context<FinalizeCpmmPool>) -> Result<()> {
+    let sovereign_key = ctx.accounts.sovereign.key();
+    let sovereign = &mut ctx.accounts.sovereign;
+    let protocol = &ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    // ---- Validations ----
+    require!(!protocol.paused, SovereignError::ProtocolPaused);
+    require!(sovereign.amm_kind == AmmKind::Cpmm, SovereignError::UnsupportedByAmm);
+    begin_pool_creation(sovereign, clock.unix_timestamp)?;
+
+    // ---- Signer seeds ----
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds = &[
+        SOVEREIGN_SEED,
+        &sovereign_id_bytes,
+        &[sovereign.bump],
+    ];
+    let sovereign_signer_seeds = &[&sovereign_seeds[..]];
+
+    // Token-2022 would re-enter this program through the hook on every token move below
+    let hook_detached = detach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer_seeds,
+    )?;
+
+    let sol_vault_seeds = &[
+        SOL_VAULT_SEED,
+        sovereign_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ];
+    let sol_vault_signer = &[&sol_vault_seeds[..]];
+
+    let quote = QuoteToken::resolve(
+        sovereign,
+        Some(&ctx.accounts.wgor_mint),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    // Program of the quote-side token accounts (legacy SPL for WGOR)
+    let quote_program_info = match quote.as_ref() {
+        Some(quote) => {
+            // The pool draws the token quote straight from the quote vault
+            require_keys_eq!(
+                ctx.accounts.vault_quote_account.key(),
+                quote.vault.key(),
+                SovereignError::InvalidQuoteMint
+            );
+            quote.token_program.clone()
+        }
+        None => ctx.accounts.token_program.to_account_info(),
+    };
+
+    // ---- Calculate amounts ----
+    // Creator escrow stays in sol_vault for the market buy after the pool opens
+    let creator_escrow = sovereign.creator_escrow;
+    let sol_amount = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?
+        .checked_sub(creator_escrow)
+        .ok_or(SovereignError::Underflow)?;
+    let token_amount = ctx.accounts.token_vault.amount;
+
+    // LP token allocation
+    let lp_tokens = if sovereign.sovereign_type == SovereignType::TokenLaunch {
+        token_amount
+            .checked_mul(LP_ALLOCATION_BPS as u64)
+            .unwrap()
+            .checked_div(BPS_DENOMINATOR as u64)
+            .unwrap()
+    } else {
+        token_amount
+    };
+
+    msg!(
+        "Creating CPMM pool: {} quote, {} tokens",
+        sol_amount,
+        lp_tokens
+    );
+
+    create_lock_token_accounts(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.permanent_lock.to_account_info(),
+        &ctx.accounts.wgor_mint.to_account_info(),
+        &ctx.accounts.lock_wgor_account.to_account_info(),
+        &quote_program_info,
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.lock_token_account.to_account_info(),
+        &ctx.accounts.token_program_2022.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // sol_vault's creator accounts: WGOR (native quote only) and sovereign token ATAs
+    let mut creator_accounts = vec![(
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.token_program_2022.to_account_info(),
+    )];
+    if quote.is_none() {
+        creator_accounts.push((
+            ctx.accounts.wgor_mint.to_account_info(),
+            ctx.accounts.vault_quote_account.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ));
+    }
+    for (mint, account, token_program) in creator_accounts {
+        associated_token::create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: account,
+                authority: ctx.accounts.sol_vault.to_account_info(),
+                mint,
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program,
+            },
+        ))?;
+    }
+
+    // ---- Step 1: Wrap the raise ----
+    // Native: transfer SOL from sol_vault PDA → its WGOR account, then sync_native
+    // Token:  the quote vault is already a sol_vault token account
+    if quote.is_none() {
+        quote::pay_out(
+            None,
+            &ctx.accounts.sol_vault.to_account_info(),
+            sol_vault_seeds,
+            &ctx.accounts.vault_quote_account.to_account_info(),
+            None,
+            &ctx.accounts.system_program.to_account_info(),
+            sol_amount,
+        )?;
+
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.vault_quote_account.to_account_info(),
+            },
+        ))?;
+
+        msg!("Wrapped {} lamports to WGOR", sol_amount);
+    }
+
+    // ---- Step 2: Transfer sovereign tokens to sol_vault's account ----
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program_2022.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.token_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: sovereign.to_account_info(),
+            },
+            sovereign_signer_seeds,
+        ),
+        lp_tokens,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Token-2022 withholds the transfer fee at the destination (ceiling division)
+    let tokens_for_pool = if sovereign.sell_fee_bps > 0 {
+        let fee = lp_tokens
+            .checked_mul(sovereign.sell_fee_bps as u64).unwrap()
+            .checked_add(9999).unwrap()
+            .checked_div(10000).unwrap();
+        lp_tokens.checked_sub(fee).unwrap()
+    } else {
+        lp_tokens
+    };
+
+    msg!("Transferred {} tokens to the pool creator ({} usable)", lp_tokens, tokens_for_pool);
+
+    // ---- Step 3: Determine token ordering ----
+    let wgor_key = ctx.accounts.wgor_mint.key();
+    let token_key = ctx.accounts.token_mint.key();
+    let (mint_0, mint_1, is_swapped) = samm_cpi::sort_mints(&wgor_key, &token_key);
+    let wgor_is_0 = !is_swapped;
+
+    let (amount_0, amount_1) = if wgor_is_0 {
+        (sol_amount, tokens_for_pool)
+    } else {
+        (tokens_for_pool, sol_amount)
+    };
+
+    // ---- Step 4: Front the pool creation costs to sol_vault ----
+    let cpmm_config = {
+        let data = ctx.accounts.amm_config.try_borrow_data()?;
+        CpmmAmmConfig::try_deserialize(&data)?
+    };
+    let creation_cost = cpmm_config.create_pool_fee
+        .checked_add(CPMM_POOL_RENT_RESERVE)
+        .ok_or(SovereignError::Overflow)?;
+    let vault_lamports_before = ctx.accounts.sol_vault.lamports();
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+        ),
+        creation_cost,
+    )?;
+
+    // ---- Trading open time ----
+    // open_time in the past = pool immediately tradeable.
+    // A launch guard can delay trading to give the anti-sniper window a clean start;
+    // the window is counted from the same timestamp.
+    let trading_opens_at = clock.unix_timestamp
+        .checked_add(sovereign.launch_guard.open_delay)
+        .ok_or(SovereignError::Overflow)?;
+    let open_time = if sovereign.launch_guard.open_delay > 0 {
+        trading_opens_at as u64
+    } else {
+        1u64
+    };
+
+    // ---- Step 5: CPI to CPMM initialize, then move the LP tokens to the lock ----
+    #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+    let (lp_amount, liquidity) = {
+        let (token_account_0, token_account_1, token_program_0, token_program_1, mint_0_info, mint_1_info) = if wgor_is_0 {
+            (
+                ctx.accounts.vault_quote_account.to_account_info(),
+                ctx.accounts.vault_token_account.to_account_info(),
+                quote_program_info.clone(),
+                ctx.accounts.token_program_2022.to_account_info(),
+                ctx.accounts.wgor_mint.to_account_info(),
+                ctx.accounts.token_mint.to_account_info(),
+            )
+        } else {
+            (
+                ctx.accounts.vault_token_account.to_account_info(),
+                ctx.accounts.vault_quote_account.to_account_info(),
+                ctx.accounts.token_program_2022.to_account_info(),
+                quote_program_info.clone(),
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.wgor_mint.to_account_info(),
+            )
+        };
+
+        let initialize_accounts = crate::cpmm::InitializeAccounts {
+            creator: ctx.accounts.sol_vault.to_account_info(),
+            amm_config: ctx.accounts.amm_config.to_account_info(),
+            authority: ctx.accounts.cpmm_authority.to_account_info(),
+            pool_state: ctx.accounts.pool_state.to_account_info(),
+            token_0_mint: mint_0_info,
+            token_1_mint: mint_1_info,
+            lp_mint: ctx.accounts.lp_mint.to_account_info(),
+            creator_token_0: token_account_0,
+            creator_token_1: token_account_1,
+            creator_lp_token: ctx.accounts.vault_lp_account.to_account_info(),
+            token_0_vault: ctx.accounts.cpmm_token_vault_0.to_account_info(),
+            token_1_vault: ctx.accounts.cpmm_token_vault_1.to_account_info(),
+            create_pool_fee: ctx.accounts.create_pool_fee.to_account_info(),
+            observation_state: ctx.accounts.observation_state.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            token_0_program: token_program_0,
+            token_1_program: token_program_1,
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        };
+
+        crate::cpmm::initialize(
+            &ctx.accounts.amm_program.to_account_info(),
+            initialize_accounts,
+            amount_0,
+            amount_1,
+            open_time,
+            sol_vault_signer,
+        )?;
+
+        let pool = {
+            let pool_data = ctx.accounts.pool_state.try_borrow_data()?;
+            crate::cpmm::CpmmPoolState::try_deserialize(&pool_data)?
+        };
+        let (reserve_0, reserve_1) = pool
+            .reserves(
+                crate::amm::token_amount(&ctx.accounts.cpmm_token_vault_0)?,
+                crate::amm::token_amount(&ctx.accounts.cpmm_token_vault_1)?,
+            )
+            .ok_or(SovereignError::Overflow)?;
+        let lp_amount = crate::amm::token_amount(&ctx.accounts.vault_lp_account)?;
+
+        associated_token::create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: ctx.accounts.lock_lp_account.to_account_info(),
+                authority: ctx.accounts.permanent_lock.to_account_info(),
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_lp_account.to_account_info(),
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.lock_lp_account.to_account_info(),
+                    authority: ctx.accounts.sol_vault.to_account_info(),
+                },
+                sol_vault_signer,
+            ),
+            lp_amount,
+            pool.lp_mint_decimals,
+        )?;
+
+        // Empty LP account rent → sol_vault (part of the unspent creation cost)
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_lp_account.to_account_info(),
+                destination: ctx.accounts.sol_vault.to_account_info(),
+                authority: ctx.accounts.sol_vault.to_account_info(),
+            },
+            sol_vault_signer,
+        ))?;
+
+        let liquidity = crate::cpmm::lp_to_sqrt_k(lp_amount, reserve_0, reserve_1, pool.lp_supply)
+            .ok_or(SovereignError::DivisionByZero)?;
+
+        msg!("CPMM pool created, {} LP tokens locked", lp_amount);
+        (lp_amount, liquidity)
+    };
+
+    #[cfg(any(feature = "localnet", feature = "devnet"))]
+    let (lp_amount, liquidity) = {
+        msg!("DEVNET: Skipping CPMM initialize CPI (test mode)");
+        let _ = (open_time, sol_vault_signer);
+        (0u64, 0u128)
+    };
+
+    // ---- Step 6: Return the unspent creation cost to the cranker ----
+    let vault_lamports = ctx.accounts.sol_vault.lamports();
+    require!(
+        vault_lamports >= vault_lamports_before,
+        SovereignError::InsufficientVaultBalance
+    );
+    let unspent = vault_lamports - vault_lamports_before;
+    if unspent > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.payer.to_account_info(),
+                },
+                sol_vault_signer,
+            ),
+            unspent,
+        )?;
+    }
+
+    // ---- Register the pool's sovereign-token vault ----
+    let (expected_token_vault, _) = Pubkey::find_program_address(
+        &[
+            pda_seeds::POOL_VAULT_SEED,
+            ctx.accounts.pool_state.key().as_ref(),
+            token_key.as_ref(),
+        ],
+        &ctx.accounts.amm_program.key(),
+    );
+    let cpmm_token_vault = if wgor_is_0 {
+        ctx.accounts.cpmm_token_vault_1.key()
+    } else {
+        ctx.accounts.cpmm_token_vault_0.key()
+    };
+    require!(
+        cpmm_token_vault == expected_token_vault,
+        SovereignError::InvalidPoolVault
+    );
+
+    let registry = &mut ctx.accounts.pool_vault_registry;
+    registry.sovereign = sovereign_key;
+    registry.bump = ctx.bumps.pool_vault_registry;
+    registry.register(cpmm_token_vault)?;
+
+    emit!(PoolVaultRegistered {
+        sovereign_id: sovereign.sovereign_id,
+        vault: cpmm_token_vault,
+        registered_by: ctx.accounts.payer.key(),
+    });
+
+    // ---- Step 7: Initialize permanent lock ----
+    // A CPMM position is full range; `liquidity` is its principal in sqrt(k) units
+    let permanent_lock = &mut ctx.accounts.permanent_lock;
+    permanent_lock.sovereign = sovereign_key;
+    permanent_lock.pool_state = ctx.accounts.pool_state.key();
+    permanent_lock.position_mint = ctx.accounts.lp_mint.key();
+    permanent_lock.position = ctx.accounts.lock_lp_account.key();
+    permanent_lock.position_token_account = ctx.accounts.lock_lp_account.key();
+    permanent_lock.liquidity = liquidity;
+    permanent_lock.tick_lower_index = MIN_TICK_INDEX;
+    permanent_lock.tick_upper_index = MAX_TICK_INDEX;
+    permanent_lock.unwound = false;
+    permanent_lock.created_at = clock.unix_timestamp;
+    permanent_lock.bump = ctx.bumps.permanent_lock;
+
+    // ---- Step 8: Update sovereign state ----
+    sovereign.pool_state = ctx.accounts.pool_state.key();
+    // CPMM pools cannot be restricted: anyone can add liquidity, so claim_fees
+    // bounds extraction by the lock's LP share rather than the pool's vaults
+    sovereign.pool_restricted = false;
+    sovereign.total_supply = token_amount;
+    sovereign.trading_opens_at = trading_opens_at;
+    sovereign.position_mint = ctx.accounts.lp_mint.key();
+    sovereign.recovery_target = sovereign.total_deposited;
+    sovereign.total_recovered = 0;
+    sovereign.finalized_at = clock.unix_timestamp;
+    sovereign.state = if sovereign.recovery_target == 0 {
+        SovereignStatus::Active
+    } else {
+        SovereignStatus::Recovery
+    };
+
+    emit!(CpmmPoolCreated {
+        sovereign_id: sovereign.sovereign_id,
+        pool_state: ctx.accounts.pool_state.key(),
+        lp_mint: ctx.accounts.lp_mint.key(),
+        token_mint_0: mint_0,
+        token_mint_1: mint_1,
+        amount_0,
+        amount_1,
+        lp_amount,
+        created_at: clock.unix_timestamp,
+    });
+
+    emit!(SovereignFinalized {
+        sovereign_id: sovereign.sovereign_id,
+        total_deposited: sovereign.total_deposited,
+        token_supply: sovereign.total_supply,
+        lp_tokens,
+        recovery_target: sovereign.recovery_target,
+        finalized_at: clock.unix_timestamp,
+    });
+
+    // ---- Step 9: Creator market buy ----
+    // The creator's escrow (native GOR only) buys tokens through the freshly
+    // created pool in the same transaction, from sol_vault's WGOR account.
+    // Bought tokens stay in creator_token_vault until recovery or unwind.
+    if creator_escrow > 0 {
+        let min_tokens_out = creator_buy_min_out(sovereign, tokens_for_pool, sol_amount, creator_escrow)?;
+
+        #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+        let tokens_received = {
+            quote::pay_out(
+                None,
+                &ctx.accounts.sol_vault.to_account_info(),
+                sol_vault_seeds,
+                &ctx.accounts.vault_quote_account.to_account_info(),
+                None,
+                &ctx.accounts.system_program.to_account_info(),
+                creator_escrow,
+            )?;
+
+            token::sync_native(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                SyncNative {
+                    account: ctx.accounts.vault_quote_account.to_account_info(),
+                },
+            ))?;
+
+            let (input_vault, output_vault) = if wgor_is_0 {
+                (
+                    ctx.accounts.cpmm_token_vault_0.to_account_info(),
+                    ctx.accounts.cpmm_token_vault_1.to_account_info(),
+                )
+            } else {
+                (
+                    ctx.accounts.cpmm_token_vault_1.to_account_info(),
+                    ctx.accounts.cpmm_token_vault_0.to_account_info(),
+                )
+            };
+
+            let swap = crate::amm::AmmSwap {
+                payer: ctx.accounts.sol_vault.to_account_info(),        // sol_vault owns the WGOR account
+                amm_config: ctx.accounts.amm_config.to_account_info(),
+                pool_state: ctx.accounts.pool_state.to_account_info(),
+                input_token_account: ctx.accounts.vault_quote_account.to_account_info(),
+                output_token_account: ctx.accounts.creator_token_vault.to_account_info(),
+                input_vault,
+                output_vault,
+                input_vault_mint: ctx.accounts.wgor_mint.to_account_info(),
+                output_vault_mint: ctx.accounts.token_mint.to_account_info(),
+                observation_state: ctx.accounts.observation_state.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                token_program_2022: ctx.accounts.token_program_2022.to_account_info(),
+                memo_program: ctx.accounts.system_program.to_account_info(), // unused by the CPMM swap
+                extra: vec![ctx.accounts.cpmm_authority.to_account_info()],
+            };
+
+            let tokens_before = ctx.accounts.creator_token_vault.amount;
+
+            crate::amm::adapter(AmmKind::Cpmm).swap_exact_input(
+                &ctx.accounts.amm_program.to_account_info(),
+                swap,
+                creator_escrow,
+                min_tokens_out,
+                sol_vault_signer,
+            )?;
+
+            // Measure the vault delta (net of any Token-2022 transfer fee withheld on the way in)
+            ctx.accounts.creator_token_vault.reload()?;
+            let received = ctx.accounts.creator_token_vault.amount
+                .checked_sub(tokens_before)
+                .ok_or(SovereignError::Underflow)?;
+            require!(received >= min_tokens_out, SovereignError::SlippageExceeded);
+
+            msg!("Creator market buy: {} lamports → {} tokens", creator_escrow, received);
+            Some(received)
+        };
+
+        #[cfg(any(feature = "localnet", feature = "devnet"))]
+        let tokens_received = {
+            // No swap without the AMM: the escrow stays in sol_vault and stays
+            // recorded in creator_escrow (emergency_withdraw_creator returns it)
+            msg!("DEVNET: Skipping creator market buy swap (test mode), {} lamports left in escrow", creator_escrow);
+            let _ = min_tokens_out;
+            emit!(CreatorMarketBuySkipped {
+                sovereign_id: sovereign.sovereign_id,
+                creator: sovereign.creator,
+                sol_amount: creator_escrow,
+            });
+            None
+        };
+
+        if let Some(tokens_received) = tokens_received {
+            let tracker = &mut ctx.accounts.creator_fee_tracker;
+            tracker.purchased_tokens = tokens_received;
+            tracker.tokens_locked = true;
+            tracker.purchased_at = clock.unix_timestamp;
+
+            emit!(CreatorMarketBuyExecuted {
+                sovereign_id: sovereign.sovereign_id,
+                creator: sovereign.creator,
+                sol_amount: creator_escrow,
+                tokens_received,
+            });
+            sovereign.creator_escrow = 0;
+        }
+    }
+
+    // ---- Step 10: Close sol_vault's WGOR account ----
+    // Any unswapped dust + rent back to sol_vault. The sovereign token account
+    // stays open: Token-2022 cannot close it while it holds withheld fees.
+    if quote.is_none() {
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_quote_account.to_account_info(),
+                destination: ctx.accounts.sol_vault.to_account_info(),
+                authority: ctx.accounts.sol_vault.to_account_info(),
+            },
+            sol_vault_signer,
+        ))?;
+    }
+
+    reattach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        sovereign_signer_seeds,
+        hook_detached,
+    )?;
+
+    msg!("Sovereign finalized on CPMM. State: {:?}", sovereign.state);
+    Ok(())
+}
@@ -6,14 +6,18 @@ use anchor_spl::token_interface::{
     TokenInterface,
 };
 use anchor_lang::solana_program::program::invoke_signed;
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
-use crate::events::{ProposalCreated, VoteCast, ProposalFinalized, UnwindExecuted, UnwindClaimed};
-use crate::samm::{self, instructions as samm_ix, cpi as samm_cpi, SammAccountDeserialize};
+use crate::events::{ProposalCreated, VoteCast, ProposalFinalized, ProposalFeeSettled, UnwindExecuted, UnwindClaimed};
+use crate::amm::{self, LockPoolAccounts, LockPosition};
+use super::transfer_hook::{detach_transfer_hook, reattach_transfer_hook};
 
 /// Create an unwind proposal
 /// Authorization is purely via Genesis NFT possession (bearer instrument).
+/// The proposer pays `governance_unwind_fee_lamports` into a per-proposal
+/// escrow, settled in finalize_vote.
 #[derive(Accounts)]
 pub struct ProposeUnwind<'info> {
     /// Current NFT holder (bearer of the position)
@@ -21,6 +25,13 @@ pub struct ProposeUnwind<'info> {
     pub holder: Signer<'info>,
     
     #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+    
+    #[account(
+        mut,
         seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
         bump = sovereign.bump
     )]
@@ -55,6 +66,15 @@ pub struct ProposeUnwind<'info> {
     )]
     pub proposal: Account<'info, Proposal>,
     
+    /// Proposal fee escrow - holds the unwind proposal fee until finalize_vote
+    /// CHECK: PDA that holds SOL
+    #[account(
+        mut,
+        seeds = [PROPOSAL_FEE_ESCROW_SEED, proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_fee_escrow: SystemAccount<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -77,6 +97,21 @@ pub fn propose_unwind_handler(ctx: Context<ProposeUnwind>) -> Result<()> {
         SovereignError::ActiveProposalExists
     );
     
+    // Escrow the proposal fee (anti-spam) - settled when voting is finalized
+    let proposal_fee = ctx.accounts.protocol_state.governance_unwind_fee_lamports;
+    if proposal_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.holder.to_account_info(),
+                    to: ctx.accounts.proposal_fee_escrow.to_account_info(),
+                },
+            ),
+            proposal_fee,
+        )?;
+    }
+    
     // CRITICAL: Set the active proposal flag to prevent multiple proposals
     sovereign.has_active_proposal = true;
     sovereign.active_proposal_id = sovereign.proposal_count;
@@ -93,12 +128,14 @@ pub fn propose_unwind_handler(ctx: Context<ProposeUnwind>) -> Result<()> {
     proposal.total_voted_bps = 0;
     proposal.quorum_bps = QUORUM_BPS;
     proposal.pass_threshold_bps = PASS_THRESHOLD_BPS;
+    proposal.proposal_fee = proposal_fee;
     proposal.bump = ctx.bumps.proposal;
     
     emit!(ProposalCreated {
         sovereign_id: sovereign.sovereign_id,
         proposal_id: proposal.proposal_id,
         proposer: ctx.accounts.holder.key(),
+        proposal_fee,
         created_at: clock.unix_timestamp,
         voting_ends_at: proposal.voting_ends_at,
     });
@@ -220,14 +257,24 @@ pub fn vote_handler(ctx: Context<Vote>, support: bool) -> Result<()> {
 }
 
 /// Finalize voting and determine outcome.
-/// If vote passes, snapshots SAMM pool fee_growth and starts 90-day
+/// If vote passes, snapshots the pool fee_growth and starts 90-day
 /// observation period. Unwind only proceeds if volume stays below threshold.
+/// The proposal fee is refunded to the proposer if quorum was reached,
+/// otherwise it goes to the protocol treasury.
 /// remaining_accounts[0] = pool_state (required when vote passes)
+/// remaining_accounts[1..3] = pool vaults for mint 0 and mint 1 (CPMM only)
 #[derive(Accounts)]
 pub struct FinalizeVote<'info> {
     #[account(mut)]
     pub caller: Signer<'info>,
     
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+    
     #[account(
         mut,
         seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
@@ -247,6 +294,31 @@ pub struct FinalizeVote<'info> {
         bump = permanent_lock.bump
     )]
     pub permanent_lock: Account<'info, PermanentLock>,
+    
+    /// Proposal fee escrow - drained to proposer or treasury
+    /// CHECK: PDA that holds SOL
+    #[account(
+        mut,
+        seeds = [PROPOSAL_FEE_ESCROW_SEED, proposal.key().as_ref()],
+        bump
+    )]
+    pub proposal_fee_escrow: SystemAccount<'info>,
+    
+    /// CHECK: Original proposer — receives the fee refund when quorum is reached
+    #[account(
+        mut,
+        address = proposal.proposer @ SovereignError::Unauthorized
+    )]
+    pub proposer: SystemAccount<'info>,
+    
+    /// CHECK: Protocol treasury — receives the fee when quorum fails
+    #[account(
+        mut,
+        address = protocol_state.treasury @ SovereignError::InvalidTreasury
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 pub fn finalize_vote_handler<'info>(
@@ -274,20 +346,19 @@ pub fn finalize_vote_handler<'info>(
     
     // Check if passed (51% of votes cast)
     let total_votes = proposal.votes_for_bps + proposal.votes_against_bps;
-    let passed = if total_votes > 0 {
-        let for_percentage = (proposal.votes_for_bps as u32 * BPS_DENOMINATOR as u32) / total_votes as u32;
-        quorum_met && for_percentage as u16 >= proposal.pass_threshold_bps
-    } else {
-        false
+    let passed = match (proposal.votes_for_bps * BPS_DENOMINATOR as u32).checked_div(total_votes) {
+        Some(for_percentage) => quorum_met && for_percentage as u16 >= proposal.pass_threshold_bps,
+        None => false,
     };
     
     if passed {
         proposal.status = ProposalStatus::Passed;
         
-        // Snapshot SAMM pool fee_growth and start 90-day observation period
-        // remaining_accounts[0] must be the pool_state matching permanent_lock.pool_state
+        // Snapshot the pool's fee growth and start 90-day observation period
+        // remaining_accounts[0] must be the pool_state matching permanent_lock.pool_state,
+        // followed (CPMM) by the pool vaults for mint 0 and mint 1
         require!(
-            ctx.remaining_accounts.len() >= 1,
+            !ctx.remaining_accounts.is_empty(),
             SovereignError::MissingSAMMAccounts
         );
         let pool_info = &ctx.remaining_accounts[0];
@@ -296,13 +367,11 @@ pub fn finalize_vote_handler<'info>(
             SovereignError::InvalidPool
         );
         
-        let pool_data = pool_info.try_borrow_data()?;
-        let pool = samm::PoolState::try_deserialize(&pool_data)?;
-        drop(pool_data);
-        
         // Snapshot fee_growth at this moment — will be compared at execution time
-        sovereign.fee_growth_snapshot_a = pool.fee_growth_global_0_x64;
-        sovereign.fee_growth_snapshot_b = pool.fee_growth_global_1_x64;
+        let (growth_a, growth_b) = amm::adapter(sovereign.amm_kind)
+            .fee_growth(pool_info, &ctx.remaining_accounts[1..])?;
+        sovereign.fee_growth_snapshot_a = growth_a;
+        sovereign.fee_growth_snapshot_b = growth_b;
         sovereign.activity_check_timestamp = clock.unix_timestamp + UNWIND_OBSERVATION_PERIOD;
         sovereign.activity_check_initiated = true;
         sovereign.activity_check_initiated_at = Some(clock.unix_timestamp);
@@ -325,10 +394,54 @@ pub fn finalize_vote_handler<'info>(
     sovereign.has_active_proposal = false;
     sovereign.proposal_count = sovereign.proposal_count.checked_add(1).unwrap();
     
+    // Settle proposal fee: refund on quorum (pass implies quorum), else treasury
+    let proposal_fee = proposal.proposal_fee;
+    if proposal_fee > 0 {
+        let recipient = if quorum_met {
+            ctx.accounts.proposer.to_account_info()
+        } else {
+            ctx.accounts.treasury.to_account_info()
+        };
+        
+        let proposal_key = proposal.key();
+        let escrow_seeds: &[&[u8]] = &[
+            PROPOSAL_FEE_ESCROW_SEED,
+            proposal_key.as_ref(),
+            &[ctx.bumps.proposal_fee_escrow],
+        ];
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.proposal_fee_escrow.to_account_info(),
+                    to: recipient.clone(),
+                },
+                &[escrow_seeds],
+            ),
+            proposal_fee,
+        )?;
+        
+        if !quorum_met {
+            let protocol = &mut ctx.accounts.protocol_state;
+            protocol.total_fees_collected = protocol.total_fees_collected
+                .checked_add(proposal_fee)
+                .ok_or(SovereignError::Overflow)?;
+        }
+        
+        emit!(ProposalFeeSettled {
+            sovereign_id: sovereign.sovereign_id,
+            proposal_id: proposal.proposal_id,
+            amount: proposal_fee,
+            refunded: quorum_met,
+            recipient: recipient.key(),
+        });
+    }
+    
     emit!(ProposalFinalized {
         sovereign_id: sovereign.sovereign_id,
         proposal_id: proposal.proposal_id,
-        status: proposal.status.clone(),
+        status: proposal.status,
         votes_for: proposal.votes_for_bps as u64,
         votes_against: proposal.votes_against_bps as u64,
         participation_bps,
@@ -385,16 +498,16 @@ pub struct ExecuteUnwind<'info> {
     )]
     pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
     
-    /// CHECK: SAMM position - MUST match permanent_lock.position_mint
+    /// CHECK: AMM position (SAMM NFT mint / CPMM LP mint) - MUST match permanent_lock.position_mint
     #[account(
         mut,
         constraint = position.key() == permanent_lock.position_mint @ SovereignError::InvalidPosition
     )]
     pub position: UncheckedAccount<'info>,
     
-    /// CHECK: Trashbin SAMM program
-    #[account(address = SAMM_PROGRAM_ID)]
-    pub samm_program: UncheckedAccount<'info>,
+    /// CHECK: The sovereign's AMM program
+    #[account(address = sovereign.amm_program @ SovereignError::InvalidAmmProgram)]
+    pub amm_program: UncheckedAccount<'info>,
     
     /// Vault to receive removed liquidity
     /// CHECK: PDA vault
@@ -412,36 +525,62 @@ pub struct ExecuteUnwind<'info> {
     )]
     pub token_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault receiving token quote proceeds (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Treasury's quote token account (receives token quote unwind fee and BYO surplus)
+    #[account(
+        mut,
+        constraint = treasury_quote_account.owner == protocol_state.treasury @ SovereignError::Unauthorized
+    )]
+    pub treasury_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 /// Check volume during observation period. Returns true if threshold met (cancel unwind).
+///
+/// SAMM: global fee growth * position liquidity is exact even for a concentrated range:
+/// unwinds only happen in Recovery, when the pool is restricted and the permanent
+/// lock is its only liquidity, so fees accrue globally only while our range is active.
+/// With an LP ladder, pass the total liquidity: an upper bound, since a rung
+/// earns nothing while the price is outside its band.
+/// CPMM: the growth of sqrt(k) per LP token since the snapshot, valued in quote.
 #[inline(never)]
-fn check_volume_threshold(
-    pool_account: &AccountInfo,
+pub(crate) fn check_volume_threshold(
+    sovereign: &SovereignState,
+    pool: &LockPoolAccounts,
     pool_state_key: Pubkey,
-    snapshot_a: u128,
-    total_deposited: u64,
     position_liquidity: u128,
     threshold_bps: u64,
 ) -> Result<bool> {
     require!(
-        pool_account.key() == pool_state_key,
+        pool.pool_state.key() == pool_state_key,
         SovereignError::InvalidPool
     );
     
-    let pool_data = pool_account.try_borrow_data()?;
-    let pool = samm::PoolState::try_deserialize(&pool_data)?;
-    drop(pool_data);
-    
-    let fee_delta_a = pool.fee_growth_global_0_x64.saturating_sub(snapshot_a);
     let threshold = if threshold_bps == 0 { DEFAULT_UNWIND_VOLUME_THRESHOLD_BPS as u64 } else { threshold_bps };
+    let total_deposited = sovereign.total_deposited;
     
-    // actual_fees = fee_delta * position_liquidity >> 64
-    let actual_fees = ((fee_delta_a as u128)
-        .checked_mul(position_liquidity as u128)
-        .unwrap_or(0)) >> 64;
+    let actual_fees = amm::adapter(sovereign.amm_kind).fees_since(
+        &pool.pool_state,
+        &[pool.token_vault_0.clone(), pool.token_vault_1.clone()],
+        sovereign.fee_growth_snapshot_a,
+        position_liquidity,
+        &sovereign.pool_quote_mint(),
+    )?;
     
     let required_fees = (total_deposited as u128)
         .checked_mul(threshold as u128)
@@ -467,11 +606,15 @@ pub fn execute_unwind_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteU
         SovereignError::InvalidState
     );
     require!(permanent_lock.liquidity > 0, SovereignError::NothingToWithdraw);
+    // LP ladder positions are drained first (unwind_ladder_position) into the
+    // lock's accounts, so the proceeds below include them
+    require!(permanent_lock.is_ladder_unwound(), SovereignError::LadderPositionsOutstanding);
     
     // ============ Step 0: 90-Day Observation Period & Volume Check ============
     // After vote passes, there's a 90-day observation window.
     // If pool fee_growth during this period meets the threshold,
     // the sovereign is still viable → cancel unwind.
+    // Once a ladder position has been drained the unwind is committed.
     
     let observation_ends_at = sovereign.activity_check_timestamp;
     require!(
@@ -479,15 +622,18 @@ pub fn execute_unwind_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteU
         SovereignError::ActivityCheckPeriodNotElapsed
     );
     
-    // Read current fee_growth from SAMM pool to compare against snapshot
-    require!(ctx.remaining_accounts.len() >= 15, SovereignError::MissingSAMMAccounts);
+    // Read current fee_growth from the pool to compare against snapshot
+    let adapter = amm::adapter(sovereign.amm_kind);
+    let position_len = adapter.position_accounts_len();
+    require!(ctx.remaining_accounts.len() >= position_len, SovereignError::MissingSAMMAccounts);
+    let position_accounts = &ctx.remaining_accounts[..position_len];
+    let pool = adapter.pool_accounts(&sovereign.amm_program, position_accounts)?;
     
-    let volume_met = check_volume_threshold(
-        &ctx.remaining_accounts[2],
+    let volume_met = permanent_lock.positions_unwound == 0 && check_volume_threshold(
+        sovereign,
+        &pool,
         permanent_lock.pool_state,
-        sovereign.fee_growth_snapshot_a,
-        sovereign.total_deposited,
-        permanent_lock.liquidity as u128,
+        permanent_lock.total_liquidity(),
         protocol.min_fee_growth_threshold as u64,
     )?;
     
@@ -503,21 +649,34 @@ pub fn execute_unwind_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteU
     
     msg!("Volume below threshold — proceeding with unwind");
     
-    // ============ Step 1: SAMM Liquidity Removal ============
+    let sovereign_key = sovereign.key();
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    let vault_seeds: &[&[u8]] = &[
+        SOL_VAULT_SEED,
+        sovereign_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ];
+    
+    // ============ Step 1: AMM Liquidity Removal ============
     
-    // SECURITY: Pool already validated in check_volume_threshold
+    // SECURITY: Validate pool_state (check_volume_threshold is skipped once committed)
+    require!(
+        pool.pool_state.key() == permanent_lock.pool_state,
+        SovereignError::InvalidPool
+    );
     
-    msg!("Executing unwind via SAMM CPI - removing all liquidity...");
+    msg!("Executing unwind via AMM CPI - removing all liquidity...");
     
-    // Read actual liquidity from SAMM personal position
-    let pp_data = ctx.remaining_accounts[1].try_borrow_data()?;
-    let personal_pos = samm::PersonalPositionState::try_deserialize(&pp_data)?;
-    let actual_liquidity = personal_pos.liquidity;
-    drop(pp_data);
+    // Read actual liquidity from the AMM position
+    let actual_liquidity = adapter.position_liquidity(position_accounts)?;
     
     require!(actual_liquidity > 0, SovereignError::NothingToWithdraw);
     
-    let sovereign_key = sovereign.key();
     let lock_seeds = &[
         PERMANENT_LOCK_SEED,
         sovereign_key.as_ref(),
@@ -525,72 +684,84 @@ pub fn execute_unwind_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteU
     ];
     let lock_signer_seeds = &[&lock_seeds[..]];
     
-    let decrease_accounts = samm_ix::DecreaseLiquidityV2Accounts {
-        nft_owner: permanent_lock.to_account_info(),
-        nft_account: ctx.remaining_accounts[0].clone(),
-        personal_position: ctx.remaining_accounts[1].clone(),
-        pool_state: ctx.remaining_accounts[2].clone(),
-        protocol_position: ctx.remaining_accounts[3].clone(),
-        token_vault_0: ctx.remaining_accounts[4].clone(),
-        token_vault_1: ctx.remaining_accounts[5].clone(),
-        tick_array_lower: ctx.remaining_accounts[6].clone(),
-        tick_array_upper: ctx.remaining_accounts[7].clone(),
-        recipient_token_account_0: ctx.remaining_accounts[8].clone(),
-        recipient_token_account_1: ctx.remaining_accounts[9].clone(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-        token_program_2022: ctx.remaining_accounts[10].clone(),
-        memo_program: ctx.remaining_accounts[11].clone(),
-        vault_0_mint: ctx.remaining_accounts[12].clone(),
-        vault_1_mint: ctx.remaining_accounts[13].clone(),
-        tick_array_bitmap_extension: ctx.remaining_accounts[14].clone(),
-    };
-    
-    let _result = samm_cpi::remove_liquidity(
-        &ctx.accounts.samm_program.to_account_info(),
-        decrease_accounts,
+    // The AMM pays the tokens out with a transfer that would re-enter this program
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds: &[&[u8]] = &[
+        SOVEREIGN_SEED,
+        &sovereign_id_bytes,
+        &[sovereign.bump],
+    ];
+    let hook_detached = detach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        &[sovereign_seeds],
+    )?;
+    adapter.remove_liquidity(
+        &LockPosition {
+            program: ctx.accounts.amm_program.to_account_info(),
+            owner: permanent_lock.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            accounts: position_accounts,
+        },
         actual_liquidity,
-        0, // Min amount 0 for unwind (accept any amount)
-        0,
         lock_signer_seeds,
     )?;
+    reattach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        &[sovereign_seeds],
+        hook_detached,
+    )?;
     
-    msg!("Liquidity removed from SAMM pool");
+    msg!("Liquidity removed from AMM pool");
     
-    // ============ Step 2: Read WGOR ATA balance & close → sol_vault ============
-    let recipient_0_info = &ctx.remaining_accounts[8]; // WGOR ATA
-    let recipient_1_info = &ctx.remaining_accounts[9]; // Token ATA
+    // ============ Step 2: Read WGOR (quote) ATA balance & move it → vault ============
+    let (recipient_0_info, recipient_1_info) = pool.recipients(&sovereign.pool_quote_mint()); // (WGOR (quote) ATA, Token ATA)
     
     let wgor_amount = {
         let data = recipient_0_info.try_borrow_data()?;
         u64::from_le_bytes(data[64..72].try_into().unwrap())
     };
     
-    // Close WGOR ATA → all lamports to sol_vault (unwraps WGOR to native SOL)
-    let close_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
-        program_id: anchor_spl::token::ID,
-        accounts: vec![
-            anchor_lang::solana_program::instruction::AccountMeta::new(
-                recipient_0_info.key(), false,
-            ),
-            anchor_lang::solana_program::instruction::AccountMeta::new(
-                ctx.accounts.sol_vault.key(), false,
-            ),
-            anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
-                permanent_lock.key(), true,
-            ),
-        ],
-        data: vec![9u8], // SPL Token CloseAccount
-    };
-    invoke_signed(
-        &close_wgor_ix,
-        &[
-            recipient_0_info.clone(),
-            ctx.accounts.sol_vault.to_account_info(),
-            permanent_lock.to_account_info(),
-        ],
-        lock_signer_seeds,
-    )?;
-    msg!("WGOR ATA closed → {} WGOR unwrapped to sol_vault", wgor_amount);
+    if let Some(quote) = quote.as_ref() {
+        // Token quote: transfer the quote ATA balance into the quote vault
+        if wgor_amount > 0 {
+            quote.sweep_into_vault(
+                recipient_0_info.clone(),
+                permanent_lock.to_account_info(),
+                lock_signer_seeds,
+                wgor_amount,
+            )?;
+        }
+        msg!("{} quote tokens moved to quote vault", wgor_amount);
+    } else {
+        // Close WGOR ATA → all lamports to sol_vault (unwraps WGOR to native SOL)
+        let close_wgor_ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: anchor_spl::token::ID,
+            accounts: vec![
+                anchor_lang::solana_program::instruction::AccountMeta::new(
+                    recipient_0_info.key(), false,
+                ),
+                anchor_lang::solana_program::instruction::AccountMeta::new(
+                    ctx.accounts.sol_vault.key(), false,
+                ),
+                anchor_lang::solana_program::instruction::AccountMeta::new_readonly(
+                    permanent_lock.key(), true,
+                ),
+            ],
+            data: vec![9u8], // SPL Token CloseAccount
+        };
+        invoke_signed(
+            &close_wgor_ix,
+            &[
+                recipient_0_info.clone(),
+                ctx.accounts.sol_vault.to_account_info(),
+                permanent_lock.to_account_info(),
+            ],
+            lock_signer_seeds,
+        )?;
+        msg!("WGOR ATA closed → {} WGOR unwrapped to sol_vault", wgor_amount);
+    }
     
     // ============ Step 3: Protocol fee — 20% off the top ============
     let fee_bps = protocol.unwind_fee_bps;
@@ -601,21 +772,13 @@ pub fn execute_unwind_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteU
         .ok_or(SovereignError::DivisionByZero)? as u64;
     
     if protocol_fee > 0 {
-        let vault_seeds: &[&[u8]] = &[
-            SOL_VAULT_SEED,
-            sovereign_key.as_ref(),
-            &[ctx.bumps.sol_vault],
-        ];
-        
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.treasury.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.treasury_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             protocol_fee,
         )?;
         msg!("Protocol fee: {} GOR ({}bps) → treasury", protocol_fee, fee_bps);
@@ -653,21 +816,13 @@ pub fn execute_unwind_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteU
             surplus, circulating, sovereign.token_redemption_deadline);
     } else if sovereign.sovereign_type == SovereignType::BYOToken && surplus > 0 {
         // BYO: surplus goes to protocol treasury
-        let vault_seeds: &[&[u8]] = &[
-            SOL_VAULT_SEED,
-            sovereign_key.as_ref(),
-            &[ctx.bumps.sol_vault],
-        ];
-        
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.treasury.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.treasury_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             surplus,
         )?;
         msg!("BYO surplus: {} GOR → treasury", surplus);
@@ -754,6 +909,24 @@ pub struct ClaimUnwind<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Holder's quote token account (receives token quote payouts)
+    #[account(mut)]
+    pub holder_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -795,11 +968,9 @@ pub fn claim_unwind_handler(ctx: Context<ClaimUnwind>) -> Result<()> {
         SovereignError::NoDeposits
     );
     
-    // Calculate claimer's share using safe arithmetic
-    let share_bps = deposit_record.amount
-        .checked_mul(BPS_DENOMINATOR as u64)
-        .ok_or(SovereignError::Overflow)?
-        .checked_div(sovereign.total_deposited)
+    // Calculate claimer's share of weighted share units using safe arithmetic
+    let share_bps = sovereign
+        .share_bps(deposit_record.share_units)
         .ok_or(SovereignError::DivisionByZero)?;
     
     // Calculate GOR share from unwind SOL balance, capped at original deposit
@@ -810,8 +981,14 @@ pub fn claim_unwind_handler(ctx: Context<ClaimUnwind>) -> Result<()> {
         .ok_or(SovereignError::DivisionByZero)?
         .min(deposit_record.amount);
     
-    // Transfer GOR from sol_vault to holder
+    // Transfer GOR (or token quote) from the vault to holder
     if sol_share > 0 {
+        let quote = QuoteToken::resolve(
+            sovereign,
+            ctx.accounts.quote_mint.as_deref(),
+            ctx.accounts.quote_vault.as_deref(),
+            ctx.accounts.quote_token_program.as_ref(),
+        )?;
         let sovereign_key = sovereign.key();
         let vault_seeds: &[&[u8]] = &[
             SOL_VAULT_SEED,
@@ -819,15 +996,13 @@ pub fn claim_unwind_handler(ctx: Context<ClaimUnwind>) -> Result<()> {
             &[ctx.bumps.sol_vault],
         ];
         
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.sol_vault.to_account_info(),
-                    to: ctx.accounts.holder.to_account_info(),
-                },
-                &[vault_seeds],
-            ),
+        quote::pay_out(
+            quote.as_ref(),
+            &ctx.accounts.sol_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.holder.to_account_info(),
+            ctx.accounts.holder_quote_account.as_ref().map(|a| a.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
             sol_share,
         )?;
     }
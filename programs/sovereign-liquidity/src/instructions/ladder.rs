@@ -0,0 +1,1011 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, SyncNative};
+use anchor_spl::token_interface::{
+    self,
+    Approve,
+    CloseAccount,
+    Mint as MintInterface,
+    Revoke,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+    TransferChecked,
+};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::quote::{self, QuoteToken};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::SovereignError;
+use crate::events::{
+    LadderPositionOpened, LadderFeesCollected, LadderPositionUnwound, LadderProceedsRefunded,
+};
+use crate::samm::{self, instructions as samm_ix, cpi as samm_cpi, SammAccountDeserialize};
+use crate::amm::{AmmAdapter, SammAdapter};
+use super::finalize::{check_existing_pool_price, create_lock_token_accounts};
+use super::governance::check_volume_threshold;
+use super::transfer_hook::{detach_transfer_hook, reattach_transfer_hook};
+
+// ============================================================
+// LP LADDER
+// ============================================================
+//
+// A sovereign can split its raise across several SAMM positions: the primary
+// position recorded on the PermanentLock, plus up to MAX_LP_LADDER_RUNGS rungs
+// (e.g. a tight band plus a full-range backstop), each recorded on its own
+// LockedPosition child account. All position NFTs are held by the
+// permanent_lock PDA.
+//
+// - open_ladder_position:   PoolCreated, one rung per call, before finalize_add_liquidity
+// - collect_ladder_fees:    Recovery/Active, fees land in the lock's accounts and are
+//                           distributed by the next claim_fees
+// - unwind_ladder_position: Unwinding/EmergencyUnlocked/Failed, drains a rung into the
+//                           lock's accounts; execute_unwind / emergency_remove_liquidity
+//                           run once every rung is drained
+// - refund_ladder_proceeds: Failed (stalled finalization), returns the drained rungs
+//                           to the sovereign's vaults for withdraw_failed
+
+/// Token account balance (0 if the account is not initialized)
+fn token_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(if data.len() >= 72 {
+        u64::from_le_bytes(data[64..72].try_into().unwrap())
+    } else {
+        0
+    })
+}
+
+/// Require a token account to be owned by the permanent lock
+fn require_lock_owned(account: &AccountInfo, permanent_lock: &Pubkey) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() >= 64 && data[32..64] == permanent_lock.to_bytes(),
+        SovereignError::InvalidLadderRecipient
+    );
+    Ok(())
+}
+
+/// Validate the SAMM accounts of a ladder position and build the
+/// decrease_liquidity_v2 accounts (same remaining_accounts layout as claim_fees)
+fn ladder_decrease_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    permanent_lock: &Account<'info, PermanentLock>,
+    locked_position: &LockedPosition,
+    token_program: AccountInfo<'info>,
+) -> Result<samm_ix::DecreaseLiquidityV2Accounts<'info>> {
+    require!(remaining_accounts.len() >= 15, SovereignError::MissingSAMMAccounts);
+    require!(
+        remaining_accounts[2].key() == permanent_lock.pool_state,
+        SovereignError::InvalidPool
+    );
+    require!(
+        remaining_accounts[0].key() == locked_position.position_token_account
+            && remaining_accounts[1].key() == locked_position.position,
+        SovereignError::InvalidLockedPosition
+    );
+    require_lock_owned(&remaining_accounts[8], &permanent_lock.key())?;
+    require_lock_owned(&remaining_accounts[9], &permanent_lock.key())?;
+
+    Ok(samm_ix::DecreaseLiquidityV2Accounts {
+        nft_owner: permanent_lock.to_account_info(),
+        nft_account: remaining_accounts[0].clone(),
+        personal_position: remaining_accounts[1].clone(),
+        pool_state: remaining_accounts[2].clone(),
+        protocol_position: remaining_accounts[3].clone(),
+        token_vault_0: remaining_accounts[4].clone(),
+        token_vault_1: remaining_accounts[5].clone(),
+        tick_array_lower: remaining_accounts[6].clone(),
+        tick_array_upper: remaining_accounts[7].clone(),
+        recipient_token_account_0: remaining_accounts[8].clone(),
+        recipient_token_account_1: remaining_accounts[9].clone(),
+        token_program,
+        token_program_2022: remaining_accounts[10].clone(),
+        memo_program: remaining_accounts[11].clone(),
+        vault_0_mint: remaining_accounts[12].clone(),
+        vault_1_mint: remaining_accounts[13].clone(),
+        tick_array_bitmap_extension: remaining_accounts[14].clone(),
+    })
+}
+
+/// The sovereign token mint among the position's vault mints [12]/[13]
+/// (passed writable so the transfer hook can be detached while SAMM pays out)
+fn ladder_token_mint<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    sovereign: &SovereignState,
+) -> Result<&'a AccountInfo<'info>> {
+    remaining_accounts[12..14]
+        .iter()
+        .find(|mint| mint.key() == sovereign.token_mint)
+        .ok_or_else(|| error!(SovereignError::InvalidMint))
+}
+
+// ============================================================
+// OPEN LADDER POSITION
+// ============================================================
+
+/// Open LP ladder rung `index` as its own SAMM position.
+/// Rungs open in order while the sovereign is `PoolCreated`; finalize_add_liquidity
+/// then opens the primary position with what they leave.
+///
+/// Each rung takes share_bps of the raise (both sides), at the pool's live price,
+/// over its own band. Accounts mirror finalize_add_liquidity: the caller provides a
+/// fresh position NFT mint keypair; the lock's ATAs are created here if missing.
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct OpenLadderPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+
+    /// Token mint for the sovereign token (Token-2022)
+    /// Mutable so the transfer hook can be detached while the program moves tokens
+    #[account(mut, address = sovereign.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// Quote side of the pool: WGOR native mint, or the sovereign's token quote mint
+    #[account(address = sovereign.pool_quote_mint() @ SovereignError::InvalidQuoteMint)]
+    pub wgor_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// SOL vault holding deposits
+    /// CHECK: PDA that holds SOL
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
+    /// Sovereign's token vault (Token-2022)
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Permanent lock PDA (initialized by the first rung)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PermanentLock::LEN,
+        seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub permanent_lock: Box<Account<'info, PermanentLock>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = LockedPosition::LEN,
+        seeds = [LOCKED_POSITION_SEED, permanent_lock.key().as_ref(), &[index]],
+        bump
+    )]
+    pub locked_position: Box<Account<'info, LockedPosition>>,
+
+    /// WGOR (or token quote) ATA of permanent_lock PDA
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub lock_wgor_account: UncheckedAccount<'info>,
+
+    /// Sovereign token ATA of permanent_lock PDA (Token-2022)
+    /// CHECK: Created idempotently through the ATA program, which checks the address
+    #[account(mut)]
+    pub lock_token_account: UncheckedAccount<'info>,
+
+    // ---- SAMM Accounts ----
+
+    /// CHECK: Trashbin SAMM program
+    #[account(address = SAMM_PROGRAM_ID)]
+    pub samm_program: UncheckedAccount<'info>,
+
+    /// CHECK: Pool state (must match sovereign.pool_state)
+    #[account(
+        mut,
+        address = sovereign.pool_state
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// Position NFT mint - fresh Keypair created by frontend
+    #[account(mut)]
+    pub position_nft_mint: Signer<'info>,
+
+    /// CHECK: ATA of permanent_lock for position_nft_mint (created by SAMM)
+    #[account(mut)]
+    pub position_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex metadata account for position NFT
+    #[account(mut)]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol position state PDA on SAMM
+    #[account(mut)]
+    pub protocol_position: UncheckedAccount<'info>,
+
+    /// CHECK: Lower tick array PDA on SAMM
+    #[account(mut)]
+    pub tick_array_lower: UncheckedAccount<'info>,
+
+    /// CHECK: Upper tick array PDA on SAMM
+    #[account(mut)]
+    pub tick_array_upper: UncheckedAccount<'info>,
+
+    /// CHECK: Personal position state PDA on SAMM
+    #[account(mut)]
+    pub personal_position: UncheckedAccount<'info>,
+
+    /// CHECK: SAMM pool token vault 0
+    #[account(mut)]
+    pub samm_token_vault_0: UncheckedAccount<'info>,
+
+    /// CHECK: SAMM pool token vault 1
+    #[account(mut)]
+    pub samm_token_vault_1: UncheckedAccount<'info>,
+
+    /// CHECK: Vault 0 mint (for SAMM validation)
+    pub vault_0_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Vault 1 mint (for SAMM validation)
+    pub vault_1_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Tick array bitmap extension PDA on SAMM
+    #[account(mut)]
+    pub tick_array_bitmap_extension: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = METAPLEX_PROGRAM_ID)]
+    pub metadata_program: UncheckedAccount<'info>,
+
+    // ---- Standard Programs ----
+
+    /// Legacy SPL Token program (for WGOR)
+    pub token_program: Program<'info, Token>,
+
+    /// Token-2022 program (for sovereign token)
+    pub token_program_2022: Interface<'info, TokenInterface>,
+
+    /// Token program of a token quote mint (required for token quote sovereigns)
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn open_ladder_position_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OpenLadderPosition<'info>>,
+    index: u8,
+) -> Result<()> {
+    let sovereign_key = ctx.accounts.sovereign.key();
+    let sovereign = &ctx.accounts.sovereign;
+    let clock = Clock::get()?;
+
+    // ---- Validations ----
+    require!(!ctx.accounts.protocol_state.paused, SovereignError::ProtocolPaused);
+    require!(
+        sovereign.state == SovereignStatus::PoolCreated,
+        SovereignError::InvalidState
+    );
+    require!(
+        ctx.accounts.permanent_lock.is_next_rung(index, sovereign.ladder_rung_count()),
+        SovereignError::InvalidLadderIndex
+    );
+
+    let quote = QuoteToken::resolve(
+        sovereign,
+        Some(&ctx.accounts.wgor_mint),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    let quote_program_info = match quote.as_ref() {
+        Some(quote) => quote.token_program.clone(),
+        None => ctx.accounts.token_program.to_account_info(),
+    };
+
+    // ---- Calculate amounts ----
+    // The rung's share of what is still undeployed, so earlier rungs' rounding
+    // never eats into later ones and the primary position keeps the rest
+    let rung = sovereign.lp_ladder[index as usize];
+    let available_quote = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?
+        .checked_sub(sovereign.creator_escrow)
+        .ok_or(SovereignError::Underflow)?;
+    let available_tokens = if sovereign.sovereign_type == SovereignType::TokenLaunch {
+        (ctx.accounts.token_vault.amount as u128 * LP_ALLOCATION_BPS as u128
+            / BPS_DENOMINATOR as u128) as u64
+    } else {
+        ctx.accounts.token_vault.amount
+    };
+    let quote_amount = sovereign.ladder_rung_amount(index, available_quote);
+    let lp_tokens = sovereign.ladder_rung_amount(index, available_tokens);
+
+    msg!("Ladder rung {}: {} quote, {} tokens", index, quote_amount, lp_tokens);
+
+    // ---- Move the rung's share into the lock's accounts ----
+    create_lock_token_accounts(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.permanent_lock.to_account_info(),
+        &ctx.accounts.wgor_mint.to_account_info(),
+        &ctx.accounts.lock_wgor_account.to_account_info(),
+        &quote_program_info,
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.lock_token_account.to_account_info(),
+        &ctx.accounts.token_program_2022.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let sol_vault_seeds: &[&[u8]] = &[
+        SOL_VAULT_SEED,
+        sovereign_key.as_ref(),
+        &[ctx.bumps.sol_vault],
+    ];
+    quote::pay_out(
+        quote.as_ref(),
+        &ctx.accounts.sol_vault.to_account_info(),
+        sol_vault_seeds,
+        &ctx.accounts.lock_wgor_account.to_account_info(),
+        Some(ctx.accounts.lock_wgor_account.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
+        quote_amount,
+    )?;
+    if quote.is_none() {
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.lock_wgor_account.to_account_info(),
+            },
+        ))?;
+    }
+
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds: &[&[u8]] = &[SOVEREIGN_SEED, &sovereign_id_bytes, &[sovereign.bump]];
+    // Token-2022 would re-enter this program through the hook on every token move below
+    let hook_detached = detach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        &[sovereign_seeds],
+    )?;
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program_2022.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.token_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.lock_token_account.to_account_info(),
+                authority: sovereign.to_account_info(),
+            },
+            &[sovereign_seeds],
+        ),
+        lp_tokens,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Usable tokens net of the Token-2022 transfer fee (ceiling, as Token-2022)
+    let transfer_fee = (lp_tokens as u128 * sovereign.sell_fee_bps as u128)
+        .div_ceil(BPS_DENOMINATOR as u128) as u64;
+    let tokens_in_lock = lp_tokens.checked_sub(transfer_fee).ok_or(SovereignError::Underflow)?;
+
+    // ---- Plan the position at the pool's live price ----
+    let (_, _, is_swapped) = samm_cpi::sort_mints(
+        &ctx.accounts.wgor_mint.key(),
+        &ctx.accounts.token_mint.key(),
+    );
+    let wgor_is_0 = !is_swapped;
+
+    let implied_sqrt_price_x64 = if wgor_is_0 {
+        samm::tick_math::sqrt_price_x64_from_amounts(quote_amount, tokens_in_lock)?
+    } else {
+        samm::tick_math::sqrt_price_x64_from_amounts(tokens_in_lock, quote_amount)?
+    };
+    #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+    let sqrt_price_x64 = {
+        let pool_data = ctx.accounts.pool_state.try_borrow_data()?;
+        samm::PoolState::try_deserialize(&pool_data)?.sqrt_price_x64
+    };
+    #[cfg(any(feature = "localnet", feature = "devnet"))]
+    let sqrt_price_x64 = implied_sqrt_price_x64;
+    if sovereign.uses_existing_pool() {
+        check_existing_pool_price(sqrt_price_x64, implied_sqrt_price_x64)?;
+    }
+    let plan = samm_cpi::plan_liquidity(
+        sqrt_price_x64,
+        rung.band_bps,
+        wgor_is_0,
+        quote_amount,
+        tokens_in_lock,
+    )?;
+
+    // ---- Open the position ----
+    // As in finalize_add_liquidity: the wallet payer pays rent and moves the
+    // lock's tokens as a delegate, approved only for this CPI
+    let lock_bump = ctx.bumps.permanent_lock;
+    let lock_seeds: &[&[u8]] = &[PERMANENT_LOCK_SEED, sovereign_key.as_ref(), &[lock_bump]];
+    for (program, account, amount) in [
+        (quote_program_info.clone(), ctx.accounts.lock_wgor_account.to_account_info(), quote_amount),
+        (ctx.accounts.token_program_2022.to_account_info(), ctx.accounts.lock_token_account.to_account_info(), tokens_in_lock),
+    ] {
+        token_interface::approve(
+            CpiContext::new_with_signer(
+                program,
+                Approve {
+                    to: account,
+                    delegate: ctx.accounts.payer.to_account_info(),
+                    authority: ctx.accounts.permanent_lock.to_account_info(),
+                },
+                &[lock_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    let (token_account_0, token_account_1) = if wgor_is_0 {
+        (ctx.accounts.lock_wgor_account.to_account_info(), ctx.accounts.lock_token_account.to_account_info())
+    } else {
+        (ctx.accounts.lock_token_account.to_account_info(), ctx.accounts.lock_wgor_account.to_account_info())
+    };
+
+    #[cfg(not(any(feature = "localnet", feature = "devnet")))]
+    {
+        let open_position_accounts = samm_ix::OpenPositionV2Accounts {
+            payer: ctx.accounts.payer.to_account_info(),
+            position_nft_owner: ctx.accounts.permanent_lock.to_account_info(),
+            position_nft_mint: ctx.accounts.position_nft_mint.to_account_info(),
+            position_nft_account: ctx.accounts.position_nft_account.to_account_info(),
+            metadata_account: ctx.accounts.metadata_account.to_account_info(),
+            pool_state: ctx.accounts.pool_state.to_account_info(),
+            protocol_position: ctx.accounts.protocol_position.to_account_info(),
+            tick_array_lower: ctx.accounts.tick_array_lower.to_account_info(),
+            tick_array_upper: ctx.accounts.tick_array_upper.to_account_info(),
+            personal_position: ctx.accounts.personal_position.to_account_info(),
+            token_account_0,
+            token_account_1,
+            token_vault_0: ctx.accounts.samm_token_vault_0.to_account_info(),
+            token_vault_1: ctx.accounts.samm_token_vault_1.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            metadata_program: ctx.accounts.metadata_program.to_account_info(),
+            token_program_2022: ctx.accounts.token_program_2022.to_account_info(),
+            vault_0_mint: ctx.accounts.vault_0_mint.to_account_info(),
+            vault_1_mint: ctx.accounts.vault_1_mint.to_account_info(),
+            tick_array_bitmap_extension: ctx.accounts.tick_array_bitmap_extension.to_account_info(),
+        };
+
+        samm_cpi::open_position(
+            &ctx.accounts.samm_program.to_account_info(),
+            open_position_accounts,
+            plan.tick_lower,
+            plan.tick_upper,
+            0, // let SAMM compute liquidity
+            plan.amount_0_max,
+            plan.amount_1_max,
+            samm::tick::DEFAULT_TICK_SPACING,
+            plan.base_flag,
+            &[], // wallet + position_nft_mint are already outer tx signers
+        )?;
+    }
+
+    #[cfg(any(feature = "localnet", feature = "devnet"))]
+    {
+        msg!("DEVNET: Skipping SAMM open_position CPI (test mode)");
+        let _ = (token_account_0, token_account_1);
+    }
+
+    for (program, account) in [
+        (quote_program_info, ctx.accounts.lock_wgor_account.to_account_info()),
+        (ctx.accounts.token_program_2022.to_account_info(), ctx.accounts.lock_token_account.to_account_info()),
+    ] {
+        token_interface::revoke(CpiContext::new_with_signer(
+            program,
+            Revoke {
+                source: account,
+                authority: ctx.accounts.permanent_lock.to_account_info(),
+            },
+            &[lock_seeds],
+        ))?;
+    }
+    reattach_transfer_hook(
+        &ctx.accounts.token_mint.to_account_info(),
+        &sovereign.to_account_info(),
+        &[sovereign_seeds],
+        hook_detached,
+    )?;
+
+    // ---- Record the position ----
+    let (position_pda, _) = Pubkey::find_program_address(
+        &[SAMM_POSITION_SEED, ctx.accounts.position_nft_mint.key().as_ref()],
+        &SAMM_PROGRAM_ID,
+    );
+
+    let locked_position = &mut ctx.accounts.locked_position;
+    locked_position.permanent_lock = ctx.accounts.permanent_lock.key();
+    locked_position.index = index;
+    locked_position.position_mint = ctx.accounts.position_nft_mint.key();
+    locked_position.position = position_pda;
+    locked_position.position_token_account = ctx.accounts.position_nft_account.key();
+    locked_position.liquidity = plan.liquidity;
+    locked_position.tick_lower_index = plan.tick_lower;
+    locked_position.tick_upper_index = plan.tick_upper;
+    locked_position.created_at = clock.unix_timestamp;
+    locked_position.bump = ctx.bumps.locked_position;
+
+    let permanent_lock = &mut ctx.accounts.permanent_lock;
+    permanent_lock.sovereign = sovereign_key;
+    permanent_lock.pool_state = sovereign.pool_state;
+    permanent_lock.bump = lock_bump;
+    permanent_lock.record_rung_opened(plan.liquidity, plan.tick_lower, plan.tick_upper)?;
+
+    emit!(LadderPositionOpened {
+        sovereign_id: sovereign.sovereign_id,
+        index,
+        position_nft_mint: ctx.accounts.position_nft_mint.key(),
+        liquidity: plan.liquidity,
+        quote_amount,
+        token_amount: tokens_in_lock,
+        tick_lower_index: plan.tick_lower,
+        tick_upper_index: plan.tick_upper,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// COLLECT LADDER FEES
+// ============================================================
+
+/// Collect the trading fees of an LP ladder position into the permanent lock's
+/// quote and token accounts. Permissionless; the next claim_fees distributes them
+/// along with the primary position's fees.
+///
+/// remaining_accounts: same layout as claim_fees [0..14], for this position
+/// (the sovereign token mint among [12]/[13] writable)
+#[derive(Accounts)]
+pub struct CollectLadderFees<'info> {
+    pub claimer: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Account<'info, SovereignState>,
+
+    #[account(
+        seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
+        bump = permanent_lock.bump
+    )]
+    pub permanent_lock: Account<'info, PermanentLock>,
+
+    #[account(
+        seeds = [LOCKED_POSITION_SEED, permanent_lock.key().as_ref(), &[locked_position.index]],
+        bump = locked_position.bump
+    )]
+    pub locked_position: Account<'info, LockedPosition>,
+
+    /// CHECK: Trashbin SAMM program
+    #[account(address = SAMM_PROGRAM_ID)]
+    pub samm_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn collect_ladder_fees_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectLadderFees<'info>>,
+) -> Result<()> {
+    let sovereign = &ctx.accounts.sovereign;
+    let locked_position = &ctx.accounts.locked_position;
+
+    require!(!ctx.accounts.protocol_state.paused, SovereignError::ProtocolPaused);
+    require!(
+        sovereign.state == SovereignStatus::Recovery ||
+        sovereign.state == SovereignStatus::Active,
+        SovereignError::InvalidState
+    );
+    require!(!locked_position.unwound, SovereignError::NothingToWithdraw);
+
+    let decrease_accounts = ladder_decrease_accounts(
+        ctx.remaining_accounts,
+        &ctx.accounts.permanent_lock,
+        locked_position,
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    let wgor_is_0 = ctx.remaining_accounts[12].key() == sovereign.pool_quote_mint();
+    let (quote_idx, token_idx) = if wgor_is_0 { (8, 9) } else { (9, 8) };
+    let quote_before = token_balance(&ctx.remaining_accounts[quote_idx])?;
+    let token_before = token_balance(&ctx.remaining_accounts[token_idx])?;
+
+    let sovereign_key = sovereign.key();
+    let lock_seeds: &[&[u8]] = &[
+        PERMANENT_LOCK_SEED,
+        sovereign_key.as_ref(),
+        &[ctx.accounts.permanent_lock.bump],
+    ];
+    let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+    let sovereign_seeds: &[&[u8]] = &[SOVEREIGN_SEED, &sovereign_id_bytes, &[sovereign.bump]];
+    let token_mint = ladder_token_mint(ctx.remaining_accounts, sovereign)?;
+    let hook_detached = detach_transfer_hook(token_mint, &sovereign.to_account_info(), &[sovereign_seeds])?;
+    samm_cpi::collect_fees(
+        &ctx.accounts.samm_program.to_account_info(),
+        decrease_accounts,
+        &[lock_seeds],
+    )?;
+    reattach_transfer_hook(token_mint, &sovereign.to_account_info(), &[sovereign_seeds], hook_detached)?;
+
+    let quote_amount = token_balance(&ctx.remaining_accounts[quote_idx])?.saturating_sub(quote_before);
+    let token_amount = token_balance(&ctx.remaining_accounts[token_idx])?.saturating_sub(token_before);
+
+    msg!("Ladder rung {} fees: {} quote, {} tokens → permanent lock", locked_position.index, quote_amount, token_amount);
+
+    emit!(LadderFeesCollected {
+        sovereign_id: sovereign.sovereign_id,
+        index: locked_position.index,
+        quote_amount,
+        token_amount,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// UNWIND LADDER POSITION
+// ============================================================
+
+/// Remove all liquidity of an LP ladder position into the permanent lock's
+/// quote and token accounts, ahead of execute_unwind or emergency_remove_liquidity.
+///
+/// - Unwinding: permissionless once the observation period has ended. The first
+///   rung re-checks the volume threshold; after that the unwind is committed.
+/// - EmergencyUnlocked: protocol authority only.
+/// - Failed (stalled finalization): permissionless, ahead of refund_ladder_proceeds.
+///
+/// remaining_accounts: same layout as execute_unwind [0..14], for this position
+/// (the sovereign token mint among [12]/[13] writable)
+#[derive(Accounts)]
+pub struct UnwindLadderPosition<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED],
+        bump = protocol_state.bump
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+
+    #[account(
+        mut,
+        seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
+        bump = permanent_lock.bump
+    )]
+    pub permanent_lock: Box<Account<'info, PermanentLock>>,
+
+    #[account(
+        mut,
+        seeds = [LOCKED_POSITION_SEED, permanent_lock.key().as_ref(), &[locked_position.index]],
+        bump = locked_position.bump
+    )]
+    pub locked_position: Box<Account<'info, LockedPosition>>,
+
+    /// CHECK: Trashbin SAMM program
+    #[account(address = SAMM_PROGRAM_ID)]
+    pub samm_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn unwind_ladder_position_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UnwindLadderPosition<'info>>,
+) -> Result<()> {
+    let sovereign = &ctx.accounts.sovereign;
+    let protocol = &ctx.accounts.protocol_state;
+    let clock = Clock::get()?;
+
+    require!(!ctx.accounts.locked_position.unwound, SovereignError::NothingToWithdraw);
+
+    match sovereign.state {
+        SovereignStatus::Unwinding => {
+            require!(
+                clock.unix_timestamp >= sovereign.activity_check_timestamp,
+                SovereignError::ActivityCheckPeriodNotElapsed
+            );
+            require!(ctx.remaining_accounts.len() >= 15, SovereignError::MissingSAMMAccounts);
+
+            // Same check as execute_unwind; once a rung is drained it is not repeated
+            if ctx.accounts.permanent_lock.positions_unwound == 0 {
+                let volume_met = check_volume_threshold(
+                    sovereign,
+                    &SammAdapter.pool_accounts(&SAMM_PROGRAM_ID, ctx.remaining_accounts)?,
+                    ctx.accounts.permanent_lock.pool_state,
+                    ctx.accounts.permanent_lock.total_liquidity(),
+                    protocol.min_fee_growth_threshold as u64,
+                )?;
+                require!(!volume_met, SovereignError::UnwindVolumeThresholdMet);
+            }
+        }
+        SovereignStatus::EmergencyUnlocked => {
+            require!(
+                ctx.accounts.caller.key() == protocol.authority,
+                SovereignError::Unauthorized
+            );
+        }
+        SovereignStatus::Failed => {}
+        _ => return err!(SovereignError::InvalidState),
+    }
+
+    let decrease_accounts = ladder_decrease_accounts(
+        ctx.remaining_accounts,
+        &ctx.accounts.permanent_lock,
+        &ctx.accounts.locked_position,
+        ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    // Read actual liquidity from the SAMM personal position
+    let actual_liquidity = {
+        let pp_data = ctx.remaining_accounts[1].try_borrow_data()?;
+        samm::PersonalPositionState::try_deserialize(&pp_data)?.liquidity
+    };
+
+    let wgor_is_0 = ctx.remaining_accounts[12].key() == sovereign.pool_quote_mint();
+    let (quote_idx, token_idx) = if wgor_is_0 { (8, 9) } else { (9, 8) };
+    let quote_before = token_balance(&ctx.remaining_accounts[quote_idx])?;
+    let token_before = token_balance(&ctx.remaining_accounts[token_idx])?;
+
+    if actual_liquidity > 0 {
+        let sovereign_key = sovereign.key();
+        let lock_seeds: &[&[u8]] = &[
+            PERMANENT_LOCK_SEED,
+            sovereign_key.as_ref(),
+            &[ctx.accounts.permanent_lock.bump],
+        ];
+        let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+        let sovereign_seeds: &[&[u8]] = &[SOVEREIGN_SEED, &sovereign_id_bytes, &[sovereign.bump]];
+        let token_mint = ladder_token_mint(ctx.remaining_accounts, sovereign)?;
+        let hook_detached = detach_transfer_hook(token_mint, &sovereign.to_account_info(), &[sovereign_seeds])?;
+        samm_cpi::remove_liquidity(
+            &ctx.accounts.samm_program.to_account_info(),
+            decrease_accounts,
+            actual_liquidity,
+            0, // Min amount 0 for unwind (accept any amount)
+            0,
+            &[lock_seeds],
+        )?;
+        reattach_transfer_hook(token_mint, &sovereign.to_account_info(), &[sovereign_seeds], hook_detached)?;
+    }
+
+    let quote_amount = token_balance(&ctx.remaining_accounts[quote_idx])?.saturating_sub(quote_before);
+    let token_amount = token_balance(&ctx.remaining_accounts[token_idx])?.saturating_sub(token_before);
+
+    let locked_position = &mut ctx.accounts.locked_position;
+    locked_position.record_unwound(clock.unix_timestamp);
+
+    let permanent_lock = &mut ctx.accounts.permanent_lock;
+    permanent_lock.record_rung_unwound(locked_position.index)?;
+
+    msg!("Ladder rung {} unwound: {} quote, {} tokens → permanent lock ({}/{} rungs)",
+        locked_position.index, quote_amount, token_amount,
+        permanent_lock.positions_unwound, permanent_lock.position_count);
+
+    emit!(LadderPositionUnwound {
+        sovereign_id: sovereign.sovereign_id,
+        index: locked_position.index,
+        liquidity: actual_liquidity,
+        quote_amount,
+        token_amount,
+    });
+
+    Ok(())
+}
+
+// ============================================================
+// REFUND LADDER PROCEEDS
+// ============================================================
+
+/// Return drained LP ladder positions to the sovereign's vaults after a stalled
+/// finalization (or an emergency unlock before finalization), so depositors can
+/// be refunded from the vault. Permissionless once every rung is unwound.
+///
+/// Native: the lock's WGOR account is closed into sol_vault.
+/// Token quote: the lock's quote balance moves back to quote_vault.
+/// Sovereign tokens move back to token_vault.
+#[derive(Accounts)]
+pub struct RefundLadderProceeds<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [SOVEREIGN_SEED, &sovereign.sovereign_id.to_le_bytes()],
+        bump = sovereign.bump
+    )]
+    pub sovereign: Box<Account<'info, SovereignState>>,
+
+    #[account(
+        mut,
+        seeds = [PERMANENT_LOCK_SEED, sovereign.key().as_ref()],
+        bump = permanent_lock.bump
+    )]
+    pub permanent_lock: Box<Account<'info, PermanentLock>>,
+
+    /// Token mint for the sovereign token (Token-2022)
+    /// Mutable so the transfer hook can be detached while the program moves tokens
+    #[account(mut, address = sovereign.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// Quote side of the pool: WGOR native mint, or the sovereign's token quote mint
+    #[account(address = sovereign.pool_quote_mint() @ SovereignError::InvalidQuoteMint)]
+    pub wgor_mint: Box<InterfaceAccount<'info, MintInterface>>,
+
+    /// CHECK: SOL vault PDA (receives the lamports of the closed WGOR account)
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+
+    /// Sovereign's token vault (Token-2022)
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// WGOR (or token quote) account owned by permanent_lock PDA
+    #[account(
+        mut,
+        token::mint = wgor_mint,
+        token::authority = permanent_lock,
+    )]
+    pub lock_wgor_account: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Sovereign token account owned by permanent_lock PDA (Token-2022)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = permanent_lock,
+    )]
+    pub lock_token_account: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Legacy SPL Token program (for WGOR)
+    pub token_program: Program<'info, Token>,
+
+    /// Token-2022 program (for sovereign token)
+    pub token_program_2022: Interface<'info, TokenInterface>,
+
+    /// Token program of a token quote mint (required for token quote sovereigns)
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn refund_ladder_proceeds_handler(ctx: Context<RefundLadderProceeds>) -> Result<()> {
+    let sovereign = &ctx.accounts.sovereign;
+    let permanent_lock = &ctx.accounts.permanent_lock;
+
+    // Only while the raise is being refunded, never after finalization
+    require!(
+        sovereign.state == SovereignStatus::Failed
+            || (sovereign.state == SovereignStatus::EmergencyUnlocked && sovereign.finalized_at == 0),
+        SovereignError::InvalidState
+    );
+    require!(permanent_lock.is_ladder_unwound(), SovereignError::LadderPositionsOutstanding);
+
+    let quote_amount = ctx.accounts.lock_wgor_account.amount;
+    let token_amount = ctx.accounts.lock_token_account.amount;
+    require!(quote_amount > 0 || token_amount > 0, SovereignError::NothingToWithdraw);
+
+    let quote = QuoteToken::resolve(
+        sovereign,
+        Some(&ctx.accounts.wgor_mint),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+
+    let sovereign_key = sovereign.key();
+    let lock_seeds: &[&[u8]] = &[PERMANENT_LOCK_SEED, sovereign_key.as_ref(), &[permanent_lock.bump]];
+
+    // ---- Quote back to the vault ----
+    match quote.as_ref() {
+        // Closing unwraps the WGOR: balance + rent land in sol_vault as lamports
+        None => token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.lock_wgor_account.to_account_info(),
+                destination: ctx.accounts.sol_vault.to_account_info(),
+                authority: permanent_lock.to_account_info(),
+            },
+            &[lock_seeds],
+        ))?,
+        Some(quote) if quote_amount > 0 => token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                quote.token_program.clone(),
+                TransferChecked {
+                    from: ctx.accounts.lock_wgor_account.to_account_info(),
+                    mint: quote.mint.clone(),
+                    to: quote.vault.clone(),
+                    authority: permanent_lock.to_account_info(),
+                },
+                &[lock_seeds],
+            ),
+            quote_amount,
+            quote.decimals,
+        )?,
+        Some(_) => {}
+    }
+
+    // ---- Sovereign tokens back to the token vault ----
+    if token_amount > 0 {
+        let sovereign_id_bytes = sovereign.sovereign_id.to_le_bytes();
+        let sovereign_seeds: &[&[u8]] = &[SOVEREIGN_SEED, &sovereign_id_bytes, &[sovereign.bump]];
+        let hook_detached = detach_transfer_hook(
+            &ctx.accounts.token_mint.to_account_info(),
+            &sovereign.to_account_info(),
+            &[sovereign_seeds],
+        )?;
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_2022.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.lock_token_account.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_vault.to_account_info(),
+                    authority: permanent_lock.to_account_info(),
+                },
+                &[lock_seeds],
+            ),
+            token_amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+        reattach_transfer_hook(
+            &ctx.accounts.token_mint.to_account_info(),
+            &sovereign.to_account_info(),
+            &[sovereign_seeds],
+            hook_detached,
+        )?;
+    }
+
+    // withdraw_failed splits the vault pro-rata only once this has happened
+    ctx.accounts.permanent_lock.ladder_proceeds_refunded = true;
+
+    msg!("Ladder proceeds refunded: {} quote, {} tokens → sovereign vaults", quote_amount, token_amount);
+
+    emit!(LadderProceedsRefunded {
+        sovereign_id: sovereign.sovereign_id,
+        quote_amount,
+        token_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use crate::state::*;
+use crate::errors::SovereignError;
+use crate::events::AccountMigrated;
+
+// ============================================================
+// ACCOUNT MIGRATION
+// ============================================================
+//
+// SovereignState, DepositRecord and PermanentLock have grown past the padding
+// they were created with. Accounts created before that are shorter than LEN and
+// cannot be loaded as `Account<T>` until they are reallocated. These permissionless
+// instructions grow such an account to LEN, zero-fill the new bytes and backfill
+// the appended fields whose zero value would not match the old behaviour.
+
+/// Accounts for migrating one program account to its current LEN
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Funds the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Program-owned account; its discriminator is checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrate a SovereignState created before the appended fields
+pub fn migrate_sovereign_handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate::<SovereignState>(&ctx, SovereignState::LEN, |sovereign| {
+        sovereign.backfill_appended_fields();
+    })
+}
+
+/// Migrate a DepositRecord created before the appended fields
+pub fn migrate_deposit_record_handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate::<DepositRecord>(&ctx, DepositRecord::LEN, |deposit_record| {
+        deposit_record.backfill_appended_fields();
+    })
+}
+
+/// Migrate a PermanentLock created before the appended fields
+/// They all start at zero (no ladder positions, no retained quote).
+pub fn migrate_permanent_lock_handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate::<PermanentLock>(&ctx, PermanentLock::LEN, |_| {})
+}
+
+/// Grow `account` to `len`, topping up rent from the payer, then decode it,
+/// let `backfill` set the appended fields and write it back
+fn migrate<T>(ctx: &Context<MigrateAccount>, len: usize, backfill: impl FnOnce(&mut T)) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator,
+{
+    let account = ctx.accounts.account.to_account_info();
+    let old_len = account.data_len();
+    {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= T::DISCRIMINATOR.len() && data[..T::DISCRIMINATOR.len()] == *T::DISCRIMINATOR,
+            SovereignError::InvalidMigrationAccount
+        );
+    }
+    require!(old_len < len, SovereignError::AccountAlreadyMigrated);
+
+    let rent_needed = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(account.lamports());
+    if rent_needed > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_needed,
+        )?;
+    }
+
+    // The new bytes are zeroed, so every appended field starts at its default
+    account.resize(len)?;
+
+    let mut state = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    backfill(&mut state);
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated {}: {} -> {} bytes", account.key(), old_len, len);
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_len: old_len as u32,
+        new_len: len as u32,
+    });
+
+    Ok(())
+}
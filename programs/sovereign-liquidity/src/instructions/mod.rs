@@ -5,12 +5,15 @@ pub mod transfer_hook;
 pub mod deposit;
 pub mod withdraw;
 pub mod finalize;
+pub mod finalize_cpmm;
 pub mod claim_fees;
 pub mod governance;
 pub mod activity_check;
 pub mod failed_bonding;
 pub mod admin;
 pub mod emergency;
+pub mod ladder;
+pub mod migrate;
 
 // Glob re-exports for Anchor compatibility
 // Note: "ambiguous glob re-exports" warning for `handler` is benign -
@@ -23,9 +26,12 @@ pub use transfer_hook::*;
 pub use deposit::*;
 pub use withdraw::*;
 pub use finalize::*;
+pub use finalize_cpmm::*;
 pub use claim_fees::*;
 pub use governance::*;
 pub use activity_check::*;
 pub use failed_bonding::*;
 pub use admin::*;
 pub use emergency::*;
+pub use ladder::*;
+pub use migrate::*;
@@ -1,4 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        BaseStateWithExtensions, StateWithExtensions,
+        transfer_hook::{self, TransferHookAccount},
+    },
+    state::{Account as TokenAccountState, Mint as MintState},
+};
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 use spl_tlv_account_resolution::{
@@ -37,7 +47,7 @@ pub struct InitializeExtraAccountMetas<'info> {
     #[account(
         init,
         payer = payer,
-        space = ExtraAccountMetaList::size_of(1).unwrap(), // 1 extra account: sovereign
+        space = ExtraAccountMetaList::size_of(2).unwrap(), // 2 extra accounts: sovereign, pool vault registry
         seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
         bump
     )]
@@ -78,18 +88,22 @@ pub struct TransferHookExecute<'info> {
         constraint = sovereign.token_mint == mint.key() @ SovereignError::InvalidMint
     )]
     pub sovereign: Account<'info, SovereignState>,
+    
+    /// Pool vault registry - passed as extra account
+    /// CHECK: PDA verified by seeds. Not created until finalization, so it may
+    /// still be empty; an uninitialized registry means no pool vaults exist yet.
+    #[account(
+        seeds = [POOL_VAULT_REGISTRY_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub pool_vault_registry: UncheckedAccount<'info>,
 }
 
-/// Initialize extra account metas for the transfer hook
-/// Must be called after create_token to set up the hook properly
-pub fn initialize_extra_account_metas_handler(
-    ctx: Context<InitializeExtraAccountMetas>,
-) -> Result<()> {
-    let sovereign = &ctx.accounts.sovereign;
-    
+/// Extra accounts Token-2022 resolves for the hook's Execute CPI
+pub fn extra_account_metas(sovereign_id: u64) -> Result<[ExtraAccountMeta; 2]> {
     // Define the extra accounts the hook needs
     // We need the sovereign state to read fee configuration
-    let extra_metas = [
+    Ok([
         // Sovereign state PDA - derived from SOVEREIGN_SEED + sovereign_id
         ExtraAccountMeta::new_with_seeds(
             &[
@@ -97,13 +111,35 @@ pub fn initialize_extra_account_metas_handler(
                     bytes: SOVEREIGN_SEED.to_vec(),
                 },
                 Seed::Literal {
-                    bytes: sovereign.sovereign_id.to_le_bytes().to_vec(),
+                    bytes: sovereign_id.to_le_bytes().to_vec(),
                 },
             ],
             false, // is_signer
             false, // is_writable (we only read)
         )?,
-    ];
+        // Pool vault registry PDA - derived from POOL_VAULT_REGISTRY_SEED + sovereign
+        // The sovereign is at index 5 (source, mint, destination, authority, extra_account_metas, sovereign)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: POOL_VAULT_REGISTRY_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 5 },
+            ],
+            false, // is_signer
+            false, // is_writable
+        )?,
+    ])
+}
+
+/// Initialize extra account metas for the transfer hook
+/// Must be called after create_token to set up the hook properly
+pub fn initialize_extra_account_metas_handler(
+    ctx: Context<InitializeExtraAccountMetas>,
+) -> Result<()> {
+    let sovereign = &ctx.accounts.sovereign;
+    
+    let extra_metas = extra_account_metas(sovereign.sovereign_id)?;
     
     // Write the extra account metas to the PDA
     let account_info = ctx.accounts.extra_account_metas.to_account_info();
@@ -116,28 +152,66 @@ pub fn initialize_extra_account_metas_handler(
 /// Transfer hook execute handler
 /// Called automatically by Token-2022 on every transfer
 /// 
-/// For sells (transfers to AMM pools), we calculate and route fees based on:
+/// Transfers are classified against the sovereign's pool vault registry:
+/// - source is a registered pool vault → buy
+/// - destination is a registered pool vault → sell
+/// - neither → wallet-to-wallet transfer
+///
+/// For sells, we calculate and route fees based on:
 /// - sovereign.sell_fee_bps (0-300 = 0-3%)
 /// - sovereign.fee_mode (CreatorRevenue, RecoveryBoost, FairLaunch)
 /// - sovereign.state (Recovery vs Active)
+///
+/// During the launch guard window, buys are capped per transfer and wallet
+/// balances are capped for every non-sell transfer. Sovereign-owned accounts
+/// (e.g. the creator token vault) are exempt.
 pub fn transfer_hook_execute_handler(
     ctx: Context<TransferHookExecute>,
     amount: u64,
 ) -> Result<()> {
     let sovereign = &ctx.accounts.sovereign;
-    let destination = &ctx.accounts.destination;
+    
+    // Only Token-2022 may call the hook, and only mid-transfer: it flags the
+    // source account as transferring for the duration of the hook CPI
+    require!(
+        is_transferring(&ctx.accounts.source.to_account_info().try_borrow_data()?)?,
+        SovereignError::NotTransferring
+    );
+    
+    // Classify the transfer against the registered pool vaults
+    let (is_buy, is_sell) = classify_transfer(
+        &ctx.accounts.pool_vault_registry,
+        &ctx.accounts.source.key(),
+        &ctx.accounts.destination.key(),
+    )?;
+    
+    // ---- Launch guard ----
+    let guard = &sovereign.launch_guard;
+    let now = Clock::get()?.unix_timestamp;
+    if guard.is_active(sovereign.trading_opens_at, now)
+        && !is_sell
+        && ctx.accounts.destination.owner != sovereign.key()
+    {
+        if is_buy && guard.max_buy_per_tx > 0 {
+            require!(
+                amount <= guard.max_buy_per_tx,
+                SovereignError::LaunchMaxBuyExceeded
+            );
+        }
+        // Token-2022 invokes the hook after balances are updated
+        if guard.max_wallet_balance > 0 {
+            require!(
+                ctx.accounts.destination.amount <= guard.max_wallet_balance,
+                SovereignError::LaunchMaxWalletExceeded
+            );
+        }
+    }
     
     // Skip if no sell fee configured
     if sovereign.sell_fee_bps == 0 {
         return Ok(());
     }
     
-    // Check if this is a sell (transfer to a pool/AMM)
-    // We detect sells by checking if destination is a pool token account
-    // For now, we check if destination owner is not a regular wallet
-    // In production, you'd check against known AMM program IDs
-    let is_sell = is_likely_amm_destination(&destination.owner);
-    
     if !is_sell {
         // Not a sell, no fee
         return Ok(());
@@ -168,6 +242,7 @@ pub fn transfer_hook_execute_handler(
         destination: ctx.accounts.destination.key(),
         amount,
         fee_amount,
+        is_buy,
         is_sell,
         fee_mode: sovereign.fee_mode,
     });
@@ -175,21 +250,25 @@ pub fn transfer_hook_execute_handler(
     Ok(())
 }
 
-/// Check if destination is likely an AMM pool
-/// This is a heuristic - in production, maintain a list of known AMM program IDs
-fn is_likely_amm_destination(owner: &Pubkey) -> bool {
-    // Known AMM programs on Solana/Gorbagana
-    // Add more as needed
-    let amm_programs = [
-        // Raydium CLMM (Trashbin SAMM on Gorbagana)
-        pubkey!("WTzkPUoprVx7PDc1tfKA5sS7k1ynCgU89WtwZhksHX5"),
-        // Raydium AMM v4
-        pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"),
-        // Orca Whirlpool
-        pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"),
-    ];
-    
-    amm_programs.contains(owner)
+/// Classify a transfer as (is_buy, is_sell) using the pool vault registry
+/// A registry that has not been created yet (pre-finalization) has no pools,
+/// so every transfer is a wallet-to-wallet transfer.
+fn classify_transfer(
+    registry_info: &AccountInfo,
+    source: &Pubkey,
+    destination: &Pubkey,
+) -> Result<(bool, bool)> {
+    if registry_info.owner != &crate::ID || registry_info.data_is_empty() {
+        return Ok((false, false));
+    }
+    
+    let data = registry_info.try_borrow_data()?;
+    let registry = PoolVaultRegistry::try_deserialize(&mut &data[..])?;
+    
+    let is_buy = registry.contains(source);
+    let is_sell = registry.contains(destination);
+    
+    Ok((is_buy, is_sell))
 }
 
 /// Calculate fee amount based on basis points
@@ -197,15 +276,88 @@ fn calculate_fee(amount: u64, fee_bps: u16) -> u64 {
     (amount as u128 * fee_bps as u128 / BPS_100_PERCENT as u128) as u64
 }
 
-/// Fallback instruction for transfer hook interface compliance
-/// Token-2022 may call this for certain operations
-#[derive(Accounts)]
-pub struct TransferHookFallback<'info> {
-    /// CHECK: Fallback accepts any accounts
-    pub account: UncheckedAccount<'info>,
+/// Whether Token-2022 has flagged a token account as mid-transfer
+pub fn is_transferring(token_account_data: &[u8]) -> Result<bool> {
+    let account = StateWithExtensions::<TokenAccountState>::unpack(token_account_data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+    Ok(bool::from(extension.transferring))
+}
+
+// ============================================================
+// PROGRAM-SIDE TRANSFERS
+// ============================================================
+//
+// Token-2022 runs the hook as a CPI into this program, and the runtime rejects
+// re-entering a program already on the call stack. Any instruction of ours that
+// moves a hooked sovereign token - directly or through an AMM CPI - therefore
+// detaches the hook first and reattaches it before returning.
+
+/// Transfer hook program of a Token-2022 mint (None without the extension or when detached)
+pub fn transfer_hook_program_id(mint_data: &[u8]) -> Result<Option<Pubkey>> {
+    let mint = StateWithExtensions::<MintState>::unpack(mint_data)?;
+    Ok(transfer_hook::get_program_id(&mint))
 }
 
-pub fn transfer_hook_fallback_handler(_ctx: Context<TransferHookFallback>) -> Result<()> {
-    // No-op fallback
+/// Whether `mint` is a Token-2022 mint hooked by this program
+pub fn hooked_by_program(mint: &AccountInfo) -> Result<bool> {
+    Ok(mint.owner == &spl_token_2022::ID
+        && transfer_hook_program_id(&mint.try_borrow_data()?)? == Some(crate::ID))
+}
+
+/// Detach this program's transfer hook from the sovereign token mint
+/// Returns whether it was attached; pass that to `reattach_transfer_hook`.
+/// BYO mints (not hooked by this program) are left alone.
+pub fn detach_transfer_hook<'info>(
+    mint: &AccountInfo<'info>,
+    sovereign: &AccountInfo<'info>,
+    sovereign_signer_seeds: &[&[&[u8]]],
+) -> Result<bool> {
+    if !hooked_by_program(mint)? {
+        return Ok(false);
+    }
+    set_transfer_hook_program(mint, sovereign, sovereign_signer_seeds, None)?;
+    Ok(true)
+}
+
+/// Reattach the transfer hook removed by `detach_transfer_hook`
+pub fn reattach_transfer_hook<'info>(
+    mint: &AccountInfo<'info>,
+    sovereign: &AccountInfo<'info>,
+    sovereign_signer_seeds: &[&[&[u8]]],
+    detached: bool,
+) -> Result<()> {
+    if detached {
+        set_transfer_hook_program(mint, sovereign, sovereign_signer_seeds, Some(crate::ID))?;
+    }
+    Ok(())
+}
+
+/// Token-2022 instruction pointing the mint's TransferHook extension at
+/// `program_id` (sovereign PDA is the hook authority)
+pub fn set_transfer_hook_program_ix(
+    mint: &Pubkey,
+    sovereign: &Pubkey,
+    program_id: Option<Pubkey>,
+) -> Result<Instruction> {
+    Ok(transfer_hook::instruction::update(
+        &spl_token_2022::ID,
+        mint,
+        sovereign,
+        &[],
+        program_id,
+    )?)
+}
+
+fn set_transfer_hook_program<'info>(
+    mint: &AccountInfo<'info>,
+    sovereign: &AccountInfo<'info>,
+    sovereign_signer_seeds: &[&[&[u8]]],
+    program_id: Option<Pubkey>,
+) -> Result<()> {
+    invoke_signed(
+        &set_transfer_hook_program_ix(mint.key, sovereign.key, program_id)?,
+        &[mint.clone(), sovereign.clone()],
+        sovereign_signer_seeds,
+    )?;
     Ok(())
 }
@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::rent::Rent;
+use anchor_spl::token_interface::{
+    Mint as MintInterface,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+};
+use crate::quote::{self, QuoteToken};
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::SovereignError;
-use crate::events::InvestorWithdrew;
+use crate::events::{InvestorWithdrew, ReferralVolumeUpdated};
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
@@ -31,6 +37,14 @@ pub struct Withdraw<'info> {
     )]
     pub deposit_record: Account<'info, DepositRecord>,
     
+    /// Referral record of the deposit's referrer - required for referred deposits
+    #[account(
+        mut,
+        seeds = [REFERRAL_RECORD_SEED, sovereign.key().as_ref(), deposit_record.referrer.as_ref()],
+        bump = referral_record.bump
+    )]
+    pub referral_record: Option<Account<'info, ReferralRecord>>,
+    
     /// CHECK: PDA that holds SOL
     #[account(
         mut,
@@ -39,6 +53,24 @@ pub struct Withdraw<'info> {
     )]
     pub sol_vault: SystemAccount<'info>,
     
+    /// Quote mint - required when the sovereign bonds in a token quote mint
+    #[account(address = sovereign.quote_mint @ SovereignError::InvalidQuoteMint)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    
+    /// Quote vault holding token quote deposits (authority = sol_vault)
+    #[account(
+        mut,
+        seeds = [QUOTE_VAULT_SEED, sovereign.key().as_ref()],
+        bump
+    )]
+    pub quote_vault: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    /// Depositor's quote token account (receives token quote withdrawals)
+    #[account(mut)]
+    pub depositor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -46,7 +78,7 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     let sovereign = &mut ctx.accounts.sovereign;
     let deposit_record = &mut ctx.accounts.deposit_record;
     let protocol = &ctx.accounts.protocol_state;
-    let _clock = Clock::get()?;
+    let clock = Clock::get()?;
     
     // Check protocol pause status
     require!(
@@ -54,9 +86,12 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         SovereignError::ProtocolPaused
     );
     
-    // Validate state - only during Bonding phase
+    // Validate state - only during Bonding phase, or during the exit window
+    // after a deadline extension (even if the target was hit meanwhile)
     require!(
-        sovereign.state == SovereignStatus::Bonding,
+        sovereign.state == SovereignStatus::Bonding
+            || (sovereign.state == SovereignStatus::Finalizing
+                && sovereign.is_exit_window_open(clock.unix_timestamp)),
         SovereignError::InvalidState
     );
     require!(amount > 0, SovereignError::ZeroWithdraw);
@@ -65,8 +100,15 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         SovereignError::InsufficientDepositBalance
     );
     
+    let quote = QuoteToken::resolve(
+        sovereign,
+        ctx.accounts.quote_mint.as_deref(),
+        ctx.accounts.quote_vault.as_deref(),
+        ctx.accounts.quote_token_program.as_ref(),
+    )?;
+    
     // Validate vault has sufficient balance (prevents accounting mismatch exploits)
-    let vault_balance = ctx.accounts.sol_vault.lamports();
+    let vault_balance = quote::vault_balance(quote.as_ref(), &ctx.accounts.sol_vault)?;
     require!(
         vault_balance >= amount,
         SovereignError::InsufficientVaultBalance
@@ -78,7 +120,7 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         SovereignError::CreatorCannotWithdrawDuringBonding
     );
     
-    // Transfer SOL from vault to depositor using System Program CPI with PDA signer
+    // Transfer quote from vault to depositor with the sol_vault PDA signer
     let sovereign_key = sovereign.key();
     let vault_seeds: &[&[u8]] = &[
         SOL_VAULT_SEED,
@@ -86,30 +128,38 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         &[ctx.bumps.sol_vault],
     ];
     
-    // Safe transfer: ensure vault maintains rent exemption
-    let rent = Rent::get()?;
-    let min_rent = rent.minimum_balance(0);
-    
-    // Ensure vault retains minimum rent-exempt balance
-    let available_balance = ctx.accounts.sol_vault.lamports().saturating_sub(min_rent);
-    require!(
-        available_balance >= amount,
-        SovereignError::InsufficientVaultBalance
-    );
+    // Safe transfer: ensure a native sol_vault maintains rent exemption
+    if quote.is_none() {
+        let rent = Rent::get()?;
+        let min_rent = rent.minimum_balance(0);
+        
+        // Ensure vault retains minimum rent-exempt balance
+        let available_balance = ctx.accounts.sol_vault.lamports().saturating_sub(min_rent);
+        require!(
+            available_balance >= amount,
+            SovereignError::InsufficientVaultBalance
+        );
+    }
     
-    anchor_lang::system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.sol_vault.to_account_info(),
-                to: ctx.accounts.depositor.to_account_info(),
-            },
-            &[vault_seeds],
-        ),
+    quote::pay_out(
+        quote.as_ref(),
+        &ctx.accounts.sol_vault.to_account_info(),
+        vault_seeds,
+        &ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.depositor_quote_account.as_ref().map(|a| a.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
         amount,
     )?;
     
-    // Update deposit record
+    // Withdrawn SOL no longer backs a partial-success vote
+    if deposit_record.partial_success_voted {
+        sovereign.partial_success_votes = sovereign.partial_success_votes.saturating_sub(amount);
+    }
+    
+    // Update deposit record (share units leave pro-rata with the withdrawn SOL)
+    let share_units = deposit_record.share_units_for(amount);
+    deposit_record.share_units = deposit_record.share_units.saturating_sub(share_units);
+    sovereign.total_share_units = sovereign.total_share_units.saturating_sub(share_units);
     deposit_record.amount = deposit_record.amount.checked_sub(amount).unwrap();
     sovereign.total_deposited = sovereign.total_deposited.checked_sub(amount).unwrap();
     
@@ -118,6 +168,36 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         sovereign.depositor_count = sovereign.depositor_count.checked_sub(1).unwrap();
     }
     
+    // Withdrawn SOL no longer counts as referred volume
+    if deposit_record.has_referrer() {
+        let referral_record = ctx.accounts.referral_record
+            .as_mut()
+            .ok_or(SovereignError::ReferralRecordRequired)?;
+        referral_record.referred_volume = referral_record.referred_volume.saturating_sub(amount);
+        if deposit_record.amount == 0 {
+            referral_record.referred_count = referral_record.referred_count.saturating_sub(1);
+        }
+        sovereign.total_referred_volume = sovereign.total_referred_volume.saturating_sub(amount);
+        
+        emit!(ReferralVolumeUpdated {
+            sovereign_id: sovereign.sovereign_id,
+            referrer: referral_record.referrer,
+            depositor: ctx.accounts.depositor.key(),
+            amount,
+            is_deposit: false,
+            referred_volume: referral_record.referred_volume,
+            total_referred_volume: sovereign.total_referred_volume,
+        });
+    }
+    
+    // An exit-window withdrawal can drop a full bond back into Bonding
+    if sovereign.state == SovereignStatus::Finalizing
+        && (!sovereign.is_bond_target_met() || !sovereign.has_min_depositors())
+    {
+        sovereign.state = SovereignStatus::Bonding;
+        sovereign.finalizing_at = 0;
+    }
+    
     emit!(InvestorWithdrew {
         sovereign_id: sovereign.sovereign_id,
         depositor: ctx.accounts.depositor.key(),
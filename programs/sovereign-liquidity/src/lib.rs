@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
+use spl_transfer_hook_interface::instruction::TransferHookInstruction;
 
+pub mod amm;
 pub mod constants;
+pub mod cpmm;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod quote;
 pub mod samm;
 pub mod state;
 
@@ -25,6 +29,7 @@ pub mod sovereign_liquidity {
     // ============ Sovereign Lifecycle ============
     
     /// Create a new sovereign (token launch or BYO token)
+    /// A BYO sovereign may pass an existing SAMM pool to add its liquidity to
     pub fn create_sovereign(
         ctx: Context<CreateSovereign>,
         params: CreateSovereignParams,
@@ -41,9 +46,33 @@ pub mod sovereign_liquidity {
         instructions::create_token::handler(ctx, params)
     }
 
+    /// Create the quote vault for a sovereign bonding in a token quote mint
+    /// Must be called after create_sovereign, before the first deposit
+    pub fn create_quote_vault(ctx: Context<CreateQuoteVault>) -> Result<()> {
+        instructions::create_sovereign::create_quote_vault_handler(ctx)
+    }
+
     /// Deposit SOL during bonding phase
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-        instructions::deposit::handler(ctx, amount)
+    /// During an allowlist round, pass a merkle proof of (wallet, max_amount)
+    /// Optionally attribute the deposit to a referrer (pass its ReferralRecord)
+    pub fn deposit(
+        ctx: Context<Deposit>,
+        amount: u64,
+        allowlist_proof: Option<AllowlistProof>,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::deposit::handler(ctx, amount, allowlist_proof, referrer)
+    }
+
+    /// Deposit SOL on behalf of a beneficiary (payer funds, beneficiary owns the record)
+    pub fn deposit_for(
+        ctx: Context<DepositFor>,
+        beneficiary: Pubkey,
+        amount: u64,
+        allowlist_proof: Option<AllowlistProof>,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::deposit::deposit_for_handler(ctx, beneficiary, amount, allowlist_proof, referrer)
     }
 
     /// Withdraw SOL during bonding phase (investors only)
@@ -51,7 +80,7 @@ pub mod sovereign_liquidity {
         instructions::withdraw::handler(ctx, amount)
     }
 
-    /// Finalize sovereign step 1: Create SAMM pool
+    /// Finalize sovereign step 1: Create SAMM pool (or link the existing pool)
     /// Called after bond target is met (state = Finalizing)
     pub fn finalize_create_pool(ctx: Context<FinalizeCreatePool>) -> Result<()> {
         instructions::finalize::finalize_create_pool_handler(ctx)
@@ -65,11 +94,28 @@ pub mod sovereign_liquidity {
         instructions::finalize::finalize_add_liquidity_handler(ctx)
     }
 
+    /// Finalize a CPMM sovereign: create the CPMM pool and lock its LP tokens
+    /// Single step, replaces finalize_create_pool + finalize_add_liquidity
+    pub fn finalize_cpmm_pool(ctx: Context<FinalizeCpmmPool>) -> Result<()> {
+        instructions::finalize_cpmm::finalize_cpmm_pool_handler(ctx)
+    }
+
     /// Mint Genesis NFT to a depositor after finalization
     pub fn mint_genesis_nft(ctx: Context<MintGenesisNFT>) -> Result<()> {
         instructions::finalize::mint_genesis_nft_handler(ctx)
     }
 
+    /// Release the escrowed creation fee to the protocol treasury
+    /// Permissionless, callable once the sovereign has finalized
+    pub fn release_creation_fee(ctx: Context<ReleaseCreationFee>) -> Result<()> {
+        instructions::finalize::release_creation_fee_handler(ctx)
+    }
+
+    /// Claim a referrer's pro-rata share of the referral pool
+    pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+        instructions::finalize::claim_referral_reward_handler(ctx)
+    }
+
     // ============ Fee Management ============
     
     /// Collect fees from SAMM position
@@ -87,6 +133,12 @@ pub mod sovereign_liquidity {
         instructions::claim_fees::withdraw_creator_fees_handler(ctx)
     }
 
+    /// Creator claims tokens bought with their escrow at finalization
+    /// Unlocked once recovery is complete or the sovereign is unwound
+    pub fn claim_creator_purchased_tokens(ctx: Context<ClaimCreatorPurchasedTokens>) -> Result<()> {
+        instructions::claim_fees::claim_creator_purchased_tokens_handler(ctx)
+    }
+
     /// Harvest withheld transfer fees from Token-2022 token accounts
     /// Fees are collected from TransferFeeConfig extension
     pub fn harvest_transfer_fees<'info>(
@@ -106,6 +158,7 @@ pub mod sovereign_liquidity {
     // ============ Governance ============
     
     /// Propose to unwind the sovereign (Genesis NFT holders)
+    /// Escrows the governance unwind fee until the vote is finalized
     pub fn propose_unwind(ctx: Context<ProposeUnwind>) -> Result<()> {
         instructions::governance::propose_unwind_handler(ctx)
     }
@@ -117,6 +170,7 @@ pub mod sovereign_liquidity {
 
     /// Finalize voting after period ends.
     /// If passed, snapshots SAMM fee_growth and starts 90-day observation.
+    /// Proposal fee is refunded if quorum was reached, else sent to treasury.
     /// remaining_accounts[0] = pool_state (required when vote passes)
     pub fn finalize_vote<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeVote<'info>>) -> Result<()> {
         instructions::governance::finalize_vote_handler(ctx)
@@ -132,9 +186,88 @@ pub mod sovereign_liquidity {
         instructions::governance::claim_unwind_handler(ctx)
     }
 
-    // ============ Activity Check (deprecated — use governance unwind) ============
-    // Activity check instructions removed. Unwind observation is now
-    // integrated into the governance vote flow (finalize_vote + execute_unwind).
+    // ============ LP Ladder ============
+
+    /// Open LP ladder rung `index` as its own SAMM position (state = PoolCreated)
+    /// Rungs open in order before finalize_add_liquidity opens the primary position
+    pub fn open_ladder_position<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenLadderPosition<'info>>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::ladder::open_ladder_position_handler(ctx, index)
+    }
+
+    /// Collect an LP ladder position's fees into the permanent lock
+    /// Distributed by the next claim_fees
+    pub fn collect_ladder_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectLadderFees<'info>>,
+    ) -> Result<()> {
+        instructions::ladder::collect_ladder_fees_handler(ctx)
+    }
+
+    /// Drain an LP ladder position into the permanent lock
+    /// Required for every rung before execute_unwind / emergency_remove_liquidity
+    pub fn unwind_ladder_position<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UnwindLadderPosition<'info>>,
+    ) -> Result<()> {
+        instructions::ladder::unwind_ladder_position_handler(ctx)
+    }
+
+    /// Return drained LP ladder positions to the sovereign's vaults after a stalled
+    /// finalization, ahead of withdraw_failed refunds
+    pub fn refund_ladder_proceeds(ctx: Context<RefundLadderProceeds>) -> Result<()> {
+        instructions::ladder::refund_ladder_proceeds_handler(ctx)
+    }
+
+    // ============ Activity Check ============
+
+    /// Start the 90-day activity check countdown (anyone)
+    pub fn initiate_activity_check(ctx: Context<InitiateActivityCheck>) -> Result<()> {
+        instructions::activity_check::initiate_activity_check_handler(ctx)
+    }
+
+    /// Cancel a pending activity check (creator proves liveness)
+    pub fn cancel_activity_check(ctx: Context<CancelActivityCheck>) -> Result<()> {
+        instructions::activity_check::cancel_activity_check_handler(ctx)
+    }
+
+    /// Execute an activity check after 90 days - transitions to Unwinding
+    pub fn execute_activity_check(ctx: Context<ExecuteActivityCheck>) -> Result<()> {
+        instructions::activity_check::execute_activity_check_handler(ctx)
+    }
+
+    // ============ Transfer Hook ============
+
+    /// Initialize the extra account metas PDA for the transfer hook
+    /// Must be called after create_token for mints with the TransferHook extension
+    pub fn initialize_extra_account_metas(ctx: Context<InitializeExtraAccountMetas>) -> Result<()> {
+        instructions::transfer_hook::initialize_extra_account_metas_handler(ctx)
+    }
+
+    /// Transfer hook execute - invoked by Token-2022 on every transfer
+    /// Token-2022 calls this with the SPL interface discriminator, routed via `fallback`
+    pub fn transfer_hook_execute(ctx: Context<TransferHookExecute>, amount: u64) -> Result<()> {
+        instructions::transfer_hook::transfer_hook_execute_handler(ctx, amount)
+    }
+
+    /// Fallback for the SPL transfer-hook interface
+    /// Token-2022 invokes the hook with the interface `Execute` discriminator
+    /// rather than Anchor's, so dispatch it to `transfer_hook_execute` here
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        let instruction = TransferHookInstruction::unpack(data)?;
+
+        match instruction {
+            TransferHookInstruction::Execute { amount } => {
+                let amount_bytes = amount.to_le_bytes();
+                __private::__global::transfer_hook_execute(program_id, accounts, &amount_bytes)
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
 
     // ============ Failed Bonding ============
     
@@ -143,6 +276,17 @@ pub mod sovereign_liquidity {
         instructions::failed_bonding::mark_bonding_failed_handler(ctx)
     }
 
+    /// Mark a successful raise as failed once finalization has stalled past the timeout
+    /// Permissionless; depositors then refund via withdraw_failed
+    pub fn mark_finalization_stalled(ctx: Context<MarkFinalizationStalled>) -> Result<()> {
+        instructions::failed_bonding::mark_finalization_stalled_handler(ctx)
+    }
+
+    /// Investor votes to accept a missed raise (supermajority moves to Finalizing)
+    pub fn vote_partial_success(ctx: Context<VotePartialSuccess>) -> Result<()> {
+        instructions::failed_bonding::vote_partial_success_handler(ctx)
+    }
+
     /// Investor withdraws from failed bonding
     pub fn withdraw_failed(ctx: Context<WithdrawFailed>) -> Result<()> {
         instructions::failed_bonding::withdraw_failed_handler(ctx)
@@ -153,6 +297,11 @@ pub mod sovereign_liquidity {
         instructions::failed_bonding::withdraw_creator_failed_handler(ctx)
     }
 
+    /// Creator cancels a sovereign before any deposits (refunds creation fee, retires sovereign)
+    pub fn cancel_sovereign(ctx: Context<CancelSovereign>) -> Result<()> {
+        instructions::failed_bonding::cancel_sovereign_handler(ctx)
+    }
+
     // ============ Admin Functions ============
     
     /// Update protocol fee parameters
@@ -199,6 +348,60 @@ pub mod sovereign_liquidity {
         instructions::admin::set_protocol_paused_handler(ctx, paused)
     }
 
+    /// Set the governance unwind proposal fee (0, or at least rent-exempt)
+    pub fn update_governance_unwind_fee(
+        ctx: Context<UpdateGovernanceUnwindFee>,
+        new_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::admin::update_governance_unwind_fee_handler(ctx, new_fee_lamports)
+    }
+
+    /// Set the fee kept from the creation fee on creator cancellation
+    pub fn update_cancellation_fee(
+        ctx: Context<UpdateCancellationFee>,
+        new_fee_lamports: u64,
+    ) -> Result<()> {
+        instructions::admin::update_cancellation_fee_handler(ctx, new_fee_lamports)
+    }
+
+    /// Set the share of the creation fee paid to referrers
+    pub fn update_referral_fee(
+        ctx: Context<UpdateReferralFee>,
+        new_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::admin::update_referral_fee_handler(ctx, new_fee_bps)
+    }
+
+    /// Set the constant-product AMM program offered alongside the SAMM
+    pub fn set_cpmm_program(
+        ctx: Context<SetCpmmProgram>,
+        new_program: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::set_cpmm_program_handler(ctx, new_program)
+    }
+
+    /// Add, update or disable an allowlisted quote mint
+    pub fn set_quote_mint(
+        ctx: Context<SetQuoteMint>,
+        min_bond_target: u64,
+        min_deposit: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::admin::set_quote_mint_handler(ctx, min_bond_target, min_deposit, enabled)
+    }
+
+    /// Register an additional pool vault in a sovereign's pool vault registry
+    pub fn register_pool_vault(ctx: Context<RegisterPoolVault>) -> Result<()> {
+        instructions::admin::register_pool_vault_handler(ctx)
+    }
+
+    // ============ Bonding Management ============
+
+    /// Extend the bond deadline once (creator only), opening a depositor exit window
+    pub fn extend_bond_deadline(ctx: Context<ExtendBondDeadline>, new_deadline: i64) -> Result<()> {
+        instructions::admin::extend_bond_deadline_handler(ctx, new_deadline)
+    }
+
     // ============ Sell Fee Management (TokenLaunch) ============
     
     /// Lower the sell fee (can only decrease, never increase)
@@ -212,6 +415,11 @@ pub mod sovereign_liquidity {
         instructions::admin::renounce_sell_fee_handler(ctx)
     }
 
+    /// Step the sell fee along its decay schedule (permissionless crank)
+    pub fn crank_sell_fee_decay(ctx: Context<CrankSellFeeDecay>) -> Result<()> {
+        instructions::admin::crank_sell_fee_decay_handler(ctx)
+    }
+
     // ============ Emergency Functions ============
 
     /// Emergency unlock - transitions sovereign to EmergencyUnlocked state
@@ -258,4 +466,21 @@ pub mod sovereign_liquidity {
     ) -> Result<()> {
         instructions::emergency::sweep_redemption_pool_handler(ctx)
     }
+
+    // ============ Account Migration ============
+
+    /// Grow a SovereignState created before its appended fields to the current size
+    pub fn migrate_sovereign(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_sovereign_handler(ctx)
+    }
+
+    /// Grow a DepositRecord created before its appended fields to the current size
+    pub fn migrate_deposit_record(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_deposit_record_handler(ctx)
+    }
+
+    /// Grow a PermanentLock created before the LP ladder to the current size
+    pub fn migrate_permanent_lock(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_permanent_lock_handler(ctx)
+    }
 }
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self,
+    Mint as MintInterface,
+    TokenAccount as TokenAccountInterface,
+    TokenInterface,
+    TransferChecked,
+};
+use crate::errors::SovereignError;
+use crate::state::SovereignState;
+
+// ============================================================
+// QUOTE ASSET VAULTS
+// ============================================================
+//
+// A sovereign bonds either in native GOR or in an allowlisted token quote mint.
+//
+// - Native GOR:  lamports held by the sol_vault PDA (system account)
+// - Token quote: tokens held by the quote_vault PDA token account, whose
+//                authority is the sol_vault PDA
+//
+// Both vaults are controlled by the same sol_vault seeds, so every payout
+// signs the same way regardless of the quote asset.
+
+/// Quote-token accounts of a sovereign that bonds in a token quote mint
+pub struct QuoteToken<'info> {
+    pub mint: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub decimals: u8,
+}
+
+impl<'info> QuoteToken<'info> {
+    /// Resolve an instruction's optional quote accounts.
+    /// Returns None for native GOR sovereigns; token quote sovereigns must pass them.
+    pub fn resolve(
+        sovereign: &SovereignState,
+        mint: Option<&InterfaceAccount<'info, MintInterface>>,
+        vault: Option<&InterfaceAccount<'info, TokenAccountInterface>>,
+        token_program: Option<&Interface<'info, TokenInterface>>,
+    ) -> Result<Option<Self>> {
+        if sovereign.is_native_quote() {
+            return Ok(None);
+        }
+
+        let (mint, vault, token_program) = match (mint, vault, token_program) {
+            (Some(mint), Some(vault), Some(token_program)) => (mint, vault, token_program),
+            _ => return err!(SovereignError::QuoteAccountsRequired),
+        };
+        require_keys_eq!(mint.key(), sovereign.quote_mint, SovereignError::InvalidQuoteMint);
+        require_keys_eq!(vault.mint, sovereign.quote_mint, SovereignError::InvalidQuoteMint);
+        require_keys_eq!(
+            *mint.to_account_info().owner,
+            token_program.key(),
+            SovereignError::InvalidQuoteMint
+        );
+
+        Ok(Some(Self {
+            mint: mint.to_account_info(),
+            vault: vault.to_account_info(),
+            token_program: token_program.to_account_info(),
+            decimals: mint.decimals,
+        }))
+    }
+
+    /// Move `amount` from a program-controlled quote token account into the quote vault
+    /// (e.g. LP proceeds or fees collected into the permanent lock's account)
+    pub fn sweep_into_vault(
+        &self,
+        from: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        amount: u64,
+    ) -> Result<()> {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TransferChecked {
+                    from,
+                    mint: self.mint.clone(),
+                    to: self.vault.clone(),
+                    authority,
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.decimals,
+        )
+    }
+    
+    /// Current token balance of the quote vault
+    pub fn vault_balance(&self) -> Result<u64> {
+        let data = self.vault.try_borrow_data()?;
+        require!(data.len() >= 72, SovereignError::InvalidQuoteMint);
+        Ok(u64::from_le_bytes(data[64..72].try_into().unwrap()))
+    }
+}
+
+/// Quote balance held by the sovereign (sol_vault lamports or quote_vault tokens)
+pub fn vault_balance(quote: Option<&QuoteToken>, sol_vault: &AccountInfo) -> Result<u64> {
+    match quote {
+        Some(quote) => quote.vault_balance(),
+        None => Ok(sol_vault.lamports()),
+    }
+}
+
+/// Move `amount` of quote from a signer into the sovereign's vault
+pub fn collect<'info>(
+    quote: Option<&QuoteToken<'info>>,
+    payer: &AccountInfo<'info>,
+    payer_token_account: Option<AccountInfo<'info>>,
+    sol_vault: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    match quote {
+        Some(quote) => {
+            let from = payer_token_account.ok_or(SovereignError::QuoteAccountsRequired)?;
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    quote.token_program.clone(),
+                    TransferChecked {
+                        from,
+                        mint: quote.mint.clone(),
+                        to: quote.vault.clone(),
+                        authority: payer.clone(),
+                    },
+                ),
+                amount,
+                quote.decimals,
+            )
+        }
+        None => anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: sol_vault.clone(),
+                },
+            ),
+            amount,
+        ),
+    }
+}
+
+/// Pay `amount` of quote out of the sovereign's vault
+/// Native: lamports from sol_vault to `recipient`.
+/// Token:  tokens from quote_vault to `recipient_token_account`.
+pub fn pay_out<'info>(
+    quote: Option<&QuoteToken<'info>>,
+    sol_vault: &AccountInfo<'info>,
+    sol_vault_seeds: &[&[u8]],
+    recipient: &AccountInfo<'info>,
+    recipient_token_account: Option<AccountInfo<'info>>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    match quote {
+        Some(quote) => {
+            let to = recipient_token_account.ok_or(SovereignError::QuoteAccountsRequired)?;
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    quote.token_program.clone(),
+                    TransferChecked {
+                        from: quote.vault.clone(),
+                        mint: quote.mint.clone(),
+                        to,
+                        authority: sol_vault.clone(),
+                    },
+                    &[sol_vault_seeds],
+                ),
+                amount,
+                quote.decimals,
+            )
+        }
+        None => anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: sol_vault.clone(),
+                    to: recipient.clone(),
+                },
+                &[sol_vault_seeds],
+            ),
+            amount,
+        ),
+    }
+}
@@ -410,6 +410,15 @@ pub struct AmmConfig {
 
 impl AmmConfig {
     pub const LEN: usize = 200;
+    
+    /// Trade fee rate in whole basis points, rounded up
+    /// (1 bps = 100 hundredths of a bip)
+    pub fn trade_fee_bps(&self) -> Option<u16> {
+        if self.trade_fee_rate > 1_000_000 {
+            return None;
+        }
+        u16::try_from(self.trade_fee_rate.div_ceil(100)).ok()
+    }
 }
 
 // ============================================================
@@ -522,6 +531,40 @@ impl SammAccountDeserialize for PersonalPositionState {
     }
 }
 
+impl SammAccountDeserialize for AmmConfig {
+    fn try_deserialize(data: &[u8]) -> Result<Self> {
+        // Only the leading fields are parsed; the trailing padding is not required
+        if data.len() < 8 + 88 {
+            return Err(error!(crate::errors::SovereignError::InvalidAccountData));
+        }
+        
+        let disc = &data[0..8];
+        if disc != Self::discriminator() {
+            return Err(error!(crate::errors::SovereignError::InvalidAccountData));
+        }
+        
+        let data = &data[8..];
+        
+        Ok(Self {
+            bump: data[0],
+            disable_create_pool: data[1] != 0,
+            index: u16::from_le_bytes([data[2], data[3]]),
+            trade_fee_rate: u32::from_le_bytes(data[4..8].try_into().unwrap()),
+            protocol_fee_rate: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+            fund_fee_rate: u32::from_le_bytes(data[12..16].try_into().unwrap()),
+            create_pool_fee: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+            protocol_owner: Pubkey::try_from(&data[24..56]).unwrap(),
+            fund_owner: Pubkey::try_from(&data[56..88]).unwrap(),
+            padding: [0; 16],
+        })
+    }
+    
+    fn discriminator() -> [u8; 8] {
+        // Anchor discriminator for "account:AmmConfig"
+        [218, 244, 33, 104, 203, 203, 43, 111]
+    }
+}
+
 // ============================================================
 // PDA DERIVATION
 // ============================================================
@@ -28,6 +28,8 @@ use anchor_lang::solana_program::{
     program::invoke_signed,
 };
 use super::instructions::*;
+use super::{tick, tick_math};
+use crate::errors::SovereignError;
 
 // ============================================================
 // CPI RESULT TYPES
@@ -85,6 +87,7 @@ pub struct RemoveLiquidityResult {
 /// # Returns
 ///
 /// The mint address of the position NFT
+#[allow(clippy::too_many_arguments)]
 pub fn open_position_full_range<'info>(
     samm_program: &AccountInfo<'info>,
     accounts: OpenPositionV2Accounts<'info>,
@@ -95,7 +98,41 @@ pub fn open_position_full_range<'info>(
     base_flag: Option<bool>,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<Pubkey> {
-    let args = OpenPositionV2Args::full_range(
+    open_position(
+        samm_program,
+        accounts,
+        super::tick::MIN_TICK,
+        super::tick::MAX_TICK,
+        liquidity,
+        amount_0_max,
+        amount_1_max,
+        tick_spacing,
+        base_flag,
+        signer_seeds,
+    )
+}
+
+/// Open a new liquidity position over [tick_lower, tick_upper]
+///
+/// Same as `open_position_full_range`, for a concentrated range.
+/// The ticks must be multiples of `tick_spacing`, and the tick array
+/// accounts must be the arrays containing them.
+#[allow(clippy::too_many_arguments)]
+pub fn open_position<'info>(
+    samm_program: &AccountInfo<'info>,
+    accounts: OpenPositionV2Accounts<'info>,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128,
+    amount_0_max: u64,
+    amount_1_max: u64,
+    tick_spacing: i32,
+    base_flag: Option<bool>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<Pubkey> {
+    let args = OpenPositionV2Args::new(
+        tick_lower,
+        tick_upper,
         liquidity,
         amount_0_max,
         amount_1_max,
@@ -596,41 +633,80 @@ pub fn get_tick_array_start_index(tick: i32, tick_spacing: i32) -> i32 {
     start_index * ticks_per_array
 }
 
-/// Calculate sqrt price from price (Q64.64 format)
-/// 
-/// sqrt_price_x64 = sqrt(price) * 2^64
-pub fn price_to_sqrt_price_x64(price: f64) -> u128 {
-    let sqrt_price = price.sqrt();
-    (sqrt_price * (1u128 << 64) as f64) as u128
+/// Ticks, deposit caps and expected liquidity for an LP position
+#[derive(Debug, Clone, Copy)]
+pub struct LiquidityPlan {
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    /// Maximum token_0 to deposit
+    pub amount_0_max: u64,
+    /// Maximum token_1 to deposit
+    pub amount_1_max: u64,
+    /// Side the SAMM computes liquidity from (true = token_0)
+    pub base_flag: Option<bool>,
+    /// Liquidity the SAMM mints for these caps
+    pub liquidity: u128,
 }
 
-/// Calculate price from sqrt price (Q64.64 format)
-pub fn sqrt_price_x64_to_price(sqrt_price_x64: u128) -> f64 {
-    let sqrt_price = sqrt_price_x64 as f64 / (1u128 << 64) as f64;
-    sqrt_price * sqrt_price
-}
+/// Plan a position holding `quote_amount` and `token_amount` at the pool's
+/// current sqrt price, over the band `band_bps` around it (0 = full range)
+///
+/// Full range computes liquidity from the sovereign token side to maximize
+/// token utilization; the token side keeps a 0.01% safety margin so the
+/// SAMM's computed need for the other side stays within what we have.
+/// A band needs both sides in the current price ratio, but tick alignment
+/// skews it slightly, so liquidity is computed from whichever side binds.
+pub fn plan_liquidity(
+    sqrt_price_x64: u128,
+    band_bps: u32,
+    quote_is_0: bool,
+    quote_amount: u64,
+    token_amount: u64,
+) -> Result<LiquidityPlan> {
+    let token_adj = token_amount
+        .checked_mul(9999)
+        .and_then(|amount| amount.checked_div(10000))
+        .ok_or(SovereignError::Overflow)?;
+    let (amount_0_max, amount_1_max) = if quote_is_0 {
+        (quote_amount, token_adj)
+    } else {
+        (token_adj, quote_amount)
+    };
 
-/// Calculate tick from sqrt price
-pub fn sqrt_price_x64_to_tick(sqrt_price_x64: u128) -> i32 {
-    let sqrt_price = sqrt_price_x64 as f64 / (1u128 << 64) as f64;
-    let tick = (sqrt_price.ln() / 1.0001f64.ln().sqrt()) as i32;
-    tick
-}
+    let (tick_lower, tick_upper) = if band_bps == 0 {
+        (tick::MIN_TICK, tick::MAX_TICK)
+    } else {
+        tick_math::price_band_ticks(sqrt_price_x64, band_bps, tick::DEFAULT_TICK_SPACING)?
+    };
+    let sqrt_price_lower_x64 = tick_math::get_sqrt_price_at_tick(tick_lower)?;
+    let sqrt_price_upper_x64 = tick_math::get_sqrt_price_at_tick(tick_upper)?;
 
-/// Calculate liquidity from token amounts for a full-range position
-/// 
-/// For full-range, liquidity = min(amount_0 * sqrt(P), amount_1 / sqrt(P))
-pub fn calculate_full_range_liquidity(
-    amount_0: u64,
-    amount_1: u64,
-    sqrt_price_x64: u128,
-) -> u128 {
-    let sqrt_price = sqrt_price_x64 as f64 / (1u128 << 64) as f64;
-    
-    let liquidity_0 = amount_0 as f64 * sqrt_price;
-    let liquidity_1 = amount_1 as f64 / sqrt_price;
-    
-    liquidity_0.min(liquidity_1) as u128
+    // Liquidity each side can fund, with the same integer math the SAMM uses
+    let liquidity_0 = tick_math::get_liquidity_from_amount_0(
+        sqrt_price_x64,
+        sqrt_price_upper_x64,
+        amount_0_max,
+    )?;
+    let liquidity_1 = tick_math::get_liquidity_from_amount_1(
+        sqrt_price_lower_x64,
+        sqrt_price_x64,
+        amount_1_max,
+    )?;
+
+    let base_flag = if band_bps == 0 {
+        Some(!quote_is_0)
+    } else {
+        Some(liquidity_0 <= liquidity_1)
+    };
+
+    Ok(LiquidityPlan {
+        tick_lower,
+        tick_upper,
+        amount_0_max,
+        amount_1_max,
+        base_flag,
+        liquidity: liquidity_0.min(liquidity_1),
+    })
 }
 
 // ============================================================
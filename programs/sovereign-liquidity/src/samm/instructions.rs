@@ -86,7 +86,7 @@ impl OpenPositionV2Args {
     ///
     /// When `liquidity = 0` and `base_flag` is `Some(true)`, the SAMM calculates
     /// optimal liquidity from `amount_0_max`. When `Some(false)`, from `amount_1_max`.
-    /// This avoids precision mismatches between our f64 math and the SAMM's Q64.64.
+    /// This lets the SAMM size the position with its own Q64.64 math.
     pub fn full_range(
         liquidity: u128,
         amount_0_max: u64,
@@ -94,9 +94,28 @@ impl OpenPositionV2Args {
         tick_spacing: i32,
         base_flag: Option<bool>,
     ) -> Self {
-        let tick_lower = super::tick::MIN_TICK;
-        let tick_upper = super::tick::MAX_TICK;
-        
+        Self::new(
+            super::tick::MIN_TICK,
+            super::tick::MAX_TICK,
+            liquidity,
+            amount_0_max,
+            amount_1_max,
+            tick_spacing,
+            base_flag,
+        )
+    }
+    
+    /// Create args for a position over [tick_lower, tick_upper]
+    /// Both ticks must be multiples of `tick_spacing`.
+    pub fn new(
+        tick_lower: i32,
+        tick_upper: i32,
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        tick_spacing: i32,
+        base_flag: Option<bool>,
+    ) -> Self {
         // Use floor division for correct negative tick array alignment
         let tick_array_lower_start = super::cpi::get_tick_array_start_index(tick_lower, tick_spacing);
         let tick_array_upper_start = super::cpi::get_tick_array_start_index(tick_upper, tick_spacing);
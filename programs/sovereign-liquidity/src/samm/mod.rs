@@ -8,6 +8,7 @@
 //! - **accounts**: Account structures for deserializing SAMM program accounts
 //! - **instructions**: Instruction builders for SAMM CPI calls
 //! - **cpi**: High-level CPI helper functions
+//! - **tick_math**: Exact integer Q64.64 tick and liquidity math (mirrors the SAMM)
 //!
 //! ## Supported Operations
 //!
@@ -29,6 +30,7 @@
 pub mod accounts;
 pub mod instructions;
 pub mod cpi;
+pub mod tick_math;
 
 pub use accounts::*;
 pub use instructions::*;
@@ -0,0 +1,515 @@
+//! Exact integer tick and liquidity math (Q64.64)
+//!
+//! Mirrors Raydium CLMM's `tick_math` and `liquidity_math` libraries so the
+//! values we compute (initial pool price, position liquidity) match what the
+//! SAMM computes on-chain bit for bit. No floating point is used anywhere.
+//!
+//! - `get_sqrt_price_at_tick` - sqrt(1.0001^tick) * 2^64
+//! - `get_tick_at_sqrt_price` - greatest tick whose sqrt price <= the given price
+//! - `get_liquidity_from_*`   - liquidity for token amounts over a price range
+//! - `get_delta_amount_*`     - token amounts for a liquidity over a price range
+//! - `sell_amount_out`        - upper bound on the output of one sell across several positions
+
+use anchor_lang::prelude::*;
+use crate::constants::{BPS_DENOMINATOR, MAX_TICK_INDEX};
+use crate::errors::SovereignError;
+
+/// 2^64 - one in Q64.64
+pub const Q64: u128 = 1u128 << 64;
+
+/// Sqrt price at MIN_TICK_INDEX (Q64.64)
+pub const MIN_SQRT_PRICE_X64: u128 = 4295048016;
+
+/// Sqrt price at MAX_TICK_INDEX (Q64.64)
+pub const MAX_SQRT_PRICE_X64: u128 = 79226673521066979257578248091;
+
+/// Bits of log2 precision used by `get_tick_at_sqrt_price`
+const BIT_PRECISION: u32 = 16;
+
+// ============================================================
+// FULL-WIDTH ARITHMETIC
+// ============================================================
+
+/// Full 256-bit product of two u128 values as (high, low)
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (mid << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    (high, low)
+}
+
+/// Divide a 256-bit (high, low) value by `denominator`
+/// Returns (quotient, remainder), or None if the quotient does not fit in u128.
+fn div_full(high: u128, low: u128, denominator: u128) -> Option<(u128, u128)> {
+    if denominator == 0 || high >= denominator {
+        return None;
+    }
+    if high == 0 {
+        return Some((low / denominator, low % denominator));
+    }
+
+    // Restoring long division, one bit of `low` at a time
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// floor(a * b / denominator) with a 256-bit intermediate
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    let (high, low) = full_mul(a, b);
+    div_full(high, low, denominator).map(|(q, _)| q)
+}
+
+/// ceil(a * b / denominator) with a 256-bit intermediate
+pub fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    let (high, low) = full_mul(a, b);
+    let (q, r) = div_full(high, low, denominator)?;
+    if r > 0 { q.checked_add(1) } else { Some(q) }
+}
+
+// ============================================================
+// TICK MATH
+// ============================================================
+
+/// Calculate sqrt(1.0001^tick) * 2^64
+///
+/// Each set bit of |tick| multiplies in a precomputed 1.0001^(-2^(i-1)) in Q64.64;
+/// positive ticks take the reciprocal at the end.
+pub fn get_sqrt_price_at_tick(tick: i32) -> Result<u128> {
+    let abs_tick = tick.unsigned_abs();
+    require!(abs_tick <= MAX_TICK_INDEX as u32, SovereignError::InvalidTick);
+
+    // 1.0001^(-2^(i-1)) for bit i, in Q64.64
+    const RATIOS: [u128; 19] = [
+        0xfffcb933bd6fb800,
+        0xfff97272373d4000,
+        0xfff2e50f5f657000,
+        0xffe5caca7e10f000,
+        0xffcb9843d60f7000,
+        0xff973b41fa98e800,
+        0xff2ea16466c9b000,
+        0xfe5dee046a9a3800,
+        0xfcbe86c7900bb000,
+        0xf987a7253ac65800,
+        0xf3392b0822bb6000,
+        0xe7159475a2caf000,
+        0xd097f3bdfd2f2000,
+        0xa9f746462d9f8000,
+        0x70d869a156f31c00,
+        0x31be135f97ed3200,
+        0x9aa508b5b85a500,
+        0x5d6af8dedc582c,
+        0x2216e584f5fa,
+    ];
+
+    let mut ratio = if abs_tick & 0x1 != 0 { RATIOS[0] } else { Q64 };
+    for (i, factor) in RATIOS.iter().enumerate().skip(1) {
+        if abs_tick & (1 << i) != 0 {
+            // ratio <= 2^64 and factor < 2^64, so the product fits in u128
+            ratio = (ratio * factor) >> 64;
+        }
+    }
+
+    if tick > 0 {
+        ratio = u128::MAX / ratio;
+    }
+
+    Ok(ratio)
+}
+
+/// Calculate the greatest tick whose sqrt price is <= `sqrt_price_x64`
+///
+/// Computes log2 of the price in Q32.32 (integer part from the MSB, fractional
+/// part by repeated squaring), converts it to base sqrt(1.0001), then resolves
+/// the remaining off-by-one against `get_sqrt_price_at_tick`.
+pub fn get_tick_at_sqrt_price(sqrt_price_x64: u128) -> Result<i32> {
+    // The price can never reach the price at MAX_TICK_INDEX
+    require!(
+        (MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64),
+        SovereignError::InvalidSqrtPrice
+    );
+
+    // Integer part of log2(sqrt_price) from the most significant bit
+    let msb = 127 - sqrt_price_x64.leading_zeros();
+    let log2p_integer_x32 = (msb as i128 - 64) << 32;
+
+    // Fractional part: normalize to r in [1, 2) as Q1.63, then square repeatedly;
+    // each time r^2 >= 2 the next fractional bit is 1
+    let mut bit: i128 = 0x8000_0000_0000_0000;
+    let mut precision = 0;
+    let mut log2p_fraction_x64: i128 = 0;
+    let mut r = if msb >= 64 {
+        sqrt_price_x64 >> (msb - 63)
+    } else {
+        sqrt_price_x64 << (63 - msb)
+    };
+
+    while bit > 0 && precision < BIT_PRECISION {
+        r *= r;
+        let is_r_more_than_two = (r >> 127) as u32;
+        r >>= 63 + is_r_more_than_two;
+        log2p_fraction_x64 += bit * is_r_more_than_two as i128;
+        bit >>= 1;
+        precision += 1;
+    }
+    let log2p_fraction_x32 = log2p_fraction_x64 >> 32;
+    let log2p_x32 = log2p_integer_x32 + log2p_fraction_x32;
+
+    // Change of base: multiply by 2^32 / log2(sqrt(1.0001)) to get Q64.64 ticks
+    let log_sqrt_10001_x64 = log2p_x32 * 59543866431248i128;
+
+    // tick - 0.01
+    let tick_low = ((log_sqrt_10001_x64 - 184467440737095516i128) >> 64) as i32;
+    // tick + (2^-14 / log2(sqrt(1.0001))) + 0.01
+    let tick_high = ((log_sqrt_10001_x64 + 15793534762490258745i128) >> 64) as i32;
+
+    Ok(if tick_low == tick_high {
+        tick_low
+    } else if get_sqrt_price_at_tick(tick_high)? <= sqrt_price_x64 {
+        tick_high
+    } else {
+        tick_low
+    })
+}
+
+/// Initial sqrt price for a pool seeded with `amount_0` of token_0 and `amount_1` of token_1
+///
+/// sqrt_price_x64 = floor(sqrt(amount_1 / amount_0) * 2^64), computed exactly as
+/// isqrt(amount_1 * 2^128 / amount_0).
+pub fn sqrt_price_x64_from_amounts(amount_0: u64, amount_1: u64) -> Result<u128> {
+    require!(amount_0 > 0 && amount_1 > 0, SovereignError::InvalidSqrtPrice);
+
+    // amount_1 * 2^128 / amount_0 as a 256-bit (high, low) value
+    let amount_0 = amount_0 as u128;
+    let amount_1 = amount_1 as u128;
+    let high = amount_1 / amount_0;
+    let (low, _) = div_full(amount_1 % amount_0, 0, amount_0)
+        .ok_or(SovereignError::Overflow)?;
+
+    // Bitwise integer square root of the 256-bit value
+    let mut root = 0u128;
+    for i in (0..128).rev() {
+        let candidate = root | (1u128 << i);
+        let (sq_high, sq_low) = full_mul(candidate, candidate);
+        if sq_high < high || (sq_high == high && sq_low <= low) {
+            root = candidate;
+        }
+    }
+
+    require!(
+        (MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64).contains(&root),
+        SovereignError::InvalidSqrtPrice
+    );
+    Ok(root)
+}
+
+// ============================================================
+// LIQUIDITY MATH
+// ============================================================
+
+/// Order two sqrt prices so that a <= b
+fn sort_prices(sqrt_ratio_a_x64: u128, sqrt_ratio_b_x64: u128) -> (u128, u128) {
+    if sqrt_ratio_a_x64 > sqrt_ratio_b_x64 {
+        (sqrt_ratio_b_x64, sqrt_ratio_a_x64)
+    } else {
+        (sqrt_ratio_a_x64, sqrt_ratio_b_x64)
+    }
+}
+
+/// Liquidity for `amount_0` of token_0 over the price range [a, b]
+///
+/// L = amount_0 * (sqrt(a) * sqrt(b)) / (sqrt(b) - sqrt(a))
+pub fn get_liquidity_from_amount_0(
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    amount_0: u64,
+) -> Result<u128> {
+    let (sqrt_a, sqrt_b) = sort_prices(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+    let intermediate = mul_div_floor(sqrt_a, sqrt_b, Q64).ok_or(SovereignError::Overflow)?;
+    let liquidity = mul_div_floor(amount_0 as u128, intermediate, sqrt_b - sqrt_a)
+        .ok_or(SovereignError::Overflow)?;
+    Ok(liquidity)
+}
+
+/// Liquidity for `amount_1` of token_1 over the price range [a, b]
+///
+/// L = amount_1 / (sqrt(b) - sqrt(a))
+pub fn get_liquidity_from_amount_1(
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    amount_1: u64,
+) -> Result<u128> {
+    let (sqrt_a, sqrt_b) = sort_prices(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+    let liquidity = mul_div_floor(amount_1 as u128, Q64, sqrt_b - sqrt_a)
+        .ok_or(SovereignError::Overflow)?;
+    Ok(liquidity)
+}
+
+/// Maximum liquidity mintable from `amount_0` and `amount_1` at the current price
+/// over the price range [a, b]
+pub fn get_liquidity_from_amounts(
+    sqrt_ratio_x64: u128,
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    amount_0: u64,
+    amount_1: u64,
+) -> Result<u128> {
+    let (sqrt_a, sqrt_b) = sort_prices(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+
+    if sqrt_ratio_x64 <= sqrt_a {
+        // Below the range: only token_0 is needed
+        get_liquidity_from_amount_0(sqrt_a, sqrt_b, amount_0)
+    } else if sqrt_ratio_x64 < sqrt_b {
+        // In range: limited by whichever side runs out first
+        let liquidity_0 = get_liquidity_from_amount_0(sqrt_ratio_x64, sqrt_b, amount_0)?;
+        let liquidity_1 = get_liquidity_from_amount_1(sqrt_a, sqrt_ratio_x64, amount_1)?;
+        Ok(liquidity_0.min(liquidity_1))
+    } else {
+        // Above the range: only token_1 is needed
+        get_liquidity_from_amount_1(sqrt_a, sqrt_b, amount_1)
+    }
+}
+
+/// Token_0 amount for `liquidity` over the price range [a, b]
+///
+/// amount_0 = L * (sqrt(b) - sqrt(a)) / (sqrt(a) * sqrt(b))
+pub fn get_delta_amount_0_unsigned(
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u64> {
+    let (sqrt_a, sqrt_b) = sort_prices(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+    require!(sqrt_a > 0, SovereignError::InvalidSqrtPrice);
+
+    // (L << 64) * (sqrt_b - sqrt_a) / sqrt_b needs more than 256 bits, so divide
+    // L * (sqrt_b - sqrt_a) by sqrt_b first and carry the remainder into the Q64 shift:
+    // (L * d << 64) / sqrt_b = (q << 64) + (r << 64) / sqrt_b
+    let (high, low) = full_mul(liquidity, sqrt_b - sqrt_a);
+    let (q, r) = div_full(high, low, sqrt_b).ok_or(SovereignError::Overflow)?;
+    let frac = if round_up {
+        mul_div_ceil(r, Q64, sqrt_b)
+    } else {
+        mul_div_floor(r, Q64, sqrt_b)
+    }
+    .ok_or(SovereignError::Overflow)?;
+    let scaled_low = (q << 64).wrapping_add(frac);
+    let scaled_high = (q >> 64) + u128::from(scaled_low < frac);
+
+    // Nested floor (or ceil) divisions equal a single division by sqrt_a * sqrt_b
+    let (amount, remainder) = div_full(scaled_high, scaled_low, sqrt_a)
+        .ok_or(SovereignError::Overflow)?;
+    let amount = if round_up && remainder > 0 {
+        amount.checked_add(1).ok_or(SovereignError::Overflow)?
+    } else {
+        amount
+    };
+    u64::try_from(amount).map_err(|_| error!(SovereignError::Overflow))
+}
+
+/// Token_1 amount for `liquidity` over the price range [a, b]
+///
+/// amount_1 = L * (sqrt(b) - sqrt(a))
+pub fn get_delta_amount_1_unsigned(
+    sqrt_ratio_a_x64: u128,
+    sqrt_ratio_b_x64: u128,
+    liquidity: u128,
+    round_up: bool,
+) -> Result<u64> {
+    let (sqrt_a, sqrt_b) = sort_prices(sqrt_ratio_a_x64, sqrt_ratio_b_x64);
+    let amount = if round_up {
+        mul_div_ceil(liquidity, sqrt_b - sqrt_a, Q64)
+    } else {
+        mul_div_floor(liquidity, sqrt_b - sqrt_a, Q64)
+    }
+    .ok_or(SovereignError::Overflow)?;
+    u64::try_from(amount).map_err(|_| error!(SovereignError::Overflow))
+}
+
+/// Ticks bounding the band [price / m, price * m] around `sqrt_price_x64`,
+/// with m = band_bps / 10000
+///
+/// The lower tick rounds down and the upper tick rounds up to `tick_spacing`, so the
+/// band always strictly contains the current price. Both are clamped to the tick range.
+pub fn price_band_ticks(sqrt_price_x64: u128, band_bps: u32, tick_spacing: i32) -> Result<(i32, i32)> {
+    require!(
+        band_bps > BPS_DENOMINATOR as u32 && tick_spacing > 0,
+        SovereignError::InvalidPriceBand
+    );
+
+    // sqrt(m) in Q64.64
+    let sqrt_multiple_x64 = sqrt_price_x64_from_amounts(BPS_DENOMINATOR as u64, band_bps as u64)?;
+    let sqrt_lower = mul_div_floor(sqrt_price_x64, Q64, sqrt_multiple_x64)
+        .ok_or(SovereignError::Overflow)?
+        .max(MIN_SQRT_PRICE_X64);
+    let sqrt_upper = mul_div_ceil(sqrt_price_x64, sqrt_multiple_x64, Q64)
+        .ok_or(SovereignError::Overflow)?
+        .min(MAX_SQRT_PRICE_X64 - 1);
+
+    let max_aligned_tick = MAX_TICK_INDEX / tick_spacing * tick_spacing;
+    let tick_lower = get_tick_at_sqrt_price(sqrt_lower)?
+        .div_euclid(tick_spacing)
+        .checked_mul(tick_spacing)
+        .ok_or(SovereignError::Overflow)?
+        .max(-max_aligned_tick);
+    // get_tick_at_sqrt_price rounds down, so + 1 puts the upper tick above the bound
+    let tick_above = get_tick_at_sqrt_price(sqrt_upper)? + 1;
+    let tick_upper = (tick_above + tick_spacing - 1)
+        .div_euclid(tick_spacing)
+        .checked_mul(tick_spacing)
+        .ok_or(SovereignError::Overflow)?
+        .min(max_aligned_tick);
+
+    Ok((tick_lower, tick_upper))
+}
+
+/// Deviation of the price at `sqrt_price_x64` from the price at
+/// `reference_sqrt_price_x64`, in bps of the reference price (rounded up).
+/// None when the prices are too far apart for the ratio to fit in Q64.64.
+pub fn price_deviation_bps(sqrt_price_x64: u128, reference_sqrt_price_x64: u128) -> Option<u128> {
+    // price / reference = (sqrt / reference sqrt)^2
+    let sqrt_ratio_x64 = mul_div_floor(sqrt_price_x64, Q64, reference_sqrt_price_x64)?;
+    let ratio_x64 = mul_div_floor(sqrt_ratio_x64, sqrt_ratio_x64, Q64)?;
+    mul_div_ceil(ratio_x64.abs_diff(Q64), BPS_DENOMINATOR as u128, Q64)
+}
+
+/// Liquidity of a full-range position (samm::tick::MIN_TICK..MAX_TICK)
+/// funded with at most `amount_0` and `amount_1` at the current pool price
+pub fn full_range_liquidity(sqrt_price_x64: u128, amount_0: u64, amount_1: u64) -> Result<u128> {
+    let sqrt_lower = get_sqrt_price_at_tick(super::tick::MIN_TICK)?;
+    let sqrt_upper = get_sqrt_price_at_tick(super::tick::MAX_TICK)?;
+    get_liquidity_from_amounts(sqrt_price_x64, sqrt_lower, sqrt_upper, amount_0, amount_1)
+}
+
+/// Upper bound on the output of selling `amount_in` into `positions`
+/// (liquidity, tick_lower, tick_upper) from `sqrt_price_x64`, crossing the
+/// ranges one price step at a time the way the pool does. `zero_for_one`
+/// sells token_0 for token_1.
+///
+/// Every rounding favours the seller: the input needed to finish a step rounds
+/// down, and the output and the price a partial step ends at round toward more
+/// output. Trading fees are ignored, which also only adds output.
+pub fn sell_amount_out(
+    positions: &[(u128, i32, i32)],
+    sqrt_price_x64: u128,
+    amount_in: u128,
+    zero_for_one: bool,
+) -> Result<u128> {
+    let mut ranges = Vec::with_capacity(positions.len());
+    for &(liquidity, tick_lower, tick_upper) in positions {
+        if liquidity > 0 {
+            ranges.push((liquidity, get_sqrt_price_at_tick(tick_lower)?, get_sqrt_price_at_tick(tick_upper)?));
+        }
+    }
+
+    // Range bounds the price moves through, in the order it reaches them
+    let mut bounds: Vec<u128> = ranges
+        .iter()
+        .flat_map(|&(_, sqrt_lower, sqrt_upper)| [sqrt_lower, sqrt_upper])
+        .filter(|&bound| if zero_for_one { bound < sqrt_price_x64 } else { bound > sqrt_price_x64 })
+        .collect();
+    if zero_for_one {
+        bounds.sort_unstable_by(|a, b| b.cmp(a));
+    } else {
+        bounds.sort_unstable();
+    }
+    bounds.dedup();
+
+    let mut sqrt_price = sqrt_price_x64;
+    let mut remaining = amount_in;
+    let mut amount_out = 0u128;
+    for bound in bounds {
+        if remaining == 0 {
+            break;
+        }
+        // Liquidity of the positions spanning the whole step
+        let (sqrt_low, sqrt_high) = sort_prices(sqrt_price, bound);
+        let liquidity = ranges
+            .iter()
+            .filter(|&&(_, sqrt_lower, sqrt_upper)| sqrt_lower <= sqrt_low && sqrt_high <= sqrt_upper)
+            .try_fold(0u128, |sum, &(liquidity, _, _)| sum.checked_add(liquidity))
+            .ok_or(SovereignError::Overflow)?;
+        if liquidity == 0 {
+            sqrt_price = bound;
+            continue;
+        }
+
+        // Input to reach the bound; None when it does not fit (more than any sell)
+        let to_bound = if zero_for_one {
+            get_delta_amount_0_unsigned(bound, sqrt_price, liquidity, false).ok().map(u128::from)
+        } else {
+            mul_div_floor(liquidity, bound - sqrt_price, Q64)
+        };
+        let next_price = match to_bound {
+            Some(amount) if amount <= remaining => {
+                remaining -= amount;
+                bound
+            }
+            _ if zero_for_one => {
+                // sqrt(P') = L * sqrt(P) / (L + amount * sqrt(P))
+                let product = mul_div_ceil(remaining, sqrt_price, Q64).ok_or(SovereignError::Overflow)?;
+                let denominator = liquidity.checked_add(product).ok_or(SovereignError::Overflow)?;
+                remaining = 0;
+                mul_div_floor(liquidity, sqrt_price, denominator)
+                    .ok_or(SovereignError::Overflow)?
+                    .max(bound)
+            }
+            _ => {
+                // sqrt(P') = sqrt(P) + amount / L
+                let step = mul_div_ceil(remaining, Q64, liquidity).ok_or(SovereignError::Overflow)?;
+                remaining = 0;
+                sqrt_price.saturating_add(step).min(bound)
+            }
+        };
+
+        let step_out = if zero_for_one {
+            mul_div_ceil(liquidity, sqrt_price - next_price, Q64).ok_or(SovereignError::Overflow)?
+        } else {
+            get_delta_amount_0_unsigned(sqrt_price, next_price, liquidity, true)? as u128
+        };
+        amount_out = amount_out.checked_add(step_out).ok_or(SovereignError::Overflow)?;
+        sqrt_price = next_price;
+    }
+    Ok(amount_out)
+}
+
+/// Amounts (token_0, token_1) held by `positions` (liquidity, tick_lower,
+/// tick_upper) at `sqrt_price_x64`, rounded down: what removing all of their
+/// liquidity would pay out, not counting fees
+pub fn position_amounts(positions: &[(u128, i32, i32)], sqrt_price_x64: u128) -> Result<(u128, u128)> {
+    let mut amount_0 = 0u128;
+    let mut amount_1 = 0u128;
+    for &(liquidity, tick_lower, tick_upper) in positions {
+        let sqrt_lower = get_sqrt_price_at_tick(tick_lower)?;
+        let sqrt_upper = get_sqrt_price_at_tick(tick_upper)?;
+        // token_0 above the price, token_1 below it
+        let sqrt_price = sqrt_price_x64.clamp(sqrt_lower, sqrt_upper);
+        if sqrt_price < sqrt_upper {
+            let amount = get_delta_amount_0_unsigned(sqrt_price, sqrt_upper, liquidity, false)?;
+            amount_0 = amount_0.checked_add(amount as u128).ok_or(SovereignError::Overflow)?;
+        }
+        if sqrt_lower < sqrt_price {
+            let amount = get_delta_amount_1_unsigned(sqrt_lower, sqrt_price, liquidity, false)?;
+            amount_1 = amount_1.checked_add(amount as u128).ok_or(SovereignError::Overflow)?;
+        }
+    }
+    Ok((amount_0, amount_1))
+}
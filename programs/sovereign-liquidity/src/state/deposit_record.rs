@@ -44,9 +44,22 @@ pub struct DepositRecord {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// Whether investor voted to accept a partial raise
+    pub partial_success_voted: bool,
+    
+    /// Referrer this deposit is attributed to (default = none)
+    pub referrer: Pubkey,
+    
+    /// Weighted share units (amount scaled by the early-bird multiplier at deposit time)
+    /// Used for fee claims, voting power and unwind claims; refunds use amount
+    pub share_units: u64,
 }
 
 impl DepositRecord {
+    /// Fields after `bump` are appended, so older records keep decoding. They
+    /// outgrew the original 16 bytes of padding, so a record created before
+    /// referrals must go through migrate_deposit_record before it is loaded.
     pub const LEN: usize = 8  // discriminator
         + 32  // sovereign
         + 32  // depositor
@@ -61,7 +74,9 @@ impl DepositRecord {
         + 1   // refund_claimed
         + 8   // deposited_at
         + 1   // bump
-        + 16; // padding
+        + 1   // partial_success_voted
+        + 32  // referrer
+        + 8;  // share_units
     
     /// Calculate claimable fees based on deposit share
     pub fn calculate_claimable_fees(&self, total_fees: u64, total_deposited: u64) -> u64 {
@@ -86,6 +101,27 @@ impl DepositRecord {
     pub fn has_deposit(&self) -> bool {
         self.amount > 0
     }
+    
+    /// Share units removed by withdrawing `amount` lamports (pro-rata to the deposit)
+    pub fn share_units_for(&self, amount: u64) -> u64 {
+        if amount >= self.amount {
+            return self.share_units;
+        }
+        (self.share_units as u128 * amount as u128 / self.amount as u128) as u64
+    }
+    
+    /// Set the appended fields of a migrated record: deposits made before the
+    /// early-bird bonus carry 1x share units
+    pub fn backfill_appended_fields(&mut self) {
+        if self.share_units == 0 {
+            self.share_units = self.amount;
+        }
+    }
+    
+    /// Check if this deposit is attributed to a referrer
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
 }
 
 /// Tracks creator's fee revenue and purchased tokens
@@ -65,6 +65,10 @@ pub struct Proposal {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// Unwind proposal fee held in the proposal fee escrow (lamports)
+    /// Refunded if quorum is reached, sent to treasury otherwise
+    pub proposal_fee: u64,
 }
 
 impl Proposal {
@@ -84,7 +88,8 @@ impl Proposal {
         + 8   // created_at
         + 8   // executed_at
         + 1   // bump
-        + 16; // padding
+        + 8   // proposal_fee
+        + 8;  // padding (was 16, used 8 for proposal_fee)
     
     /// Default governance parameters
     pub fn default_quorum_bps() -> u16 { 6700 }  // 67%
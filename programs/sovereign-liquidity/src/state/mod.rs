@@ -3,9 +3,15 @@ pub mod sovereign_state;
 pub mod deposit_record;
 pub mod permanent_lock;
 pub mod governance;
+pub mod pool_vault_registry;
+pub mod referral_record;
+pub mod quote_mint_config;
 
 pub use protocol_state::*;
 pub use sovereign_state::*;
 pub use deposit_record::*;
 pub use permanent_lock::*;
 pub use governance::*;
+pub use pool_vault_registry::*;
+pub use referral_record::*;
+pub use quote_mint_config::*;
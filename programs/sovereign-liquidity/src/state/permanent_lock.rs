@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_LP_LADDER_RUNGS;
+use crate::errors::SovereignError;
 
-/// Controls the Trashbin SAMM (Raydium CLMM) position NFT
+/// Controls the Trashbin SAMM (Raydium CLMM) position NFT, or the LP tokens
+/// of a CPMM pool (see `SovereignState::amm_kind`)
 /// This PDA is the permanent delegate/owner of the position
 /// - Allows fee collection only
 /// - During Recovery: LP locked, can be unwound via governance
@@ -11,25 +14,26 @@ pub struct PermanentLock {
     /// The sovereign this lock belongs to
     pub sovereign: Pubkey,
     
-    /// The Trashbin SAMM PoolState address
+    /// The PoolState address on the sovereign's AMM
     pub pool_state: Pubkey,
     
-    /// Position NFT mint address
+    /// Position NFT mint address (CPMM: the pool's LP mint)
     pub position_mint: Pubkey,
     
-    /// Position account address (PDA derived from position_mint)
+    /// Position account address (PDA derived from position_mint; CPMM: the LP token account)
     pub position: Pubkey,
     
-    /// Token account holding the position NFT
+    /// Token account holding the position NFT (CPMM: the LP tokens)
     pub position_token_account: Pubkey,
     
     /// Total liquidity in the position
+    /// CPMM: the principal in sqrt(k) units; LP tokens above it are fees
     pub liquidity: u128,
     
-    /// Lower tick index (always MIN_TICK for full range)
+    /// Lower tick index (MIN_TICK for full range, else the creator's price band)
     pub tick_lower_index: i32,
     
-    /// Upper tick index (always MAX_TICK for full range)
+    /// Upper tick index (MAX_TICK for full range, else the creator's price band)
     pub tick_upper_index: i32,
     
     /// Whether the LP has been unwound (only possible during Recovery)
@@ -43,9 +47,31 @@ pub struct PermanentLock {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    /// LP ladder positions opened (LockedPosition children, indices 0..position_count)
+    pub position_count: u8,
+    
+    /// LP ladder positions drained by an unwind or emergency removal
+    pub positions_unwound: u8,
+    
+    /// Total liquidity across the LP ladder positions
+    pub ladder_liquidity: u128,
+    
+    /// Liquidity and range of each opened ladder position (liquidity 0 once drained)
+    pub ladder_ranges: [LadderRange; MAX_LP_LADDER_RUNGS],
+    
+    /// Drained ladder proceeds returned to the sovereign's vaults (stalled finalization)
+    pub ladder_proceeds_refunded: bool,
+    
+    /// Harvested quote held back in the sovereign's vault by the claim_fees
+    /// solvency check, instead of being paid out as fees
+    pub retained_quote: u64,
 }
 
 impl PermanentLock {
+    /// Fields after `bump` are appended, so older locks keep decoding. They
+    /// outgrew the original 16 bytes of padding, so a lock created before the
+    /// last appended field must go through migrate_permanent_lock before it is loaded.
     pub const LEN: usize = 8  // discriminator
         + 32  // sovereign
         + 32  // pool_state
@@ -59,12 +85,158 @@ impl PermanentLock {
         + 8   // created_at
         + 8   // unwound_at
         + 1   // bump
-        + 16; // padding
+        + 1   // position_count
+        + 1   // positions_unwound
+        + 16  // ladder_liquidity
+        + LadderRange::LEN * MAX_LP_LADDER_RUNGS // ladder_ranges
+        + 1   // ladder_proceeds_refunded
+        + 8;  // retained_quote
     
     /// Check if the position is still active (not unwound)
     pub fn is_active(&self) -> bool {
         !self.unwound
     }
+    
+    /// Liquidity across the primary position and the LP ladder
+    pub fn total_liquidity(&self) -> u128 {
+        self.liquidity.saturating_add(self.ladder_liquidity)
+    }
+    
+    /// Record the next LP ladder position as opened (open_ladder_position)
+    pub fn record_rung_opened(&mut self, liquidity: u128, tick_lower_index: i32, tick_upper_index: i32) -> Result<()> {
+        let range = self.ladder_ranges
+            .get_mut(self.position_count as usize)
+            .ok_or(SovereignError::InvalidLpLadder)?;
+        *range = LadderRange { liquidity, tick_lower_index, tick_upper_index };
+        self.position_count += 1;
+        self.ladder_liquidity = self.ladder_liquidity
+            .checked_add(liquidity)
+            .ok_or(SovereignError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record LP ladder position `index` as drained (unwind_ladder_position)
+    pub fn record_rung_unwound(&mut self, index: u8) -> Result<()> {
+        let range = self.ladder_ranges
+            .get_mut(index as usize)
+            .ok_or(SovereignError::InvalidLpLadder)?;
+        range.liquidity = 0;
+        self.positions_unwound += 1;
+        Ok(())
+    }
+    
+    /// Positions (liquidity, tick_lower, tick_upper) still holding liquidity:
+    /// the primary one and the LP ladder
+    pub fn positions(&self) -> Vec<(u128, i32, i32)> {
+        let primary = (!self.unwound).then_some((self.liquidity, self.tick_lower_index, self.tick_upper_index));
+        primary
+            .into_iter()
+            .chain(
+                self.ladder_ranges[..(self.position_count as usize).min(MAX_LP_LADDER_RUNGS)]
+                    .iter()
+                    .map(|range| (range.liquidity, range.tick_lower_index, range.tick_upper_index)),
+            )
+            .filter(|&(liquidity, _, _)| liquidity > 0)
+            .collect()
+    }
+    
+    /// Whether rung `index` is the next LP ladder position to open
+    pub fn is_next_rung(&self, index: u8, rung_count: u8) -> bool {
+        index == self.position_count && index < rung_count
+    }
+    
+    /// Whether every LP ladder position has been drained
+    pub fn is_ladder_unwound(&self) -> bool {
+        self.positions_unwound >= self.position_count
+    }
+    
+    /// Whether every LP ladder position is back in the sovereign's vaults
+    pub fn is_ladder_refunded(&self) -> bool {
+        self.position_count == 0 || (self.is_ladder_unwound() && self.ladder_proceeds_refunded)
+    }
+}
+
+/// Liquidity and range of an LP ladder position, kept on the PermanentLock so
+/// the claim_fees solvency floor can walk every position without its account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct LadderRange {
+    /// Liquidity in the position
+    pub liquidity: u128,
+    /// Lower tick index
+    pub tick_lower_index: i32,
+    /// Upper tick index
+    pub tick_upper_index: i32,
+}
+
+impl LadderRange {
+    pub const LEN: usize = 16 + 4 + 4;
+}
+
+/// One LP ladder position owned by a PermanentLock
+/// The position NFT is held by the permanent_lock PDA like the primary one;
+/// this account records which position it is and its range.
+#[account]
+#[derive(Default)]
+pub struct LockedPosition {
+    /// The permanent lock this position belongs to
+    pub permanent_lock: Pubkey,
+    
+    /// Rung index in the sovereign's lp_ladder
+    pub index: u8,
+    
+    /// Position NFT mint address
+    pub position_mint: Pubkey,
+    
+    /// Position account address (PDA derived from position_mint)
+    pub position: Pubkey,
+    
+    /// Token account holding the position NFT
+    pub position_token_account: Pubkey,
+    
+    /// Liquidity in the position
+    pub liquidity: u128,
+    
+    /// Lower tick index (MIN_TICK for full range)
+    pub tick_lower_index: i32,
+    
+    /// Upper tick index (MAX_TICK for full range)
+    pub tick_upper_index: i32,
+    
+    /// Whether the position has been drained (unwind or emergency only)
+    pub unwound: bool,
+    
+    /// Timestamp when position was created
+    pub created_at: i64,
+    
+    /// Timestamp when unwound (0 if not unwound)
+    pub unwound_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl LockedPosition {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // permanent_lock
+        + 1   // index
+        + 32  // position_mint
+        + 32  // position
+        + 32  // position_token_account
+        + 16  // liquidity
+        + 4   // tick_lower_index
+        + 4   // tick_upper_index
+        + 1   // unwound
+        + 8   // created_at
+        + 8   // unwound_at
+        + 1   // bump
+        + 16; // padding
+    
+    /// Record the position as drained (unwind_ladder_position)
+    pub fn record_unwound(&mut self, now: i64) {
+        self.liquidity = 0;
+        self.unwound = true;
+        self.unwound_at = now;
+    }
 }
 
 /// Escrow account for holding creation fee during bonding
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_POOL_VAULTS;
+use crate::errors::SovereignError;
+
+/// Registry of AMM pool vault token accounts for a sovereign's token
+/// The transfer hook uses this to classify transfers exactly:
+/// - source is a registered vault → buy
+/// - destination is a registered vault → sell
+/// - neither → wallet-to-wallet transfer
+#[account]
+#[derive(Default)]
+pub struct PoolVaultRegistry {
+    /// The sovereign this registry belongs to
+    pub sovereign: Pubkey,
+    
+    /// Number of registered vaults
+    pub vault_count: u8,
+    
+    /// Registered pool vault token accounts (first vault_count entries are valid)
+    pub vaults: [Pubkey; MAX_POOL_VAULTS],
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PoolVaultRegistry {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // sovereign
+        + 1   // vault_count
+        + 32 * MAX_POOL_VAULTS  // vaults
+        + 1   // bump
+        + 16; // padding
+    
+    /// Check if a token account is a registered pool vault
+    pub fn contains(&self, vault: &Pubkey) -> bool {
+        self.vaults[..self.vault_count as usize].contains(vault)
+    }
+    
+    /// Register a new pool vault
+    pub fn register(&mut self, vault: Pubkey) -> Result<()> {
+        require!(
+            !self.contains(&vault),
+            SovereignError::PoolVaultAlreadyRegistered
+        );
+        require!(
+            (self.vault_count as usize) < MAX_POOL_VAULTS,
+            SovereignError::PoolVaultRegistryFull
+        );
+        self.vaults[self.vault_count as usize] = vault;
+        self.vault_count += 1;
+        Ok(())
+    }
+}
@@ -89,6 +89,22 @@ pub struct ProtocolState {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    // ============================================================
+    // APPENDED FIELDS (after bump, in the order they were added)
+    // ============================================================
+    
+    /// Fee kept from the creation fee escrow when a creator cancels
+    /// Default: 0 (full refund)
+    pub cancellation_fee_lamports: u64,
+    
+    /// Share of the creation fee paid to referrers on finalization (0-5000 = 0-50%)
+    /// Default: 0 (no referral rewards)
+    pub referral_fee_bps: u16,
+    
+    /// Constant-product AMM program offered as an alternative to the SAMM
+    /// Default: unset (CPMM sovereigns disabled)
+    pub cpmm_program: Pubkey,
 }
 
 impl ProtocolState {
@@ -109,7 +125,10 @@ impl ProtocolState {
         + 8   // sovereign_count
         + 8   // total_fees_collected
         + 1   // bump
-        + 64; // padding for future expansion
+        + 8   // cancellation_fee_lamports
+        + 2   // referral_fee_bps
+        + 32  // cpmm_program
+        + 22; // padding for future expansion (was 64, used 8 for cancellation_fee_lamports, 2 for referral_fee_bps, 32 for cpmm_program)
     
     /// Default values matching SPEC
     pub fn default_creation_fee_bps() -> u16 { 50 }  // 0.5%
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Protocol allowlist entry for a non-native quote mint
+/// One QuoteMintConfig per mint. Sovereigns can bond in any enabled quote mint
+/// (e.g. a stablecoin or another sovereign's token) instead of native GOR.
+#[account]
+#[derive(Default)]
+pub struct QuoteMintConfig {
+    /// The allowlisted quote mint (SPL Token or Token-2022)
+    pub mint: Pubkey,
+    
+    /// Token program that owns the mint
+    pub token_program: Pubkey,
+    
+    /// Mint decimals (for display/reference)
+    pub decimals: u8,
+    
+    /// Minimum bond target in quote base units
+    pub min_bond_target: u64,
+    
+    /// Minimum single deposit in quote base units
+    pub min_deposit: u64,
+    
+    /// Whether new sovereigns may bond in this mint
+    pub enabled: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl QuoteMintConfig {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // mint
+        + 32  // token_program
+        + 1   // decimals
+        + 8   // min_bond_target
+        + 8   // min_deposit
+        + 1   // enabled
+        + 1   // bump
+        + 32; // padding
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Tracks deposit volume referred to a sovereign by one referrer
+/// One ReferralRecord per referrer per sovereign
+#[account]
+#[derive(Default)]
+pub struct ReferralRecord {
+    /// The sovereign this record belongs to
+    pub sovereign: Pubkey,
+    
+    /// The referrer's wallet address (receives the reward)
+    pub referrer: Pubkey,
+    
+    /// Current deposit volume attributed to this referrer in lamports
+    /// (reduced when referred depositors withdraw during bonding)
+    pub referred_volume: u64,
+    
+    /// Number of depositors currently attributed to this referrer
+    pub referred_count: u32,
+    
+    /// Reward claimed from the referral pool in lamports
+    pub reward_claimed: u64,
+    
+    /// Whether the referral reward has been claimed
+    pub claimed: bool,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ReferralRecord {
+    pub const LEN: usize = 8  // discriminator
+        + 32  // sovereign
+        + 32  // referrer
+        + 8   // referred_volume
+        + 4   // referred_count
+        + 8   // reward_claimed
+        + 1   // claimed
+        + 1   // bump
+        + 16; // padding
+    
+    /// Pro-rata share of the referral pool for this referrer
+    pub fn calculate_reward(&self, referral_pool: u64, total_referred_volume: u64) -> u64 {
+        if total_referred_volume == 0 {
+            return 0;
+        }
+        (referral_pool as u128 * self.referred_volume as u128 / total_referred_volume as u128) as u64
+    }
+}
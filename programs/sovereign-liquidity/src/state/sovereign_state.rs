@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use solana_keccak_hasher::hashv;
+use crate::constants::{BPS_DENOMINATOR, FINALIZATION_TIMEOUT, MAX_LP_LADDER_RUNGS, SAMM_PROGRAM_ID, TRANSFER_FEE_EFFECTIVE_EPOCHS, WGOR_MINT};
 
 /// Type of token launch
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
@@ -22,6 +24,154 @@ pub enum FeeMode {
     FairLaunch,
 }
 
+/// AMM backend holding the permanent lock's liquidity
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum AmmKind {
+    /// Trashbin SAMM (Raydium CLMM fork) - position NFT held by the permanent lock
+    #[default]
+    Samm,
+    /// Constant-product AMM (Raydium CP-Swap layout) - LP tokens held by the permanent lock
+    Cpmm,
+}
+
+/// Linear sell fee decay schedule for Token Launcher
+/// The sell fee falls from start_bps to end_bps over duration seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct SellFeeDecay {
+    /// Sell fee at the start of the schedule
+    pub start_bps: u16,
+    /// Steady-state sell fee once the schedule ends
+    pub end_bps: u16,
+    /// Schedule start (unix timestamp). 0 = start at finalization
+    pub start_time: i64,
+    /// Schedule length in seconds (0 = no schedule)
+    pub duration: i64,
+}
+
+impl SellFeeDecay {
+    pub const LEN: usize = 2 + 2 + 8 + 8;
+    
+    /// Whether a schedule is configured
+    pub fn is_enabled(&self) -> bool {
+        self.duration > 0
+    }
+    
+    /// Scheduled sell fee at `now`, given the effective start time
+    pub fn fee_at(&self, start: i64, now: i64) -> u16 {
+        if now <= start {
+            return self.start_bps;
+        }
+        let elapsed = now - start;
+        if elapsed >= self.duration {
+            return self.end_bps;
+        }
+        
+        // Linear interpolation between start_bps and end_bps
+        let start_bps = self.start_bps as i128;
+        let end_bps = self.end_bps as i128;
+        let fee = start_bps + (end_bps - start_bps) * elapsed as i128 / self.duration as i128;
+        fee as u16
+    }
+}
+
+/// Anti-sniper launch guard for Token Launcher
+/// Delays pool open and caps buys for the first window_duration seconds of trading.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct LaunchGuard {
+    /// Seconds between pool creation and trading open (SAMM open_time)
+    pub open_delay: i64,
+    /// Seconds after trading opens during which the caps apply (0 = no guard)
+    pub window_duration: i64,
+    /// Maximum tokens per buy transfer during the window (0 = no cap)
+    pub max_buy_per_tx: u64,
+    /// Maximum wallet balance after a transfer during the window (0 = no cap)
+    pub max_wallet_balance: u64,
+}
+
+impl LaunchGuard {
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+    
+    /// Whether a guard is configured
+    pub fn is_enabled(&self) -> bool {
+        self.window_duration > 0 || self.open_delay > 0
+    }
+    
+    /// Whether the buy caps are in force at `now`, for a pool that opens at `trading_opens_at`
+    pub fn is_active(&self, trading_opens_at: i64, now: i64) -> bool {
+        trading_opens_at > 0
+            && self.window_duration > 0
+            && now < trading_opens_at.saturating_add(self.window_duration)
+    }
+}
+
+/// Early-depositor share bonus
+/// Deposits earn share units at multiplier_bps / 10000 per lamport: the full
+/// multiplier for hold_duration seconds after creation, then decaying linearly
+/// to 1.0x over decay_duration seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct EarlyBirdBonus {
+    /// Starting multiplier in basis points (e.g. 12000 = 1.2x, 0 = no bonus)
+    pub multiplier_bps: u16,
+    /// Seconds after creation at the full multiplier
+    pub hold_duration: i64,
+    /// Seconds over which the multiplier decays to 1.0x after hold_duration
+    pub decay_duration: i64,
+}
+
+impl EarlyBirdBonus {
+    pub const LEN: usize = 2 + 8 + 8;
+    
+    /// Whether a bonus is configured
+    pub fn is_enabled(&self) -> bool {
+        self.multiplier_bps > BPS_DENOMINATOR
+    }
+    
+    /// Share multiplier in basis points at `now`, given the sovereign's creation time
+    pub fn multiplier_at(&self, start: i64, now: i64) -> u16 {
+        if !self.is_enabled() {
+            return BPS_DENOMINATOR;
+        }
+        let elapsed = now.saturating_sub(start).max(0);
+        if elapsed < self.hold_duration {
+            return self.multiplier_bps;
+        }
+        let decay_elapsed = elapsed - self.hold_duration;
+        if decay_elapsed >= self.decay_duration {
+            return BPS_DENOMINATOR;
+        }
+        
+        // Linear decay from multiplier_bps to 1.0x
+        let bonus = (self.multiplier_bps - BPS_DENOMINATOR) as i128;
+        let remaining = bonus * (self.decay_duration - decay_elapsed) as i128 / self.decay_duration as i128;
+        BPS_DENOMINATOR + remaining as u16
+    }
+    
+    /// Weighted share units for a deposit of `amount` lamports at `now`
+    pub fn share_units(&self, amount: u64, start: i64, now: i64) -> Option<u64> {
+        let units = amount as u128 * self.multiplier_at(start, now) as u128 / BPS_DENOMINATOR as u128;
+        u64::try_from(units).ok()
+    }
+}
+
+/// One rung of the LP ladder: a share of the raise deployed as its own
+/// SAMM position, over its own price band around the launch price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct LpRung {
+    /// Share of the raise (both sides) in basis points (0 = unused rung)
+    pub share_bps: u16,
+    /// Price band multiple in bps, as lp_price_band_bps (0 = full range)
+    pub band_bps: u32,
+}
+
+impl LpRung {
+    pub const LEN: usize = 2 + 4;
+    
+    /// Whether the rung is configured
+    pub fn is_enabled(&self) -> bool {
+        self.share_bps > 0
+    }
+}
+
 /// Current state of the sovereign lifecycle
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum SovereignStatus {
@@ -91,7 +241,7 @@ pub struct SovereignState {
     // BONDING CONFIGURATION
     // ============================================================
     
-    /// Required SOL to raise (in lamports)
+    /// Hard cap: SOL raise that finalizes immediately (in lamports)
     pub bond_target: u64,
     
     /// Unix timestamp deadline for bonding
@@ -144,20 +294,20 @@ pub struct SovereignState {
     pub creation_fee_escrowed: u64,
     
     // ============================================================
-    // SAMM POOL CONFIGURATION (set at creation)
+    // AMM POOL CONFIGURATION (set at creation)
     // ============================================================
     
-    /// Trashbin SAMM AmmConfig address (fee tier chosen by creator)
+    /// AmmConfig address on that AMM (fee tier chosen by creator)
     pub amm_config: Pubkey,
     
-    /// Swap fee in basis points (matching the AMM config tier, e.g. 30 = 0.3%)
+    /// Swap fee in basis points, derived from the AMM config trade fee rate (e.g. 30 = 0.3%)
     pub swap_fee_bps: u16,
     
     // ============================================================
     // POOL INFORMATION
     // ============================================================
     
-    /// Trashbin SAMM PoolState address (set on finalization)
+    /// PoolState address on the sovereign's AMM (set on finalization)
     pub pool_state: Pubkey,
     
     /// Position NFT mint (held by PermanentLock)
@@ -279,6 +429,95 @@ pub struct SovereignState {
     
     /// PDA bump seed
     pub bump: u8,
+    
+    // ============================================================
+    // APPENDED FIELDS (after bump, in the order they were added)
+    // ============================================================
+    
+    /// Optional sell fee decay schedule (followed by crank_sell_fee_decay)
+    pub sell_fee_decay: SellFeeDecay,
+    
+    /// Sell fee the decay crank set on the mint, not yet in effect
+    pub pending_sell_fee_bps: u16,
+    
+    /// Whether pending_sell_fee_bps is waiting for Token-2022 to apply it
+    pub sell_fee_pending: bool,
+    
+    /// Epoch of the decay crank's last set_transfer_fee
+    pub sell_fee_set_epoch: u64,
+    
+    /// Optional anti-sniper launch guard (enforced by the transfer hook)
+    pub launch_guard: LaunchGuard,
+    
+    /// Timestamp the pool opens for trading (the pool's open_time; 0 until a pool is set)
+    pub trading_opens_at: i64,
+    
+    /// Merkle root of (wallet, max_amount) leaves ([0; 32] = no allowlist)
+    pub allowlist_merkle_root: [u8; 32],
+    
+    /// Deposits require an allowlist proof until this timestamp
+    pub allowlist_end_time: i64,
+    
+    /// Maximum total deposit per investor wallet (0 = no cap)
+    pub max_deposit_per_wallet: u64,
+    
+    /// Minimum investor depositors required for bonding to succeed (0 = none)
+    pub min_depositor_count: u32,
+    
+    /// Soft cap: minimum SOL raise for success at bond_deadline (in lamports)
+    pub soft_cap: u64,
+    
+    /// Deposit weight voting to accept a partial raise after a missed deadline
+    pub partial_success_votes: u64,
+    
+    /// Whether the creator has used the one-time deadline extension
+    pub deadline_extended: bool,
+    
+    /// Depositors may withdraw until this timestamp after an extension
+    pub extension_exit_window_end: i64,
+    
+    /// Share of the creation fee paid to referrers (snapshot of protocol setting at creation)
+    pub referral_fee_bps: u16,
+    
+    /// Total deposit volume attributed to referrers
+    pub total_referred_volume: u64,
+    
+    /// Referral rewards set aside from the creation fee on release (lamports)
+    pub referral_pool: u64,
+    
+    /// Optional early-depositor share bonus
+    pub early_bird_bonus: EarlyBirdBonus,
+    
+    /// Total weighted share units across all deposits (equals total_deposited without a bonus)
+    /// Used for fee claims, voting power and unwind claims
+    pub total_share_units: u64,
+    
+    /// Quote mint the sovereign bonds in (default = native GOR held in sol_vault)
+    /// Token quote mints are held in the quote_vault PDA
+    pub quote_mint: Pubkey,
+    
+    /// Minimum single deposit in quote base units (token quote mints only)
+    pub quote_min_deposit: u64,
+    
+    /// Concentrated LP band as a multiple of the launch price in bps (0 = full range)
+    /// The position covers [launch price / m, launch price * m]
+    pub lp_price_band_bps: u32,
+    
+    /// Extra LP positions opened alongside the primary one (unused rungs last)
+    /// The primary position gets whatever share the rungs leave.
+    pub lp_ladder: [LpRung; MAX_LP_LADDER_RUNGS],
+    
+    /// Timestamp when the raise entered Finalizing (0 while Bonding)
+    pub finalizing_at: i64,
+    
+    /// Existing SAMM pool to add liquidity into (BYO only; default = create a new pool)
+    pub existing_pool: Pubkey,
+    
+    /// AMM backend the liquidity is deployed to
+    pub amm_kind: AmmKind,
+    
+    /// Program of that AMM (the SAMM, or the protocol's CPMM program at creation)
+    pub amm_program: Pubkey,
 }
 
 /// Max length constants for string fields
@@ -288,6 +527,9 @@ pub const MAX_TOKEN_SYMBOL_LEN: usize = 10;
 pub const MAX_METADATA_URI_LEN: usize = 200;
 
 impl SovereignState {
+    /// Fields after `bump` are appended, so older accounts keep decoding. They
+    /// outgrew the original 40 bytes of padding, so a sovereign created before
+    /// the launch guard must go through migrate_sovereign before it is loaded.
     pub const LEN: usize = 8  // discriminator
         + 8   // sovereign_id
         + 32  // creator
@@ -344,7 +586,33 @@ impl SovereignState {
         + 8   // created_at
         + 8   // finalized_at
         + 1   // bump
-        + 40; // padding for future expansion (was 64, used 24 for redemption fields)
+        + SellFeeDecay::LEN // sell_fee_decay
+        + 2   // pending_sell_fee_bps
+        + 1   // sell_fee_pending
+        + 8   // sell_fee_set_epoch
+        + LaunchGuard::LEN  // launch_guard
+        + 8   // trading_opens_at
+        + 32  // allowlist_merkle_root
+        + 8   // allowlist_end_time
+        + 8   // max_deposit_per_wallet
+        + 4   // min_depositor_count
+        + 8   // soft_cap
+        + 8   // partial_success_votes
+        + 1   // deadline_extended
+        + 8   // extension_exit_window_end
+        + 2   // referral_fee_bps
+        + 8   // total_referred_volume
+        + 8   // referral_pool
+        + EarlyBirdBonus::LEN // early_bird_bonus
+        + 8   // total_share_units
+        + 32  // quote_mint
+        + 8   // quote_min_deposit
+        + 4   // lp_price_band_bps
+        + LpRung::LEN * MAX_LP_LADDER_RUNGS // lp_ladder
+        + 8   // finalizing_at
+        + 32  // existing_pool
+        + 1   // amm_kind
+        + 32; // amm_program
     
     /// Calculate maximum creator buy-in based on bond target
     pub fn max_creator_buy_in(&self) -> u64 {
@@ -356,11 +624,196 @@ impl SovereignState {
         current_time > self.bond_deadline
     }
     
+    /// Check if the post-extension exit window is open
+    pub fn is_exit_window_open(&self, current_time: i64) -> bool {
+        current_time < self.extension_exit_window_end
+    }
+    
+    /// When the raise became finalizable: entry into Finalizing, or the bond
+    /// deadline for a soft-cap raise still waiting on finalize_create_pool
+    pub fn finalization_started_at(&self) -> i64 {
+        if self.finalizing_at > 0 {
+            self.finalizing_at
+        } else {
+            self.bond_deadline
+        }
+    }
+    
+    /// Check if finalization has run past FINALIZATION_TIMEOUT
+    /// (counted from the end of any deadline-extension exit window)
+    pub fn is_finalization_stalled(&self, current_time: i64) -> bool {
+        let started_at = self.finalization_started_at().max(self.extension_exit_window_end);
+        current_time > started_at.saturating_add(FINALIZATION_TIMEOUT)
+    }
+    
     /// Check if bond target is met
     pub fn is_bond_target_met(&self) -> bool {
         self.total_deposited >= self.bond_target
     }
     
+    /// Check if soft cap is met (raise succeeds at the deadline)
+    pub fn is_soft_cap_met(&self) -> bool {
+        self.total_deposited >= self.soft_cap
+    }
+    
+    /// Set the appended fields of a migrated sovereign whose zero value would
+    /// change its behaviour: hard-cap-only raise, 1x share units, SAMM backend
+    pub fn backfill_appended_fields(&mut self) {
+        if self.soft_cap == 0 {
+            self.soft_cap = self.bond_target;
+        }
+        if self.total_share_units == 0 {
+            self.total_share_units = self.total_deposited;
+        }
+        if self.amm_program == Pubkey::default() {
+            self.amm_program = SAMM_PROGRAM_ID;
+        }
+    }
+    
+    /// Whether vote_partial_success could still accept the raise as-is: it must
+    /// meet `min_bond_target` and have enough depositors
+    pub fn is_partial_raise_acceptable(&self, min_bond_target: u64) -> bool {
+        self.total_deposited > 0
+            && self.total_deposited >= min_bond_target
+            && self.has_min_depositors()
+    }
+    
+    /// Whether finalization adds liquidity to an existing pool instead of creating one
+    pub fn uses_existing_pool(&self) -> bool {
+        self.existing_pool != Pubkey::default()
+    }
+    
+    /// Whether the LP position covers the full tick range
+    pub fn is_full_range(&self) -> bool {
+        self.lp_price_band_bps == 0
+    }
+    
+    /// Number of LP ladder rungs, each opened as a LockedPosition before the primary
+    pub fn ladder_rung_count(&self) -> u8 {
+        self.lp_ladder.iter().take_while(|rung| rung.is_enabled()).count() as u8
+    }
+    
+    /// Share of the raise (bps) still undeployed when rung `index` opens
+    pub fn ladder_share_remaining(&self, index: u8) -> u32 {
+        let deployed: u32 = self.lp_ladder.iter()
+            .take(index as usize)
+            .map(|rung| rung.share_bps as u32)
+            .sum();
+        (BPS_DENOMINATOR as u32).saturating_sub(deployed)
+    }
+    
+    /// Rung `index`'s part of `available` (quote or tokens still undeployed when
+    /// it opens), rounded down so the primary position keeps the dust
+    pub fn ladder_rung_amount(&self, index: u8, available: u64) -> u64 {
+        let share = self.lp_ladder[index as usize].share_bps as u128;
+        (available as u128 * share / self.ladder_share_remaining(index) as u128) as u64
+    }
+    
+    /// Whether the sovereign bonds in native GOR (lamports in sol_vault)
+    pub fn is_native_quote(&self) -> bool {
+        self.quote_mint == Pubkey::default()
+    }
+    
+    /// Mint of the quote side of the pool (WGOR for native GOR sovereigns)
+    pub fn pool_quote_mint(&self) -> Pubkey {
+        if self.is_native_quote() {
+            WGOR_MINT
+        } else {
+            self.quote_mint
+        }
+    }
+    
+    /// Minimum single deposit in quote base units
+    pub fn min_deposit(&self, protocol_min_deposit: u64) -> u64 {
+        if self.is_native_quote() {
+            protocol_min_deposit
+        } else {
+            self.quote_min_deposit
+        }
+    }
+    
+    /// A depositor's share of the sovereign in basis points, by weighted share units
+    pub fn share_bps(&self, share_units: u64) -> Option<u64> {
+        share_units
+            .checked_mul(BPS_DENOMINATOR as u64)?
+            .checked_div(self.total_share_units)
+    }
+    
+    /// Whether the allowlist round is in force at `now`
+    pub fn is_allowlist_active(&self, now: i64) -> bool {
+        self.allowlist_merkle_root != [0u8; 32] && now < self.allowlist_end_time
+    }
+    
+    /// Verify a merkle proof of (wallet, max_amount) against the allowlist root
+    /// Leaves are keccak(wallet || max_amount_le); pairs are hashed in sorted order.
+    pub fn verify_allowlist(&self, wallet: &Pubkey, max_amount: u64, proof: &[[u8; 32]]) -> bool {
+        let mut node = hashv(&[wallet.as_ref(), &max_amount.to_le_bytes()]).to_bytes();
+        for sibling in proof {
+            node = if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            };
+        }
+        node == self.allowlist_merkle_root
+    }
+    
+    /// Whether the mint needs the TransferHook extension
+    pub fn has_transfer_hook(&self) -> bool {
+        self.sell_fee_bps > 0 || self.launch_guard.is_enabled()
+    }
+    
+    /// Effective start of the sell fee decay schedule (0 if not started yet)
+    pub fn sell_fee_decay_start(&self) -> i64 {
+        if self.sell_fee_decay.start_time > 0 {
+            self.sell_fee_decay.start_time
+        } else {
+            self.finalized_at
+        }
+    }
+    
+    /// Sell fee currently configured on the mint (may not be in effect yet)
+    pub fn configured_sell_fee_bps(&self) -> u16 {
+        if self.sell_fee_pending {
+            self.pending_sell_fee_bps
+        } else {
+            self.sell_fee_bps
+        }
+    }
+    
+    /// Whether the pending sell fee is in effect at `epoch`
+    /// Token-2022 applies set_transfer_fee from the second epoch after the call.
+    pub fn is_pending_sell_fee_effective(&self, epoch: u64) -> bool {
+        epoch >= self.sell_fee_set_epoch.saturating_add(TRANSFER_FEE_EFFECTIVE_EPOCHS)
+    }
+    
+    /// Refund owed for a deposit of `deposit_amount` after a failed raise
+    /// Exact deposits, unless finalization stalled after the pool was created:
+    /// LP ladder rungs may have come back with less quote, so the vault (less
+    /// the creator's escrow) is split pro-rata over the deposits not yet refunded.
+    pub fn failed_refund_amount(&self, deposit_amount: u64, vault_balance: u64) -> Option<u64> {
+        if self.pool_state == Pubkey::default() {
+            return Some(deposit_amount);
+        }
+        let available = vault_balance.saturating_sub(self.creator_escrow);
+        (deposit_amount as u128)
+            .checked_mul(available as u128)?
+            .checked_div(self.total_deposited as u128)
+            .map(|refund| refund as u64)
+    }
+    
+    /// Bond room held back so each depositor still needed for min_depositor_count
+    /// can deposit `min_deposit` (`is_new_depositor`: the caller would be one of them)
+    pub fn reserved_for_missing_depositors(&self, is_new_depositor: bool, min_deposit: u64) -> u64 {
+        let counted = self.depositor_count.saturating_add(is_new_depositor as u32);
+        (self.min_depositor_count.saturating_sub(counted) as u64).saturating_mul(min_deposit)
+    }
+    
+    /// Check if enough distinct investors have deposited
+    pub fn has_min_depositors(&self) -> bool {
+        self.depositor_count >= self.min_depositor_count
+    }
+    
     /// Check if recovery is complete
     pub fn is_recovery_complete(&self) -> bool {
         self.total_sol_fees_distributed >= self.recovery_target
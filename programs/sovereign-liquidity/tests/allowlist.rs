@@ -0,0 +1,97 @@
+//! Merkle allowlist proofs checked by deposit during the allowlist round.
+//!
+//! The tree is built here the way the creator's tooling does: leaves are
+//! keccak(wallet || max_amount_le) and each pair is hashed in sorted order.
+
+use anchor_lang::prelude::Pubkey;
+use solana_keccak_hasher::hashv;
+use sovereign_liquidity::state::SovereignState;
+
+fn leaf(wallet: &Pubkey, max_amount: u64) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), &max_amount.to_le_bytes()]).to_bytes()
+}
+
+fn parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[low, high]).to_bytes()
+}
+
+/// Four-leaf tree: returns the sovereign holding its root and the proof of each leaf
+fn allowlisted(entries: &[(Pubkey, u64); 4]) -> (SovereignState, Vec<Vec<[u8; 32]>>) {
+    let leaves: Vec<[u8; 32]> = entries.iter().map(|(wallet, max)| leaf(wallet, *max)).collect();
+    let left = parent(&leaves[0], &leaves[1]);
+    let right = parent(&leaves[2], &leaves[3]);
+    let sovereign = SovereignState {
+        allowlist_merkle_root: parent(&left, &right),
+        ..Default::default()
+    };
+    let proofs = vec![
+        vec![leaves[1], right],
+        vec![leaves[0], right],
+        vec![leaves[3], left],
+        vec![leaves[2], left],
+    ];
+    (sovereign, proofs)
+}
+
+fn entries() -> [(Pubkey, u64); 4] {
+    [
+        (Pubkey::new_unique(), 1_000_000_000),
+        (Pubkey::new_unique(), 2_500_000_000),
+        (Pubkey::new_unique(), 500_000_000),
+        (Pubkey::new_unique(), 10_000_000_000),
+    ]
+}
+
+#[test]
+fn valid_proofs_verify() {
+    let entries = entries();
+    let (sovereign, proofs) = allowlisted(&entries);
+    for ((wallet, max_amount), proof) in entries.iter().zip(&proofs) {
+        assert!(sovereign.verify_allowlist(wallet, *max_amount, proof));
+    }
+}
+
+#[test]
+fn wrong_wallet_is_rejected() {
+    let entries = entries();
+    let (sovereign, proofs) = allowlisted(&entries);
+    assert!(!sovereign.verify_allowlist(&Pubkey::new_unique(), entries[0].1, &proofs[0]));
+    // Another listed wallet cannot reuse someone else's proof
+    assert!(!sovereign.verify_allowlist(&entries[1].0, entries[0].1, &proofs[0]));
+}
+
+#[test]
+fn wrong_max_amount_is_rejected() {
+    let entries = entries();
+    let (sovereign, proofs) = allowlisted(&entries);
+    let (wallet, max_amount) = entries[2];
+    assert!(!sovereign.verify_allowlist(&wallet, max_amount + 1, &proofs[2]));
+    assert!(!sovereign.verify_allowlist(&wallet, entries[3].1, &proofs[2]));
+}
+
+#[test]
+fn tampered_sibling_is_rejected() {
+    let entries = entries();
+    let (sovereign, proofs) = allowlisted(&entries);
+    let (wallet, max_amount) = entries[1];
+    for index in 0..proofs[1].len() {
+        let mut proof = proofs[1].clone();
+        proof[index][0] ^= 1;
+        assert!(!sovereign.verify_allowlist(&wallet, max_amount, &proof));
+    }
+    // Dropping a level does not verify either
+    assert!(!sovereign.verify_allowlist(&wallet, max_amount, &proofs[1][..1]));
+}
+
+#[test]
+fn empty_proof_verifies_against_a_single_leaf_root() {
+    let wallet = Pubkey::new_unique();
+    let sovereign = SovereignState {
+        allowlist_merkle_root: leaf(&wallet, 750_000_000),
+        ..Default::default()
+    };
+    assert!(sovereign.verify_allowlist(&wallet, 750_000_000, &[]));
+    assert!(!sovereign.verify_allowlist(&wallet, 750_000_001, &[]));
+    assert!(!sovereign.verify_allowlist(&Pubkey::new_unique(), 750_000_000, &[]));
+}
@@ -0,0 +1,31 @@
+//! Helpers shared by the integration tests
+// Each test crate compiles this module and uses only some of it
+#![allow(dead_code)]
+
+use anchor_lang::prelude::{AccountInfo, Pubkey};
+
+/// Deterministic xorshift64* generator so failures reproduce
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+/// Leak an account so it lives as long as the `'info` the program expects
+pub fn account(key: Pubkey, owner: Pubkey, lamports: u64, data: Vec<u8>, executable: bool) -> &'static AccountInfo<'static> {
+    Box::leak(Box::new(AccountInfo::new(
+        Box::leak(Box::new(key)),
+        false,
+        true,
+        Box::leak(Box::new(lamports)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        executable,
+        0,
+    )))
+}
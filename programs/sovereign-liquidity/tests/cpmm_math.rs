@@ -0,0 +1,200 @@
+//! CPMM lock math against a simulated constant-product pool.
+//!
+//! The pool model follows Raydium CP-Swap: the trade fee is taken from the
+//! input and stays in the reserves, withdrawals round both sides down.
+
+mod common;
+
+use sovereign_liquidity::cpmm::*;
+use common::Rng;
+
+const FEE_BPS: u64 = 25;
+/// LP tokens the CPMM program locks forever at pool creation
+const LOCKED_LP: u64 = 100;
+
+struct Pool {
+    quote: u64,
+    token: u64,
+    lp_supply: u64,
+}
+
+impl Pool {
+    /// A new pool whose LP tokens (except the locked ones) all go to the lock
+    fn seed(quote: u64, token: u64) -> (Self, u64) {
+        let lp_supply = sqrt_k(quote, token) as u64;
+        (Pool { quote, token, lp_supply }, lp_supply - LOCKED_LP)
+    }
+
+    /// Exact-input swap; returns the fee charged, in input units
+    fn swap(&mut self, amount_in: u64, quote_in: bool) -> u64 {
+        let fee = amount_in * FEE_BPS / 10_000;
+        let (reserve_in, reserve_out) = if quote_in {
+            (&mut self.quote, &mut self.token)
+        } else {
+            (&mut self.token, &mut self.quote)
+        };
+        let in_after_fee = (amount_in - fee) as u128;
+        let out = (*reserve_out as u128 * in_after_fee / (*reserve_in as u128 + in_after_fee)) as u64;
+        *reserve_in += amount_in;
+        *reserve_out -= out;
+        fee
+    }
+
+    /// Burn `lp` LP tokens; returns (quote, token) paid out
+    fn withdraw(&mut self, lp: u64) -> (u64, u64) {
+        let quote = (self.quote as u128 * lp as u128 / self.lp_supply as u128) as u64;
+        let token = (self.token as u128 * lp as u128 / self.lp_supply as u128) as u64;
+        self.quote -= quote;
+        self.token -= token;
+        self.lp_supply -= lp;
+        (quote, token)
+    }
+
+    fn growth(&self) -> u128 {
+        sqrt_k_per_lp_x64(self.quote, self.token, self.lp_supply).unwrap()
+    }
+
+    fn sqrt_k_of(&self, lp: u64) -> u128 {
+        lp_to_sqrt_k(lp, self.quote, self.token, self.lp_supply).unwrap()
+    }
+
+    /// Quote value of a (quote, token) pair at the pool price
+    fn quote_value(&self, quote: u64, token: u64) -> u128 {
+        quote as u128 + token as u128 * self.quote as u128 / self.token as u128
+    }
+}
+
+impl Rng {
+    /// Up to `max_bps` of `reserve`
+    fn trade(&mut self, reserve: u64, max_bps: u64) -> u64 {
+        (reserve as u128 * (1 + self.next_u64() % max_bps) as u128 / 10_000) as u64
+    }
+}
+
+/// Harvest the lock's fees the way CpmmAdapter::collect_fees does
+fn harvest(pool: &mut Pool, lp_held: &mut u64, principal: u128) -> (u64, u64) {
+    let fee_lp = fee_lp_amount(*lp_held, principal, pool.quote, pool.token, pool.lp_supply).unwrap();
+    *lp_held -= fee_lp;
+    pool.withdraw(fee_lp)
+}
+
+#[test]
+fn harvest_never_touches_principal() {
+    for seed in 1..=20u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let (mut pool, mut lp_held) = Pool::seed(
+            1_000_000_000_000 + rng.next_u64() % 1_000_000_000_000,
+            100_000_000_000_000_000 + rng.next_u64() % 100_000_000_000_000_000,
+        );
+        let principal = pool.sqrt_k_of(lp_held);
+        let principal_value = sqrt_k_quote_value(principal, pool.quote, pool.token).unwrap();
+
+        // Harvesting before any trade takes nothing of value
+        let (quote, token) = harvest(&mut pool, &mut lp_held, principal);
+        assert!(pool.quote_value(quote, token) <= 1, "seed {seed}: harvested {quote}/{token} with no trades");
+        assert!(pool.sqrt_k_of(lp_held) >= principal);
+
+        for round in 0..50 {
+            for _ in 0..(1 + rng.next_u64() % 10) {
+                if rng.next_u64().is_multiple_of(2) {
+                    let amount = rng.trade(pool.quote, 300);
+                    pool.swap(amount, true);
+                } else {
+                    let amount = rng.trade(pool.token, 300);
+                    pool.swap(amount, false);
+                }
+            }
+
+            let growth_before = pool.growth();
+            harvest(&mut pool, &mut lp_held, principal);
+
+            // The lock still owns at least its principal in sqrt(k) units...
+            assert!(pool.sqrt_k_of(lp_held) >= principal, "seed {seed} round {round}: principal dipped");
+            // ...withdrawing fees does not dilute the remaining LP tokens, beyond
+            // the floor of sqrt(k) itself (one unit spread over the supply)...
+            let rounding = (1u128 << 64) / pool.lp_supply as u128 + 1;
+            assert!(pool.growth() + rounding >= growth_before, "seed {seed} round {round}: growth fell");
+            // ...and the principal is still worth what it was, less price impact only
+            let value = sqrt_k_quote_value(pool.sqrt_k_of(lp_held), pool.quote, pool.token).unwrap();
+            assert!(value >= sqrt_k_quote_value(principal, pool.quote, pool.token).unwrap());
+        }
+
+        assert!(principal_value > 0);
+    }
+}
+
+#[test]
+fn fees_since_matches_simulated_swaps() {
+    for seed in 1..=20u64 {
+        let mut rng = Rng(seed.wrapping_mul(0xd1b5_4a32_d192_ed03));
+        let (mut pool, lp_held) = Pool::seed(5_000_000_000_000, 250_000_000_000_000_000);
+        // A third party holds part of the pool, so the lock only earns its share
+        let outside_lp = pool.lp_supply;
+        pool.quote *= 2;
+        pool.token *= 2;
+        pool.lp_supply += outside_lp;
+
+        let principal = pool.sqrt_k_of(lp_held);
+        let snapshot = pool.growth();
+        let lock_share = lp_held as f64 / pool.lp_supply as f64;
+
+        assert_eq!(fees_since(&pool, snapshot, principal), 0, "seed {seed}: fees with no trades");
+
+        // Round trips keep the price near where it started
+        let mut fees_quote_value = 0f64;
+        for _ in 0..200 {
+            let quote_in = rng.trade(pool.quote, 100);
+            let token_out_before = pool.token;
+            fees_quote_value += pool.swap(quote_in, true) as f64;
+            let token_in = token_out_before - pool.token;
+            let fee_tokens = pool.swap(token_in, false);
+            fees_quote_value += fee_tokens as f64 * pool.quote as f64 / pool.token as f64;
+        }
+
+        let expected = fees_quote_value * lock_share;
+        let measured = fees_since(&pool, snapshot, principal) as f64;
+        let error = (measured - expected).abs() / expected;
+        assert!(error < 0.01, "seed {seed}: fees_since {measured} vs simulated {expected}");
+
+        // What collect_fees would withdraw is worth the same fees
+        let mut lp = lp_held;
+        let (quote, token) = harvest(&mut pool, &mut lp, principal);
+        let harvested = pool.quote_value(quote, token) as f64;
+        let error = (harvested - expected).abs() / expected;
+        assert!(error < 0.01, "seed {seed}: harvested {harvested} vs simulated {expected}");
+    }
+}
+
+/// CpmmAdapter::fees_since for a pool with the quote on the `quote` side
+fn fees_since(pool: &Pool, snapshot: u128, liquidity: u128) -> u128 {
+    let fee_sqrt_k = fee_sqrt_k_since(pool.growth(), snapshot, liquidity).unwrap();
+    sqrt_k_quote_value(fee_sqrt_k, pool.quote, pool.token).unwrap()
+}
+
+#[test]
+fn lock_reserves_are_its_lp_share_of_a_shared_pool() {
+    for seed in 1..=20u64 {
+        let mut rng = Rng(seed.wrapping_mul(0x94d0_49bb_1331_11eb));
+        let (mut pool, lp_held) = Pool::seed(
+            1_000_000_000_000 + rng.next_u64() % 1_000_000_000_000,
+            100_000_000_000_000_000 + rng.next_u64() % 100_000_000_000_000_000,
+        );
+        // A third party adds as much again as the lock holds
+        let outside_lp = pool.lp_supply;
+        pool.quote *= 2;
+        pool.token *= 2;
+        pool.lp_supply += outside_lp;
+        for _ in 0..20 {
+            let amount = rng.trade(pool.quote, 300);
+            pool.swap(amount, true);
+        }
+
+        // The lock's reserves are what withdrawing its LP tokens pays out
+        let (quote, token) = lp_reserves(lp_held, pool.quote, pool.token, pool.lp_supply).unwrap();
+        assert!(quote <= pool.quote / 2 && token <= pool.token / 2, "seed {seed}: more than the lock's share");
+        let (paid_quote, paid_token) = pool.withdraw(lp_held);
+        assert_eq!((quote, token), (paid_quote, paid_token), "seed {seed}");
+    }
+
+    assert_eq!(lp_reserves(0, 1_000, 1_000, 0), Some((0, 0)));
+}
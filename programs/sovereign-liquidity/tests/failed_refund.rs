@@ -0,0 +1,106 @@
+//! Refunds after a failed raise, including a finalization that stalled after
+//! the pool was created and an LP ladder rung was opened and unwound.
+//!
+//! There is no on-chain harness here: the vault and lock balances are tracked
+//! by hand and the program's own sizing/refund helpers decide the amounts.
+
+use anchor_lang::prelude::Pubkey;
+use sovereign_liquidity::state::{LpRung, PermanentLock, SovereignState};
+
+const DEPOSITS: [u64; 4] = [1_000_000_000, 2_500_000_000, 333_333_333, 6_166_666_667];
+const CREATOR_ESCROW: u64 = 50_000_000;
+
+fn raised_sovereign() -> SovereignState {
+    let mut sovereign = SovereignState {
+        total_deposited: DEPOSITS.iter().sum(),
+        creator_escrow: CREATOR_ESCROW,
+        ..Default::default()
+    };
+    sovereign.lp_ladder[0] = LpRung { share_bps: 2_000, band_bps: 0 };
+    sovereign
+}
+
+/// Run withdraw_failed for every depositor in order, returning the refunds
+fn refund_all(sovereign: &mut SovereignState, vault: &mut u64) -> Vec<u64> {
+    DEPOSITS
+        .iter()
+        .map(|&amount| {
+            let refund = sovereign.failed_refund_amount(amount, *vault).unwrap();
+            assert!(*vault >= refund, "vault cannot cover refund");
+            *vault -= refund;
+            sovereign.total_deposited -= amount;
+            refund
+        })
+        .collect()
+}
+
+#[test]
+fn refunds_exact_deposits_before_pool_creation() {
+    let mut sovereign = raised_sovereign();
+    let mut vault = sovereign.total_deposited + CREATOR_ESCROW;
+
+    let refunds = refund_all(&mut sovereign, &mut vault);
+
+    assert_eq!(refunds, DEPOSITS);
+    assert_eq!(vault, CREATOR_ESCROW);
+}
+
+#[test]
+fn open_rung_stall_unwind_refunds_pro_rata() {
+    let mut sovereign = raised_sovereign();
+    let mut vault = sovereign.total_deposited + CREATOR_ESCROW;
+    let mut lock = PermanentLock::default();
+
+    // finalize_create_pool
+    sovereign.pool_state = Pubkey::new_unique();
+
+    // open_ladder_position(0): the rung's share of the undeployed quote
+    let rung_quote = sovereign.ladder_rung_amount(0, vault - sovereign.creator_escrow);
+    vault -= rung_quote;
+    lock.position_count = 1;
+
+    // mark_finalization_stalled, then unwind_ladder_position at a 7% loss
+    let unwound_quote = rung_quote - rung_quote * 7 / 100;
+    lock.positions_unwound = 1;
+    assert!(lock.is_ladder_unwound());
+    assert!(!lock.is_ladder_refunded(), "refunds must wait for the ladder proceeds");
+
+    // refund_ladder_proceeds
+    vault += unwound_quote;
+    lock.ladder_proceeds_refunded = true;
+    assert!(lock.is_ladder_refunded());
+
+    let total_deposited = sovereign.total_deposited;
+    let refundable = vault - CREATOR_ESCROW;
+    assert!(refundable < total_deposited);
+
+    let refunds = refund_all(&mut sovereign, &mut vault);
+
+    // Every depositor takes the same haircut, within rounding
+    for (&deposit, &refund) in DEPOSITS.iter().zip(&refunds) {
+        let fair = (deposit as u128 * refundable as u128 / total_deposited as u128) as u64;
+        assert!(refund > 0, "depositor got nothing");
+        assert!(refund.abs_diff(fair) <= 1, "refund {refund} vs fair share {fair}");
+    }
+
+    // Nothing stranded: the creator's escrow is all that is left
+    assert_eq!(refunds.iter().sum::<u64>(), refundable);
+    assert_eq!(vault, CREATOR_ESCROW);
+    assert_eq!(sovereign.total_deposited, 0);
+}
+
+#[test]
+fn creator_withdrawing_first_does_not_change_refunds() {
+    let mut sovereign = raised_sovereign();
+    sovereign.pool_state = Pubkey::new_unique();
+    let mut vault = sovereign.total_deposited + CREATOR_ESCROW - 123_456_789;
+
+    let mut early = sovereign.clone();
+    let mut early_vault = vault - CREATOR_ESCROW;
+    early.creator_escrow = 0;
+
+    let after_creator = refund_all(&mut early, &mut early_vault);
+    let before_creator = refund_all(&mut sovereign, &mut vault);
+
+    assert_eq!(after_creator, before_creator);
+}
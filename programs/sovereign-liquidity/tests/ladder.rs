@@ -0,0 +1,164 @@
+//! LP ladder rung sizing, and the order the lock's positions are opened,
+//! harvested and drained in.
+//!
+//! The SAMM CPIs are not run: each instruction's guards and lock-state updates
+//! are the PermanentLock / LockedPosition methods it calls.
+
+mod common;
+
+use sovereign_liquidity::constants::{BPS_DENOMINATOR, MAX_LP_LADDER_RUNGS, MIN_PRIMARY_LP_SHARE_BPS};
+use sovereign_liquidity::state::{LockedPosition, LpRung, PermanentLock, SovereignState};
+use common::Rng;
+
+fn laddered(shares: &[u16]) -> SovereignState {
+    let mut sovereign = SovereignState::default();
+    for (slot, &share_bps) in sovereign.lp_ladder.iter_mut().zip(shares) {
+        *slot = LpRung { share_bps, band_bps: 0 };
+    }
+    sovereign
+}
+
+/// open_ladder_position for every rung in order, returning each rung's
+/// amount and what is left for the primary position
+fn open_all(sovereign: &SovereignState, total: u64) -> (Vec<u64>, u64) {
+    let mut available = total;
+    let amounts = (0..sovereign.ladder_rung_count())
+        .map(|index| {
+            let amount = sovereign.ladder_rung_amount(index, available);
+            available -= amount;
+            amount
+        })
+        .collect();
+    (amounts, available)
+}
+
+#[test]
+fn share_remaining_counts_earlier_rungs() {
+    let sovereign = laddered(&[2_000, 3_500, 500]);
+
+    assert_eq!(sovereign.ladder_rung_count(), 3);
+    assert_eq!(sovereign.ladder_share_remaining(0), 10_000);
+    assert_eq!(sovereign.ladder_share_remaining(1), 8_000);
+    assert_eq!(sovereign.ladder_share_remaining(2), 4_500);
+
+    // Rung amounts of an evenly divisible raise are exact
+    let (amounts, primary) = open_all(&sovereign, 1_000_000);
+    assert_eq!(amounts, [200_000, 350_000, 50_000]);
+    assert_eq!(primary, 400_000);
+}
+
+#[test]
+fn rung_count_stops_at_first_unused_rung() {
+    assert_eq!(SovereignState::default().ladder_rung_count(), 0);
+    assert_eq!(laddered(&[4_000]).ladder_rung_count(), 1);
+    assert_eq!(laddered(&[4_000, 0, 1_000]).ladder_rung_count(), 1);
+}
+
+#[test]
+fn rung_rounding_never_shortchanges_later_positions() {
+    let mut rng = Rng(0x5eed_1adde7);
+    for case in 0..2_000 {
+        let rungs = 1 + (rng.next_u64() % MAX_LP_LADDER_RUNGS as u64) as usize;
+        // Up to the most create_sovereign allows, leaving the primary its minimum
+        let mut room = BPS_DENOMINATOR - MIN_PRIMARY_LP_SHARE_BPS;
+        let mut shares = Vec::new();
+        while shares.len() < rungs && room > 0 {
+            let share = 1 + (rng.next_u64() % room.min(5_000) as u64) as u16;
+            room -= share;
+            shares.push(share);
+        }
+        let sovereign = laddered(&shares);
+        let total = rng.next_u64() % 1_000_000_000_000_000 + rng.next_u64() % 1_000;
+
+        let (amounts, primary) = open_all(&sovereign, total);
+
+        // Every rung gets at least its share of the whole raise, and at most
+        // one base unit more per earlier rung
+        for (index, (&share, &amount)) in shares.iter().zip(&amounts).enumerate() {
+            let fair = (total as u128 * share as u128 / BPS_DENOMINATOR as u128) as u64;
+            assert!(
+                amount >= fair && amount <= fair + index as u64,
+                "case {case}: rung {index} got {amount}, fair share {fair}"
+            );
+        }
+
+        // Nothing is lost, and the primary position keeps at least its share
+        let ladder_share: u64 = shares.iter().map(|&share| share as u64).sum();
+        let primary_fair = (total as u128 * (BPS_DENOMINATOR as u64 - ladder_share) as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        assert_eq!(amounts.iter().sum::<u64>() + primary, total);
+        assert!(primary >= primary_fair, "case {case}: primary got {primary}, fair share {primary_fair}");
+    }
+}
+
+#[test]
+fn rungs_open_in_order_before_the_primary() {
+    let sovereign = laddered(&[2_000, 3_000]);
+    let rung_count = sovereign.ladder_rung_count();
+    let mut lock = PermanentLock::default();
+
+    // finalize_add_liquidity waits for every rung
+    assert_ne!(lock.position_count, rung_count);
+
+    // Rungs cannot be skipped, reopened or opened past the ladder
+    assert!(!lock.is_next_rung(1, rung_count));
+    assert!(lock.is_next_rung(0, rung_count));
+    lock.record_rung_opened(500, -600, 600).unwrap();
+    assert!(!lock.is_next_rung(0, rung_count));
+    assert!(lock.is_next_rung(1, rung_count));
+    lock.record_rung_opened(700, -1200, 1200).unwrap();
+    assert!(!lock.is_next_rung(2, rung_count));
+
+    assert_eq!(lock.position_count, rung_count);
+    assert_eq!(lock.ladder_liquidity, 1_200);
+}
+
+#[test]
+fn collect_and_unwind_follow_open() {
+    let rung_count = 2;
+    let mut lock = PermanentLock { liquidity: 1_000, tick_lower_index: -60, tick_upper_index: 60, ..Default::default() };
+    let mut positions: Vec<LockedPosition> = Vec::new();
+
+    // A lock with no ladder has nothing to drain or refund first
+    assert!(lock.is_ladder_unwound());
+    assert!(lock.is_ladder_refunded());
+
+    for index in 0..rung_count {
+        assert!(lock.is_next_rung(index, rung_count));
+        lock.record_rung_opened(500, -600, 600).unwrap();
+        positions.push(LockedPosition { index, liquidity: 500, ..Default::default() });
+    }
+
+    // The solvency floor sells through the primary and every rung
+    assert_eq!(lock.positions(), [(1_000, -60, 60), (500, -600, 600), (500, -600, 600)]);
+    assert_eq!(lock.total_liquidity(), 2_000);
+
+    // collect_ladder_fees and unwind_ladder_position only take undrained rungs;
+    // the primary (execute_unwind / emergency removal) waits for all of them
+    assert!(!lock.is_ladder_unwound());
+    for position in positions.iter_mut().rev() {
+        assert!(!position.unwound, "rung {} drained twice", position.index);
+        position.record_unwound(1_700_000_000);
+        lock.record_rung_unwound(position.index).unwrap();
+    }
+    assert!(lock.is_ladder_unwound());
+    assert!(positions.iter().all(|position| position.unwound && position.liquidity == 0));
+
+    // Drained rungs no longer count toward the solvency floor
+    assert_eq!(lock.positions(), [(1_000, -60, 60)]);
+
+    // withdraw_failed additionally waits for refund_ladder_proceeds
+    assert!(!lock.is_ladder_refunded());
+    lock.ladder_proceeds_refunded = true;
+    assert!(lock.is_ladder_refunded());
+}
+
+#[test]
+fn ladder_holds_at_most_max_rungs() {
+    let mut lock = PermanentLock::default();
+    for _ in 0..MAX_LP_LADDER_RUNGS {
+        lock.record_rung_opened(1, -60, 60).unwrap();
+    }
+    assert!(lock.record_rung_opened(1, -60, 60).is_err());
+    assert!(lock.record_rung_unwound(MAX_LP_LADDER_RUNGS as u8).is_err());
+}
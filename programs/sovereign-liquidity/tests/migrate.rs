@@ -0,0 +1,105 @@
+//! Accounts created before the appended fields: migrate_* grows them to LEN
+//! with zeroed bytes, then backfills the fields whose zero value would change
+//! the account's behaviour.
+//!
+//! The old accounts are built by serializing with the appended fields at their
+//! defaults and sizing the data to what the account was created with.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use sovereign_liquidity::constants::SAMM_PROGRAM_ID;
+use sovereign_liquidity::state::{DepositRecord, PermanentLock, SovereignState, SovereignStatus};
+
+/// Account sizes before the appended fields (the old LEN, padding included)
+const SOVEREIGN_V1_LEN: usize = 823;
+const DEPOSIT_RECORD_V1_LEN: usize = 185;
+const PERMANENT_LOCK_V1_LEN: usize = 226;
+
+/// Serialize `account` as an old account of `old_len` bytes, then grow and
+/// decode it the way migrate_* does
+fn migrate<T: AccountSerialize + AccountDeserialize>(account: &T, old_len: usize, len: usize) -> T {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    assert!(old_len < len);
+    // Strings are stored at their length, so the data can end before old_len;
+    // anything past it must be the zeroed appended fields
+    assert!(data.iter().skip(old_len).all(|&byte| byte == 0), "appended fields must start zeroed");
+
+    data.resize(old_len, 0);
+    data.resize(len, 0);
+    T::try_deserialize(&mut &data[..]).unwrap()
+}
+
+#[test]
+fn old_sovereign_keeps_its_fields_and_backfills_the_rest() {
+    let old = SovereignState {
+        sovereign_id: 7,
+        creator: Pubkey::new_unique(),
+        token_mint: Pubkey::new_unique(),
+        state: SovereignStatus::Bonding,
+        bond_target: 100_000_000_000,
+        total_deposited: 42_000_000_000,
+        depositor_count: 3,
+        bump: 254,
+        ..Default::default()
+    };
+
+    let mut sovereign = migrate(&old, SOVEREIGN_V1_LEN, SovereignState::LEN);
+    assert_eq!(sovereign.sovereign_id, 7);
+    assert_eq!(sovereign.creator, old.creator);
+    assert_eq!(sovereign.total_deposited, old.total_deposited);
+    assert_eq!(sovereign.bump, 254);
+
+    sovereign.backfill_appended_fields();
+    // Hard cap only, every deposit at 1x, liquidity on the SAMM
+    assert_eq!(sovereign.soft_cap, sovereign.bond_target);
+    assert_eq!(sovereign.total_share_units, sovereign.total_deposited);
+    assert_eq!(sovereign.amm_program, SAMM_PROGRAM_ID);
+    assert!(sovereign.is_native_quote());
+    assert!(!sovereign.uses_existing_pool());
+    assert_eq!(sovereign.ladder_rung_count(), 0);
+}
+
+#[test]
+fn old_deposit_record_counts_at_one_x() {
+    let old = DepositRecord {
+        sovereign: Pubkey::new_unique(),
+        depositor: Pubkey::new_unique(),
+        amount: 1_500_000_000,
+        bump: 253,
+        ..Default::default()
+    };
+
+    let mut record = migrate(&old, DEPOSIT_RECORD_V1_LEN, DepositRecord::LEN);
+    assert_eq!(record.amount, old.amount);
+    assert_eq!(record.bump, 253);
+    assert!(!record.has_referrer());
+
+    record.backfill_appended_fields();
+    assert_eq!(record.share_units, record.amount);
+
+    // Shares match the sovereign's backfilled total
+    let sovereign = SovereignState { total_deposited: 3_000_000_000, total_share_units: 3_000_000_000, ..Default::default() };
+    assert_eq!(sovereign.share_bps(record.share_units), Some(5_000));
+}
+
+#[test]
+fn old_permanent_lock_has_no_ladder() {
+    let old = PermanentLock {
+        sovereign: Pubkey::new_unique(),
+        liquidity: 1_000_000,
+        tick_lower_index: -443_580,
+        tick_upper_index: 443_580,
+        bump: 252,
+        ..Default::default()
+    };
+
+    let lock = migrate(&old, PERMANENT_LOCK_V1_LEN, PermanentLock::LEN);
+    assert_eq!(lock.liquidity, old.liquidity);
+    assert_eq!(lock.bump, 252);
+    assert_eq!(lock.position_count, 0);
+    assert_eq!(lock.retained_quote, 0);
+    assert!(lock.is_ladder_unwound());
+    assert!(lock.is_ladder_refunded());
+    assert_eq!(lock.positions(), [(1_000_000, -443_580, 443_580)]);
+}
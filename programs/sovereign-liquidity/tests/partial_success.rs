@@ -0,0 +1,42 @@
+//! Which missed raises wait out the partial success vote before
+//! mark_bonding_failed: only those vote_partial_success could accept.
+
+use sovereign_liquidity::state::SovereignState;
+
+const MIN_BOND_TARGET: u64 = 50_000_000_000;
+
+fn missed_raise(total_deposited: u64, depositor_count: u32, min_depositor_count: u32) -> SovereignState {
+    SovereignState {
+        bond_target: 200_000_000_000,
+        soft_cap: 150_000_000_000,
+        total_deposited,
+        depositor_count,
+        min_depositor_count,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn raise_above_the_minimum_can_be_accepted() {
+    let sovereign = missed_raise(MIN_BOND_TARGET, 12, 10);
+    assert!(!sovereign.is_soft_cap_met());
+    assert!(sovereign.is_partial_raise_acceptable(MIN_BOND_TARGET));
+}
+
+#[test]
+fn raise_below_the_minimum_fails_without_a_vote() {
+    let sovereign = missed_raise(MIN_BOND_TARGET - 1, 12, 10);
+    assert!(!sovereign.is_partial_raise_acceptable(MIN_BOND_TARGET));
+}
+
+#[test]
+fn raise_with_too_few_depositors_fails_without_a_vote() {
+    let sovereign = missed_raise(100_000_000_000, 9, 10);
+    assert!(!sovereign.is_partial_raise_acceptable(MIN_BOND_TARGET));
+}
+
+#[test]
+fn empty_raise_fails_without_a_vote() {
+    let sovereign = missed_raise(0, 0, 0);
+    assert!(!sovereign.is_partial_raise_acceptable(0));
+}
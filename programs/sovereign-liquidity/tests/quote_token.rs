@@ -0,0 +1,201 @@
+//! Token quote sovereigns: resolving the optional quote accounts, and the
+//! vault balance and refund sizing deposit, withdraw and withdraw_failed use.
+//!
+//! Accounts are built by hand from the SPL Token / Token-2022 layouts. The
+//! transfers (quote::collect / quote::pay_out) are CPIs and are not run here;
+//! their effect is written to the vault's data directly.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use sovereign_liquidity::errors::SovereignError;
+use sovereign_liquidity::quote::{self, QuoteToken};
+use sovereign_liquidity::state::SovereignState;
+
+use common::account;
+
+const DECIMALS: u8 = 6;
+/// Protocol-wide native minimum; token quote sovereigns use their own
+const PROTOCOL_MIN_DEPOSIT: u64 = 100_000_000;
+const QUOTE_MIN_DEPOSIT: u64 = 10_000_000;
+
+fn mint(key: Pubkey, token_program: Pubkey) -> InterfaceAccount<'static, Mint> {
+    let mut data = vec![0; spl_token_2022::state::Mint::LEN];
+    spl_token_2022::state::Mint {
+        mint_authority: COption::None,
+        supply: 1_000_000_000_000,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    InterfaceAccount::try_from(account(key, token_program, 1, data, false)).unwrap()
+}
+
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64, token_program: Pubkey) -> InterfaceAccount<'static, TokenAccount> {
+    let mut data = vec![0; spl_token_2022::state::Account::LEN];
+    spl_token_2022::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token_2022::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    InterfaceAccount::try_from(account(Pubkey::new_unique(), token_program, 1, data, false)).unwrap()
+}
+
+fn token_program(id: Pubkey) -> Interface<'static, TokenInterface> {
+    Interface::try_from(account(id, Pubkey::default(), 1, vec![], true)).unwrap()
+}
+
+/// Apply a token transfer in or out of `vault` to its account data
+fn move_tokens(vault: &AccountInfo, amount_in: u64, amount_out: u64) {
+    let mut data = vault.try_borrow_mut_data().unwrap();
+    let mut state = spl_token_2022::state::Account::unpack_from_slice(&data).unwrap();
+    state.amount = state.amount + amount_in - amount_out;
+    state.pack_into_slice(&mut data);
+}
+
+fn token_sovereign(quote_mint: Pubkey) -> SovereignState {
+    SovereignState {
+        quote_mint,
+        quote_min_deposit: QUOTE_MIN_DEPOSIT,
+        bond_target: 1_000_000_000,
+        ..Default::default()
+    }
+}
+
+struct Fixture {
+    sovereign: SovereignState,
+    mint: InterfaceAccount<'static, Mint>,
+    vault: InterfaceAccount<'static, TokenAccount>,
+    program: Interface<'static, TokenInterface>,
+}
+
+fn fixture(program_id: Pubkey) -> Fixture {
+    let mint_key = Pubkey::new_unique();
+    Fixture {
+        sovereign: token_sovereign(mint_key),
+        mint: mint(mint_key, program_id),
+        vault: token_account(mint_key, Pubkey::new_unique(), 0, program_id),
+        program: token_program(program_id),
+    }
+}
+
+fn resolve_err(
+    sovereign: &SovereignState,
+    mint: Option<&InterfaceAccount<'static, Mint>>,
+    vault: Option<&InterfaceAccount<'static, TokenAccount>>,
+    program: Option<&Interface<'static, TokenInterface>>,
+) -> Error {
+    match QuoteToken::resolve(sovereign, mint, vault, program) {
+        Ok(_) => panic!("resolve accepted the accounts"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn resolves_spl_token_and_token_2022_quotes() {
+    for program_id in [spl_token::ID, spl_token_2022::ID] {
+        let f = fixture(program_id);
+        let quote = QuoteToken::resolve(&f.sovereign, Some(&f.mint), Some(&f.vault), Some(&f.program))
+            .unwrap()
+            .expect("token quote sovereign resolved to native");
+
+        assert_eq!(quote.mint.key(), f.sovereign.quote_mint);
+        assert_eq!(quote.vault.key(), f.vault.key());
+        assert_eq!(quote.token_program.key(), program_id);
+        assert_eq!(quote.decimals, DECIMALS);
+    }
+}
+
+#[test]
+fn native_sovereign_ignores_quote_accounts() {
+    let f = fixture(spl_token::ID);
+    let native = SovereignState::default();
+
+    assert!(QuoteToken::resolve(&native, None, None, None).unwrap().is_none());
+    assert!(QuoteToken::resolve(&native, Some(&f.mint), Some(&f.vault), Some(&f.program)).unwrap().is_none());
+}
+
+#[test]
+fn rejects_missing_quote_accounts() {
+    let f = fixture(spl_token::ID);
+    let required: Error = SovereignError::QuoteAccountsRequired.into();
+
+    assert_eq!(resolve_err(&f.sovereign, None, None, None), required);
+    assert_eq!(resolve_err(&f.sovereign, None, Some(&f.vault), Some(&f.program)), required);
+    assert_eq!(resolve_err(&f.sovereign, Some(&f.mint), None, Some(&f.program)), required);
+    assert_eq!(resolve_err(&f.sovereign, Some(&f.mint), Some(&f.vault), None), required);
+}
+
+#[test]
+fn rejects_mismatched_quote_accounts() {
+    let f = fixture(spl_token::ID);
+    let invalid: Error = SovereignError::InvalidQuoteMint.into();
+
+    // A mint other than the sovereign's quote mint
+    let other_mint = mint(Pubkey::new_unique(), spl_token::ID);
+    assert_eq!(resolve_err(&f.sovereign, Some(&other_mint), Some(&f.vault), Some(&f.program)), invalid);
+
+    // A vault holding some other mint
+    let other_vault = token_account(other_mint.key(), Pubkey::new_unique(), 0, spl_token::ID);
+    assert_eq!(resolve_err(&f.sovereign, Some(&f.mint), Some(&other_vault), Some(&f.program)), invalid);
+
+    // A token program that does not own the mint
+    let token_2022 = token_program(spl_token_2022::ID);
+    assert_eq!(resolve_err(&f.sovereign, Some(&f.mint), Some(&f.vault), Some(&token_2022)), invalid);
+}
+
+#[test]
+fn token_quote_balances_and_refunds_track_the_vault() {
+    let f = fixture(spl_token_2022::ID);
+    let mut sovereign = f.sovereign;
+    let quote = QuoteToken::resolve(&sovereign, Some(&f.mint), Some(&f.vault), Some(&f.program))
+        .unwrap()
+        .unwrap();
+    // Lamports in the sol_vault are not quote once the sovereign bonds in a token
+    let sol_vault = account(Pubkey::new_unique(), Pubkey::default(), 890_880, vec![], false);
+
+    // Token quote minimums come from the allowlist, not the native protocol minimum
+    assert_eq!(sovereign.min_deposit(PROTOCOL_MIN_DEPOSIT), QUOTE_MIN_DEPOSIT);
+
+    // deposit: three investors
+    let mut deposits = [250_000_000u64, 40_000_000, 125_000_000];
+    for &amount in &deposits {
+        assert!(amount >= sovereign.min_deposit(PROTOCOL_MIN_DEPOSIT));
+        move_tokens(&quote.vault, amount, 0);
+        sovereign.total_deposited += amount;
+        sovereign.depositor_count += 1;
+    }
+    assert_eq!(quote::vault_balance(Some(&quote), sol_vault).unwrap(), sovereign.total_deposited);
+
+    // withdraw: the second investor takes part of their deposit back during bonding
+    let withdrawn = 15_000_000;
+    assert!(quote::vault_balance(Some(&quote), sol_vault).unwrap() >= withdrawn);
+    move_tokens(&quote.vault, 0, withdrawn);
+    deposits[1] -= withdrawn;
+    sovereign.total_deposited -= withdrawn;
+    assert_eq!(quote.vault_balance().unwrap(), sovereign.total_deposited);
+
+    // withdraw_failed: the raise failed before a pool was created, so each
+    // depositor gets exactly what they still have deposited
+    for &amount in &deposits {
+        let vault_balance = quote::vault_balance(Some(&quote), sol_vault).unwrap();
+        let refund = sovereign.failed_refund_amount(amount, vault_balance).unwrap();
+        assert_eq!(refund, amount);
+        assert!(vault_balance >= refund);
+        move_tokens(&quote.vault, 0, refund);
+        sovereign.total_deposited -= amount;
+    }
+
+    assert_eq!(quote.vault_balance().unwrap(), 0);
+    assert_eq!(sovereign.total_deposited, 0);
+    assert_eq!(sol_vault.lamports(), 890_880, "native vault touched by a token quote sovereign");
+}
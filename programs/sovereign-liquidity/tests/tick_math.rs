@@ -0,0 +1,408 @@
+//! Property tests for the integer Q64.64 tick and liquidity math.
+//!
+//! Reference vectors come from Raydium CLMM's `tick_math` (exact outputs) and from
+//! a 100-digit evaluation of sqrt(1.0001^tick) * 2^64 (true values).
+
+mod common;
+
+use sovereign_liquidity::samm::tick;
+use sovereign_liquidity::samm::tick_math::*;
+use common::Rng;
+
+/// (tick, Raydium CLMM sqrt_price_x64, floor of the true sqrt_price_x64)
+const SQRT_PRICE_VECTORS: [(i32, u128, u128); 15] = [
+    (-443636, 4295048016, 4295048016),
+    (-443630, 4296336659, 4296336659),
+    (-200000, 837899702512935, 837899702510258),
+    (-50000, 1514390236238525528, 1514390236237315696),
+    (-1000, 17547129613991882732, 17547129613991598781),
+    (-10, 18437523468038803493, 18437523468038800958),
+    (-1, 18445821805675395072, 18445821805675392311),
+    (0, 18446744073709551616, 18446744073709551616),
+    (1, 18447666387855957090, 18447666387855959850),
+    (10, 18455969290605287889, 18455969290605290427),
+    (1000, 19392480388906522465, 19392480388906836277),
+    (50000, 224699260981858280811, 224699260982037790824),
+    (200000, 406113483392345977776134, 406113483393643373014939),
+    (443630, 79202910276621891576810579599, 79202910266154922620747521260),
+    (443636, 79226673521066979257578248091, 79226673515401279992447579061),
+];
+
+impl Rng {
+    fn tick(&mut self) -> i32 {
+        let span = (2 * 443636 + 1) as u64;
+        (self.next_u64() % span) as i32 - 443636
+    }
+
+    fn amount(&mut self) -> u64 {
+        // Spread amounts across magnitudes, not just near u64::MAX
+        let bits = 1 + self.next_u64() % 64;
+        let amount = self.next_u64() >> (64 - bits);
+        amount.max(1)
+    }
+}
+
+const ITERATIONS: usize = 2_000;
+
+#[test]
+fn sqrt_price_matches_reference_vectors() {
+    for (t, raydium, exact) in SQRT_PRICE_VECTORS {
+        let sqrt_price = get_sqrt_price_at_tick(t).unwrap();
+        assert_eq!(sqrt_price, raydium, "tick {}", t);
+
+        // Within 1e-9 of the true value across the whole range
+        let error = sqrt_price.abs_diff(exact);
+        assert!(error <= exact / 1_000_000_000, "tick {} off by {}", t, error);
+    }
+
+    assert_eq!(get_sqrt_price_at_tick(-443636).unwrap(), MIN_SQRT_PRICE_X64);
+    assert_eq!(get_sqrt_price_at_tick(443636).unwrap(), MAX_SQRT_PRICE_X64);
+    assert!(get_sqrt_price_at_tick(-443637).is_err());
+    assert!(get_sqrt_price_at_tick(443637).is_err());
+}
+
+#[test]
+fn tick_at_sqrt_price_matches_reference_vectors() {
+    for (t, raydium, _) in SQRT_PRICE_VECTORS {
+        if raydium < MAX_SQRT_PRICE_X64 {
+            assert_eq!(get_tick_at_sqrt_price(raydium).unwrap(), t);
+        }
+    }
+
+    assert_eq!(get_tick_at_sqrt_price(MIN_SQRT_PRICE_X64).unwrap(), -443636);
+    assert_eq!(get_tick_at_sqrt_price(MAX_SQRT_PRICE_X64 - 1).unwrap(), 443635);
+    assert!(get_tick_at_sqrt_price(MIN_SQRT_PRICE_X64 - 1).is_err());
+    assert!(get_tick_at_sqrt_price(MAX_SQRT_PRICE_X64).is_err());
+}
+
+#[test]
+fn sqrt_price_is_strictly_increasing() {
+    let mut rng = Rng(0x5eed_0001);
+    for _ in 0..ITERATIONS {
+        let t = rng.tick().min(443635);
+        let lower = get_sqrt_price_at_tick(t).unwrap();
+        let upper = get_sqrt_price_at_tick(t + 1).unwrap();
+        assert!(lower < upper, "tick {}", t);
+    }
+}
+
+#[test]
+fn tick_round_trips_through_sqrt_price() {
+    let mut rng = Rng(0x5eed_0002);
+    for _ in 0..ITERATIONS {
+        let t = rng.tick().min(443635);
+        let lower = get_sqrt_price_at_tick(t).unwrap();
+        let upper = get_sqrt_price_at_tick(t + 1).unwrap();
+
+        // Every price in [sqrt(t), sqrt(t + 1)) maps back to tick t
+        assert_eq!(get_tick_at_sqrt_price(lower).unwrap(), t);
+        assert_eq!(get_tick_at_sqrt_price(upper - 1).unwrap(), t);
+        let inside = lower + (rng.next_u64() as u128) % (upper - lower);
+        assert_eq!(get_tick_at_sqrt_price(inside).unwrap(), t);
+    }
+}
+
+#[test]
+fn mul_div_matches_native_arithmetic() {
+    let mut rng = Rng(0x5eed_0003);
+    for _ in 0..ITERATIONS {
+        let a = rng.next_u64() as u128;
+        let b = rng.next_u64() as u128;
+        let d = (rng.next_u64() as u128).max(1);
+        let floor = a * b / d;
+        let ceil = floor + u128::from(!(a * b).is_multiple_of(d));
+        assert_eq!(mul_div_floor(a, b, d), Some(floor));
+        assert_eq!(mul_div_ceil(a, b, d), Some(ceil));
+    }
+
+    // 256-bit intermediates
+    assert_eq!(mul_div_floor(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+    assert_eq!(mul_div_floor(u128::MAX, Q64, Q64 * 2), Some(u128::MAX / 2));
+    assert_eq!(mul_div_ceil(u128::MAX, 3, 4), Some(u128::MAX / 4 * 3 + 3));
+    assert_eq!(mul_div_floor(u128::MAX, 5, 4), None);
+    assert_eq!(mul_div_floor(1, 1, 0), None);
+}
+
+#[test]
+fn sqrt_price_from_amounts_is_floor_sqrt() {
+    let mut rng = Rng(0x5eed_0004);
+    for _ in 0..ITERATIONS {
+        let amount_0 = rng.amount();
+        let amount_1 = rng.amount();
+        let Ok(sqrt_price) = sqrt_price_x64_from_amounts(amount_0, amount_1) else {
+            continue;
+        };
+
+        // sqrt_price^2 <= amount_1 * 2^128 / amount_0 < (sqrt_price + 1)^2, checked in Q64
+        let price_x64 = mul_div_floor(amount_1 as u128, Q64, amount_0 as u128).unwrap();
+        assert!(mul_div_floor(sqrt_price, sqrt_price, Q64).unwrap() <= price_x64);
+        assert!(mul_div_ceil(sqrt_price + 1, sqrt_price + 1, Q64).unwrap() >= price_x64);
+    }
+
+    assert_eq!(sqrt_price_x64_from_amounts(1, 1).unwrap(), Q64);
+    assert_eq!(sqrt_price_x64_from_amounts(1, 4).unwrap(), 2 * Q64);
+    assert_eq!(sqrt_price_x64_from_amounts(4, 1).unwrap(), Q64 / 2);
+    assert!(sqrt_price_x64_from_amounts(0, 1).is_err());
+}
+
+#[test]
+fn liquidity_never_needs_more_than_the_amounts() {
+    let mut rng = Rng(0x5eed_0005);
+    let sqrt_lower = get_sqrt_price_at_tick(tick::MIN_TICK).unwrap();
+    let sqrt_upper = get_sqrt_price_at_tick(tick::MAX_TICK).unwrap();
+
+    for _ in 0..ITERATIONS {
+        let amount_0 = rng.amount();
+        let amount_1 = rng.amount();
+        let Ok(sqrt_price) = sqrt_price_x64_from_amounts(amount_0, amount_1) else {
+            continue;
+        };
+        if sqrt_price <= sqrt_lower || sqrt_price >= sqrt_upper {
+            continue;
+        }
+
+        let liquidity = full_range_liquidity(sqrt_price, amount_0, amount_1).unwrap();
+
+        // Depositing that liquidity (amounts rounded up, as the SAMM does) fits the budget
+        let need_0 = get_delta_amount_0_unsigned(sqrt_price, sqrt_upper, liquidity, true).unwrap();
+        let need_1 = get_delta_amount_1_unsigned(sqrt_lower, sqrt_price, liquidity, true).unwrap();
+        assert!(need_0 <= amount_0, "need_0 {} > {}", need_0, amount_0);
+        assert!(need_1 <= amount_1, "need_1 {} > {}", need_1, amount_1);
+
+        // Rounding down never exceeds rounding up
+        let floor_0 = get_delta_amount_0_unsigned(sqrt_price, sqrt_upper, liquidity, false).unwrap();
+        let floor_1 = get_delta_amount_1_unsigned(sqrt_lower, sqrt_price, liquidity, false).unwrap();
+        assert!(floor_0 <= need_0 && need_0 - floor_0 <= 1);
+        assert!(floor_1 <= need_1 && need_1 - floor_1 <= 1);
+    }
+}
+
+#[test]
+fn liquidity_outside_range_uses_one_side() {
+    let sqrt_a = get_sqrt_price_at_tick(-1000).unwrap();
+    let sqrt_b = get_sqrt_price_at_tick(1000).unwrap();
+
+    // Below the range only token_0 counts, above it only token_1
+    let below = get_liquidity_from_amounts(sqrt_a - 1, sqrt_a, sqrt_b, 1_000_000, 0).unwrap();
+    assert_eq!(below, get_liquidity_from_amount_0(sqrt_a, sqrt_b, 1_000_000).unwrap());
+    let above = get_liquidity_from_amounts(sqrt_b, sqrt_a, sqrt_b, 0, 1_000_000).unwrap();
+    assert_eq!(above, get_liquidity_from_amount_1(sqrt_a, sqrt_b, 1_000_000).unwrap());
+
+    // Argument order of the range does not matter
+    assert_eq!(
+        get_liquidity_from_amount_0(sqrt_b, sqrt_a, 1_000_000).unwrap(),
+        get_liquidity_from_amount_0(sqrt_a, sqrt_b, 1_000_000).unwrap()
+    );
+}
+
+#[test]
+fn price_band_ticks_bracket_the_price() {
+    let mut rng = Rng(0x5eed_0006);
+    for _ in 0..ITERATIONS {
+        let t = rng.tick().clamp(-443000, 443000);
+        let sqrt_price = get_sqrt_price_at_tick(t).unwrap() + rng.next_u64() as u128 % 1000;
+        let band_bps = 10_001 + (rng.next_u64() % 1_000_000) as u32;
+
+        let (lower, upper) = price_band_ticks(sqrt_price, band_bps, tick::DEFAULT_TICK_SPACING).unwrap();
+        assert_eq!(lower % tick::DEFAULT_TICK_SPACING, 0);
+        assert_eq!(upper % tick::DEFAULT_TICK_SPACING, 0);
+        assert!(lower < upper, "band {} at tick {}", band_bps, t);
+        assert!(get_sqrt_price_at_tick(lower).unwrap() < sqrt_price);
+        assert!(get_sqrt_price_at_tick(upper).unwrap() > sqrt_price);
+    }
+
+    // 2x band at price 1: [0.5, 2] widened outward to the tick spacing
+    let (lower, upper) = price_band_ticks(Q64, 20_000, 10).unwrap();
+    assert_eq!((lower, upper), (-6940, 6940));
+    assert!(price_band_ticks(Q64, 10_000, 10).is_err());
+}
+
+#[test]
+fn price_deviation_tracks_tick_distance() {
+    let mut rng = Rng(0x5eed_0007);
+    for _ in 0..ITERATIONS {
+        let t = rng.tick().clamp(-400000, 400000);
+        let k = (rng.next_u64() % 4001) as i32 - 2000;
+        let reference = get_sqrt_price_at_tick(t).unwrap();
+        let price = get_sqrt_price_at_tick(t + k).unwrap();
+
+        let deviation = price_deviation_bps(price, reference).unwrap() as f64;
+        let expected = (1.0001f64.powi(k) - 1.0).abs() * 10_000.0;
+        assert!((deviation - expected).abs() <= 1.5, "tick {} + {}: {} vs {}", t, k, deviation, expected);
+    }
+
+    assert_eq!(price_deviation_bps(Q64, Q64), Some(0));
+    // sqrt price x2 = price x4
+    assert_eq!(price_deviation_bps(2 * Q64, Q64), Some(30_000));
+    assert_eq!(price_deviation_bps(Q64, 2 * Q64), Some(7_500));
+}
+
+/// Input a sell needs to move `positions` from `sqrt_price` to `sqrt_final`,
+/// rounded up (None if it does not fit in u64)
+fn sell_input(positions: &[(u128, i32, i32)], sqrt_price: u128, sqrt_final: u128, zero_for_one: bool) -> Option<u128> {
+    let mut total = 0u128;
+    for &(liquidity, lower, upper) in positions {
+        let (low, high) = (get_sqrt_price_at_tick(lower).unwrap(), get_sqrt_price_at_tick(upper).unwrap());
+        let (from, to) = if zero_for_one {
+            (sqrt_final.max(low), sqrt_price.min(high))
+        } else {
+            (sqrt_price.max(low), sqrt_final.min(high))
+        };
+        if from < to {
+            total += if zero_for_one {
+                get_delta_amount_0_unsigned(from, to, liquidity, true).ok()?
+            } else {
+                get_delta_amount_1_unsigned(from, to, liquidity, true).ok()?
+            } as u128;
+        }
+    }
+    Some(total)
+}
+
+/// Output of the same move, rounded down
+fn sell_output(positions: &[(u128, i32, i32)], sqrt_price: u128, sqrt_final: u128, zero_for_one: bool) -> u128 {
+    let mut total = 0u128;
+    for &(liquidity, lower, upper) in positions {
+        let (low, high) = (get_sqrt_price_at_tick(lower).unwrap(), get_sqrt_price_at_tick(upper).unwrap());
+        let (from, to) = if zero_for_one {
+            (sqrt_final.max(low), sqrt_price.min(high))
+        } else {
+            (sqrt_price.max(low), sqrt_final.min(high))
+        };
+        if from < to {
+            total += if zero_for_one {
+                get_delta_amount_1_unsigned(from, to, liquidity, false).unwrap()
+            } else {
+                get_delta_amount_0_unsigned(from, to, liquidity, false).unwrap()
+            } as u128;
+        }
+    }
+    total
+}
+
+/// Output of selling `amount_in`, found independently of `sell_amount_out`:
+/// the farthest final price the amount pays for, by bisection over every
+/// position at once. Never more than the exact output.
+fn reference_sell_out(positions: &[(u128, i32, i32)], sqrt_price: u128, amount_in: u128, zero_for_one: bool) -> u128 {
+    let affordable = |sqrt_final: u128| {
+        sell_input(positions, sqrt_price, sqrt_final, zero_for_one).is_some_and(|input| input <= amount_in)
+    };
+    // `near` is always affordable; `far` is not, unless it is the price limit
+    let (mut near, mut far) = if zero_for_one {
+        (sqrt_price, MIN_SQRT_PRICE_X64)
+    } else {
+        (sqrt_price, MAX_SQRT_PRICE_X64)
+    };
+    if affordable(far) {
+        near = far;
+    }
+    while near.abs_diff(far) > 1 {
+        let mid = near.min(far) + near.abs_diff(far) / 2;
+        if affordable(mid) {
+            near = mid;
+        } else {
+            far = mid;
+        }
+    }
+    sell_output(positions, sqrt_price, near, zero_for_one)
+}
+
+#[test]
+fn sell_out_bounds_a_sell_through_every_position() {
+    let mut rng = Rng(0x5eed_0008);
+    for case in 0..ITERATIONS {
+        let t = (rng.next_u64() % 200_001) as i32 - 100_000;
+        let sqrt_price = get_sqrt_price_at_tick(t).unwrap() + rng.next_u64() as u128 % 1000;
+
+        // A primary position and up to three ladder rungs, some of them away
+        // from the current price
+        let mut positions = Vec::new();
+        for rung in 0..1 + rng.next_u64() % 4 {
+            let liquidity = 1 + (rng.next_u64() % (1 << 50)) as u128;
+            let range = if rung == 0 && rng.next_u64().is_multiple_of(2) {
+                (tick::MIN_TICK, tick::MAX_TICK)
+            } else {
+                let a = t + (rng.next_u64() % 40_001) as i32 - 20_000;
+                let b = a + 1 + (rng.next_u64() % 20_000) as i32;
+                (a, b)
+            };
+            positions.push((liquidity, range.0, range.1));
+        }
+        let zero_for_one = rng.next_u64().is_multiple_of(2);
+        let amount_in = rng.amount() as u128;
+
+        let out = sell_amount_out(&positions, sqrt_price, amount_in, zero_for_one).unwrap();
+        let reference = reference_sell_out(&positions, sqrt_price, amount_in, zero_for_one);
+
+        // Rounding only ever adds output: at most what a unit of input buys at
+        // the starting price, plus a unit or two, per position and price step
+        let unit = if zero_for_one {
+            mul_div_ceil(sqrt_price, sqrt_price, Q64).unwrap().div_ceil(Q64)
+        } else {
+            mul_div_ceil(Q64, Q64, sqrt_price).unwrap().div_ceil(sqrt_price)
+        };
+        let steps = 2 * positions.len() as u128 + 1;
+        assert!(out >= reference, "case {case}: {out} < reference {reference}");
+        assert!(
+            out <= reference + (unit + 2) * steps,
+            "case {case}: {out} vs reference {reference}, unit {unit} ({positions:?}, {amount_in} in)"
+        );
+    }
+}
+
+#[test]
+fn ladder_positions_do_not_sell_like_one_curve() {
+    // Full range plus a band wholly below the price: selling token_0 pushes
+    // the price into the band, which pays out more than the band's virtual
+    // reserves folded into the full-range curve
+    let sqrt_price = Q64;
+    let positions = [(1_000_000_000u128, tick::MIN_TICK, tick::MAX_TICK), (40_000_000_000, -27720, -4210)];
+    let (low, high) = (get_sqrt_price_at_tick(-27720).unwrap(), get_sqrt_price_at_tick(-4210).unwrap());
+    let min_sqrt = get_sqrt_price_at_tick(tick::MIN_TICK).unwrap();
+    let max_sqrt = get_sqrt_price_at_tick(tick::MAX_TICK).unwrap();
+
+    // Token_0 in the pool, and the token_1 the positions hold
+    let tokens = get_delta_amount_0_unsigned(sqrt_price, max_sqrt, positions[0].0, false).unwrap() as u128;
+    let quote = get_delta_amount_1_unsigned(min_sqrt, sqrt_price, positions[0].0, false).unwrap() as u128
+        + get_delta_amount_1_unsigned(low, high, positions[1].0, false).unwrap() as u128;
+
+    // (x + x_off)(y + y_off) = k with the offsets summed over both positions
+    let x_off: u128 = positions.iter().map(|&(l, lower, _)| l * get_sqrt_price_at_tick(lower).unwrap() / Q64).sum();
+    let y_off: u128 = positions.iter().map(|&(l, _, upper)| l * Q64 / get_sqrt_price_at_tick(upper).unwrap()).sum();
+    let curve_out = (quote + x_off) - mul_div_ceil(quote + x_off, tokens + y_off, 2 * tokens + y_off).unwrap();
+
+    let out = sell_amount_out(&positions, sqrt_price, tokens, true).unwrap();
+    let reference = reference_sell_out(&positions, sqrt_price, tokens, true);
+    assert!(out >= reference);
+    assert!(reference > curve_out + curve_out / 20, "{reference} vs curve {curve_out}");
+}
+
+#[test]
+fn position_amounts_split_each_range_at_the_price() {
+    let sqrt_price = Q64;
+    let min_sqrt = get_sqrt_price_at_tick(tick::MIN_TICK).unwrap();
+    let max_sqrt = get_sqrt_price_at_tick(tick::MAX_TICK).unwrap();
+    let (low, high) = (get_sqrt_price_at_tick(-27720).unwrap(), get_sqrt_price_at_tick(-4210).unwrap());
+    let full_range = (1_000_000_000u128, tick::MIN_TICK, tick::MAX_TICK);
+    let below = (40_000_000_000u128, -27720, -4210);
+    let above = (40_000_000_000u128, 4210, 27720);
+
+    // A range below the price holds only token_1, one above it only token_0
+    let (amount_0, amount_1) = position_amounts(&[below], sqrt_price).unwrap();
+    assert_eq!(amount_0, 0);
+    assert_eq!(amount_1, get_delta_amount_1_unsigned(low, high, below.0, false).unwrap() as u128);
+    let (amount_0, amount_1) = position_amounts(&[above], sqrt_price).unwrap();
+    let (above_low, above_high) = (get_sqrt_price_at_tick(4210).unwrap(), get_sqrt_price_at_tick(27720).unwrap());
+    assert_eq!(amount_0, get_delta_amount_0_unsigned(above_low, above_high, above.0, false).unwrap() as u128);
+    assert_eq!(amount_1, 0);
+
+    // Amounts add up across positions
+    let (amount_0, amount_1) = position_amounts(&[full_range, below], sqrt_price).unwrap();
+    assert_eq!(amount_0, get_delta_amount_0_unsigned(sqrt_price, max_sqrt, full_range.0, false).unwrap() as u128);
+    assert_eq!(
+        amount_1,
+        get_delta_amount_1_unsigned(min_sqrt, sqrt_price, full_range.0, false).unwrap() as u128
+            + get_delta_amount_1_unsigned(low, high, below.0, false).unwrap() as u128
+    );
+    assert_eq!(position_amounts(&[], sqrt_price).unwrap(), (0, 0));
+}
@@ -0,0 +1,302 @@
+//! A hooked sovereign mint: the Execute hook only runs mid-transfer, and the
+//! program detaches its own hook (as the sovereign PDA) for program-side
+//! transfers instead of re-entering itself through Token-2022.
+//!
+//! Token-2022's processor and this program's entrypoint are called in-process;
+//! there is no runtime here, so each instruction is run directly with the
+//! signers a transaction (or the program's PDA signature) would provide.
+
+mod common;
+
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee, transfer_hook, ExtensionType, StateWithExtensionsMut},
+    processor::Processor,
+    state::{Account as TokenAccountState, Mint as MintState},
+};
+use solana_sysvar::program_stubs::{self, SyscallStubs};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::{self as hook_ix, ExecuteInstruction};
+use sovereign_liquidity::constants::{POOL_VAULT_REGISTRY_SEED, SOVEREIGN_SEED};
+use sovereign_liquidity::errors::SovereignError;
+use sovereign_liquidity::instructions::transfer_hook::{
+    extra_account_metas, hooked_by_program, is_transferring, set_transfer_hook_program_ix,
+    transfer_hook_program_id,
+};
+use sovereign_liquidity::state::SovereignState;
+
+use common::account;
+
+const DECIMALS: u8 = 6;
+const SELL_FEE_BPS: u16 = 100;
+const SOVEREIGN_ID: u64 = 7;
+
+/// Default Clock and Rent for Token-2022 and the hook
+struct Sysvars;
+
+impl SyscallStubs for Sysvars {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Clock) = Clock::default() };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+}
+
+/// Rent-exempt for every fixture account
+const LAMPORTS: u64 = 1_000_000_000;
+
+/// Run one instruction with `signers` as the only signatures
+fn process(instruction: &Instruction, infos: &[AccountInfo<'static>], signers: &[Pubkey]) -> ProgramResult {
+    let accounts: Vec<AccountInfo<'static>> = instruction
+        .accounts
+        .iter()
+        .map(|meta| {
+            let mut info = infos
+                .iter()
+                .find(|info| info.key == &meta.pubkey)
+                .unwrap_or_else(|| panic!("missing account {}", meta.pubkey))
+                .clone();
+            info.is_signer = signers.contains(&meta.pubkey);
+            info.is_writable = meta.is_writable;
+            info
+        })
+        .collect();
+    if instruction.program_id == spl_token_2022::ID {
+        Processor::process(&instruction.program_id, &accounts, &instruction.data)
+    } else {
+        sovereign_liquidity::entry(&instruction.program_id, Box::leak(accounts.into_boxed_slice()), &instruction.data)
+    }
+}
+
+struct HookedMint {
+    mint: &'static AccountInfo<'static>,
+    sovereign: &'static AccountInfo<'static>,
+    /// Extra accounts of the Execute hook: validation PDA, registry
+    validation: &'static AccountInfo<'static>,
+    registry: &'static AccountInfo<'static>,
+    /// Sovereign-owned token vault
+    vault: &'static AccountInfo<'static>,
+    alice: Pubkey,
+    alice_tokens: &'static AccountInfo<'static>,
+    bob_tokens: &'static AccountInfo<'static>,
+}
+
+impl HookedMint {
+    /// A sovereign token mint as create_token sets it up: transfer fee plus this
+    /// program's hook, both under the sovereign PDA
+    fn new() -> Self {
+        static SYSVARS: Once = Once::new();
+        SYSVARS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Sysvars));
+        });
+
+        let mint_key = Pubkey::new_unique();
+        let (sovereign_key, sovereign_bump) = Pubkey::find_program_address(
+            &[SOVEREIGN_SEED, &SOVEREIGN_ID.to_le_bytes()],
+            &sovereign_liquidity::ID,
+        );
+        let mint_extensions = [ExtensionType::TransferFeeConfig, ExtensionType::TransferHook];
+        let mint_len = ExtensionType::try_calculate_account_len::<MintState>(&mint_extensions).unwrap();
+        let account_len = ExtensionType::try_calculate_account_len::<TokenAccountState>(
+            &ExtensionType::get_required_init_account_extensions(&mint_extensions),
+        )
+        .unwrap();
+
+        let mut sovereign_data = Vec::new();
+        SovereignState {
+            sovereign_id: SOVEREIGN_ID,
+            token_mint: mint_key,
+            sell_fee_bps: SELL_FEE_BPS,
+            bump: sovereign_bump,
+            ..Default::default()
+        }
+        .try_serialize(&mut sovereign_data)
+        .unwrap();
+
+        let mut validation_data = vec![0; ExtraAccountMetaList::size_of(2).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut validation_data,
+            &extra_account_metas(SOVEREIGN_ID).unwrap(),
+        )
+        .unwrap();
+        let (registry_key, _) = Pubkey::find_program_address(
+            &[POOL_VAULT_REGISTRY_SEED, sovereign_key.as_ref()],
+            &sovereign_liquidity::ID,
+        );
+
+        let token_account = || account(Pubkey::new_unique(), spl_token_2022::ID, LAMPORTS, vec![0; account_len], false);
+        let fixture = Self {
+            mint: account(mint_key, spl_token_2022::ID, LAMPORTS, vec![0; mint_len], false),
+            sovereign: account(sovereign_key, sovereign_liquidity::ID, LAMPORTS, sovereign_data, false),
+            validation: account(
+                spl_transfer_hook_interface::get_extra_account_metas_address(&mint_key, &sovereign_liquidity::ID),
+                sovereign_liquidity::ID,
+                LAMPORTS,
+                validation_data,
+                false,
+            ),
+            registry: account(registry_key, System::id(), LAMPORTS, Vec::new(), false),
+            vault: token_account(),
+            alice: Pubkey::new_unique(),
+            alice_tokens: token_account(),
+            bob_tokens: token_account(),
+        };
+
+        let token = &spl_token_2022::ID;
+        let mint_authority = Pubkey::new_unique();
+        let mut infos = fixture.infos();
+        infos.push(account(mint_authority, System::id(), LAMPORTS, Vec::new(), false).clone());
+        for instruction in [
+            transfer_fee::instruction::initialize_transfer_fee_config(
+                token, &mint_key, Some(&sovereign_key), Some(&sovereign_key), SELL_FEE_BPS, u64::MAX,
+            )
+            .unwrap(),
+            transfer_hook::instruction::initialize(token, &mint_key, Some(sovereign_key), Some(sovereign_liquidity::ID))
+                .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(token, &mint_key, &mint_authority, None, DECIMALS).unwrap(),
+            spl_token_2022::instruction::initialize_account3(token, fixture.vault.key, &mint_key, &sovereign_key)
+                .unwrap(),
+            spl_token_2022::instruction::initialize_account3(token, fixture.alice_tokens.key, &mint_key, &fixture.alice)
+                .unwrap(),
+            spl_token_2022::instruction::initialize_account3(token, fixture.bob_tokens.key, &mint_key, &Pubkey::new_unique())
+                .unwrap(),
+            spl_token_2022::instruction::mint_to(token, &mint_key, fixture.vault.key, &mint_authority, &[], 1_000_000)
+                .unwrap(),
+        ] {
+            process(&instruction, &infos, &[mint_authority]).unwrap();
+        }
+        fixture
+    }
+
+    fn infos(&self) -> Vec<AccountInfo<'static>> {
+        vec![
+            self.mint.clone(),
+            self.sovereign.clone(),
+            self.validation.clone(),
+            self.registry.clone(),
+            self.vault.clone(),
+            self.alice_tokens.clone(),
+            self.bob_tokens.clone(),
+            account(self.alice, System::id(), LAMPORTS, Vec::new(), false).clone(),
+        ]
+    }
+
+    /// Point the hook at `program_id`, signed by `signer`
+    fn set_hook(&self, program_id: Option<Pubkey>, signer: Pubkey) -> ProgramResult {
+        let instruction = set_transfer_hook_program_ix(self.mint.key, self.sovereign.key, program_id).unwrap();
+        process(&instruction, &self.infos(), &[signer])
+    }
+
+    fn hook_program(&self) -> Option<Pubkey> {
+        transfer_hook_program_id(&self.mint.try_borrow_data().unwrap()).unwrap()
+    }
+
+    /// The Execute call Token-2022 makes to the hook for an Alice -> Bob transfer
+    fn execute_hook(&self, amount: u64) -> Instruction {
+        let mut instruction = hook_ix::execute(
+            &sovereign_liquidity::ID,
+            self.alice_tokens.key,
+            self.mint.key,
+            self.bob_tokens.key,
+            &self.alice,
+            amount,
+        );
+        instruction.accounts.extend([
+            AccountMeta::new_readonly(*self.validation.key, false),
+            AccountMeta::new_readonly(*self.sovereign.key, false),
+            AccountMeta::new_readonly(*self.registry.key, false),
+        ]);
+        instruction
+    }
+}
+
+fn set_transferring(account: &AccountInfo) {
+    let mut data = account.try_borrow_mut_data().unwrap();
+    let mut state = StateWithExtensionsMut::<TokenAccountState>::unpack(&mut data).unwrap();
+    transfer_hook::set_transferring(&mut state).unwrap();
+}
+
+fn balance(account: &AccountInfo) -> u64 {
+    let data = account.try_borrow_data().unwrap();
+    u64::from_le_bytes(data[64..72].try_into().unwrap())
+}
+
+#[test]
+fn hook_called_outside_a_transfer_is_rejected() {
+    let fixture = HookedMint::new();
+
+    assert_eq!(
+        process(&fixture.execute_hook(10_000), &fixture.infos(), &[]),
+        Err(ProgramError::from(anchor_lang::error::Error::from(SovereignError::NotTransferring)))
+    );
+}
+
+#[test]
+fn hook_runs_while_token_2022_flags_the_transfer() {
+    let fixture = HookedMint::new();
+    assert!(!is_transferring(&fixture.alice_tokens.try_borrow_data().unwrap()).unwrap());
+
+    // What Token-2022 does to both accounts before invoking the hook
+    set_transferring(fixture.alice_tokens);
+    set_transferring(fixture.bob_tokens);
+
+    assert!(is_transferring(&fixture.alice_tokens.try_borrow_data().unwrap()).unwrap());
+    process(&fixture.execute_hook(10_000), &fixture.infos(), &[]).unwrap();
+}
+
+#[test]
+fn program_transfer_runs_with_the_hook_detached() {
+    let fixture = HookedMint::new();
+    let sovereign = *fixture.sovereign.key;
+    assert!(hooked_by_program(fixture.mint).unwrap());
+
+    // detach_transfer_hook, signed by the sovereign PDA
+    fixture.set_hook(None, sovereign).unwrap();
+    assert!(!hooked_by_program(fixture.mint).unwrap());
+    assert_eq!(fixture.hook_program(), None);
+
+    // A program-side transfer needs none of the hook's accounts while detached
+    let transfer = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::ID, fixture.vault.key, fixture.mint.key, fixture.bob_tokens.key,
+        &sovereign, &[], 10_000, DECIMALS,
+    )
+    .unwrap();
+    process(&transfer, &fixture.infos(), &[sovereign]).unwrap();
+    // The sell fee is still withheld at the destination
+    assert_eq!(balance(fixture.bob_tokens), 10_000 - 100);
+
+    // reattach_transfer_hook
+    fixture.set_hook(Some(sovereign_liquidity::ID), sovereign).unwrap();
+    assert!(hooked_by_program(fixture.mint).unwrap());
+}
+
+#[test]
+fn only_the_sovereign_can_detach_the_hook() {
+    let fixture = HookedMint::new();
+
+    assert_eq!(
+        fixture.set_hook(None, fixture.alice),
+        Err(ProgramError::MissingRequiredSignature)
+    );
+    assert_eq!(fixture.hook_program(), Some(sovereign_liquidity::ID));
+}
+
+#[test]
+fn other_programs_hooks_are_left_attached() {
+    let fixture = HookedMint::new();
+    let other_program = Pubkey::new_unique();
+
+    fixture.set_hook(Some(other_program), *fixture.sovereign.key).unwrap();
+
+    assert!(!hooked_by_program(fixture.mint).unwrap());
+    assert_eq!(fixture.hook_program(), Some(other_program));
+}